@@ -0,0 +1,97 @@
+#!/usr/bin/env python3
+"""Generates src/fold_table.rs, the data behind fold_text in src/lib.rs.
+
+A character folds to the NFKD decomposition of its full case folding with every combining
+mark removed. The table holds the characters whose folding differs from themselves, and the
+ranges of combining marks, which fold to nothing. Hangul syllables are decomposed in code.
+
+Run with the Python whose Unicode version should be used, then bump FOLDING_VERSION in
+src/lib.rs so the indexes built with the previous table are rebuilt on upgrade.
+"""
+
+import os
+import unicodedata
+
+# Latin letters written with a stroke or as a ligature have no decomposition, they are spelled
+# out so that search treats them like their accented neighbours
+SPELLED_OUT = {
+    "æ": "ae",
+    "œ": "oe",
+    "ø": "o",
+    "đ": "d",
+    "ł": "l",
+    "ħ": "h",
+    "ŧ": "t",
+    "ƀ": "b",
+    "ǥ": "g",
+    "ƈ": "c",
+}
+
+HANGUL_SYLLABLES = range(0xAC00, 0xD7A4)
+SURROGATES = range(0xD800, 0xE000)
+
+
+def is_mark(c):
+    return unicodedata.category(c) in ("Mn", "Me") or unicodedata.combining(c) != 0
+
+
+def fold(text):
+    while True:
+        folded = unicodedata.normalize("NFKD", unicodedata.normalize("NFKD", text).casefold())
+        folded = "".join(SPELLED_OUT.get(c, c) for c in folded if not is_mark(c))
+        if folded == text:
+            return folded
+        text = folded
+
+
+def rust_char(code_point):
+    return "'\\u{%04x}'" % code_point
+
+
+def rust_str(text):
+    if all(c.isascii() and c.isalnum() for c in text):
+        return '"%s"' % text
+    return '"%s"' % "".join("\\u{%04x}" % ord(c) for c in text)
+
+
+def main():
+    folds = []
+    marks = []
+    for code_point in range(0x110000):
+        if code_point in SURROGATES or code_point in HANGUL_SYLLABLES:
+            continue
+        c = chr(code_point)
+        if is_mark(c):
+            if marks and marks[-1][1] == code_point - 1:
+                marks[-1][1] = code_point
+            else:
+                marks.append([code_point, code_point])
+            continue
+        folded = fold(c)
+        if folded != c:
+            folds.append((code_point, folded))
+
+    lines = [
+        "// Generated by gen_fold_table.py from the Unicode %s character database, do not edit."
+        % unicodedata.unidata_version,
+        "",
+        "// Combining marks as inclusive ranges, sorted",
+        "pub(crate) const MARKS: &[(char, char)] = &[",
+    ]
+    lines += ["    (%s, %s)," % (rust_char(start), rust_char(end)) for start, end in marks]
+    lines += [
+        "];",
+        "",
+        "// Characters that fold to something other than themselves, sorted",
+        "pub(crate) const FOLDS: &[(char, &str)] = &[",
+    ]
+    lines += ["    (%s, %s)," % (rust_char(cp), rust_str(folded)) for cp, folded in folds]
+    lines += ["];", ""]
+
+    path = os.path.join(os.path.dirname(os.path.abspath(__file__)), "src", "fold_table.rs")
+    with open(path, "w") as out:
+        out.write("\n".join(lines))
+
+
+if __name__ == "__main__":
+    main()
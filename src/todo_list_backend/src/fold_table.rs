@@ -0,0 +1,7114 @@
+// Generated by gen_fold_table.py from the Unicode 14.0.0 character database, do not edit.
+
+// Combining marks as inclusive ranges, sorted
+pub(crate) const MARKS: &[(char, char)] = &[
+    ('\u{0300}', '\u{036f}'),
+    ('\u{0483}', '\u{0489}'),
+    ('\u{0591}', '\u{05bd}'),
+    ('\u{05bf}', '\u{05bf}'),
+    ('\u{05c1}', '\u{05c2}'),
+    ('\u{05c4}', '\u{05c5}'),
+    ('\u{05c7}', '\u{05c7}'),
+    ('\u{0610}', '\u{061a}'),
+    ('\u{064b}', '\u{065f}'),
+    ('\u{0670}', '\u{0670}'),
+    ('\u{06d6}', '\u{06dc}'),
+    ('\u{06df}', '\u{06e4}'),
+    ('\u{06e7}', '\u{06e8}'),
+    ('\u{06ea}', '\u{06ed}'),
+    ('\u{0711}', '\u{0711}'),
+    ('\u{0730}', '\u{074a}'),
+    ('\u{07a6}', '\u{07b0}'),
+    ('\u{07eb}', '\u{07f3}'),
+    ('\u{07fd}', '\u{07fd}'),
+    ('\u{0816}', '\u{0819}'),
+    ('\u{081b}', '\u{0823}'),
+    ('\u{0825}', '\u{0827}'),
+    ('\u{0829}', '\u{082d}'),
+    ('\u{0859}', '\u{085b}'),
+    ('\u{0898}', '\u{089f}'),
+    ('\u{08ca}', '\u{08e1}'),
+    ('\u{08e3}', '\u{0902}'),
+    ('\u{093a}', '\u{093a}'),
+    ('\u{093c}', '\u{093c}'),
+    ('\u{0941}', '\u{0948}'),
+    ('\u{094d}', '\u{094d}'),
+    ('\u{0951}', '\u{0957}'),
+    ('\u{0962}', '\u{0963}'),
+    ('\u{0981}', '\u{0981}'),
+    ('\u{09bc}', '\u{09bc}'),
+    ('\u{09c1}', '\u{09c4}'),
+    ('\u{09cd}', '\u{09cd}'),
+    ('\u{09e2}', '\u{09e3}'),
+    ('\u{09fe}', '\u{09fe}'),
+    ('\u{0a01}', '\u{0a02}'),
+    ('\u{0a3c}', '\u{0a3c}'),
+    ('\u{0a41}', '\u{0a42}'),
+    ('\u{0a47}', '\u{0a48}'),
+    ('\u{0a4b}', '\u{0a4d}'),
+    ('\u{0a51}', '\u{0a51}'),
+    ('\u{0a70}', '\u{0a71}'),
+    ('\u{0a75}', '\u{0a75}'),
+    ('\u{0a81}', '\u{0a82}'),
+    ('\u{0abc}', '\u{0abc}'),
+    ('\u{0ac1}', '\u{0ac5}'),
+    ('\u{0ac7}', '\u{0ac8}'),
+    ('\u{0acd}', '\u{0acd}'),
+    ('\u{0ae2}', '\u{0ae3}'),
+    ('\u{0afa}', '\u{0aff}'),
+    ('\u{0b01}', '\u{0b01}'),
+    ('\u{0b3c}', '\u{0b3c}'),
+    ('\u{0b3f}', '\u{0b3f}'),
+    ('\u{0b41}', '\u{0b44}'),
+    ('\u{0b4d}', '\u{0b4d}'),
+    ('\u{0b55}', '\u{0b56}'),
+    ('\u{0b62}', '\u{0b63}'),
+    ('\u{0b82}', '\u{0b82}'),
+    ('\u{0bc0}', '\u{0bc0}'),
+    ('\u{0bcd}', '\u{0bcd}'),
+    ('\u{0c00}', '\u{0c00}'),
+    ('\u{0c04}', '\u{0c04}'),
+    ('\u{0c3c}', '\u{0c3c}'),
+    ('\u{0c3e}', '\u{0c40}'),
+    ('\u{0c46}', '\u{0c48}'),
+    ('\u{0c4a}', '\u{0c4d}'),
+    ('\u{0c55}', '\u{0c56}'),
+    ('\u{0c62}', '\u{0c63}'),
+    ('\u{0c81}', '\u{0c81}'),
+    ('\u{0cbc}', '\u{0cbc}'),
+    ('\u{0cbf}', '\u{0cbf}'),
+    ('\u{0cc6}', '\u{0cc6}'),
+    ('\u{0ccc}', '\u{0ccd}'),
+    ('\u{0ce2}', '\u{0ce3}'),
+    ('\u{0d00}', '\u{0d01}'),
+    ('\u{0d3b}', '\u{0d3c}'),
+    ('\u{0d41}', '\u{0d44}'),
+    ('\u{0d4d}', '\u{0d4d}'),
+    ('\u{0d62}', '\u{0d63}'),
+    ('\u{0d81}', '\u{0d81}'),
+    ('\u{0dca}', '\u{0dca}'),
+    ('\u{0dd2}', '\u{0dd4}'),
+    ('\u{0dd6}', '\u{0dd6}'),
+    ('\u{0e31}', '\u{0e31}'),
+    ('\u{0e34}', '\u{0e3a}'),
+    ('\u{0e47}', '\u{0e4e}'),
+    ('\u{0eb1}', '\u{0eb1}'),
+    ('\u{0eb4}', '\u{0ebc}'),
+    ('\u{0ec8}', '\u{0ecd}'),
+    ('\u{0f18}', '\u{0f19}'),
+    ('\u{0f35}', '\u{0f35}'),
+    ('\u{0f37}', '\u{0f37}'),
+    ('\u{0f39}', '\u{0f39}'),
+    ('\u{0f71}', '\u{0f7e}'),
+    ('\u{0f80}', '\u{0f84}'),
+    ('\u{0f86}', '\u{0f87}'),
+    ('\u{0f8d}', '\u{0f97}'),
+    ('\u{0f99}', '\u{0fbc}'),
+    ('\u{0fc6}', '\u{0fc6}'),
+    ('\u{102d}', '\u{1030}'),
+    ('\u{1032}', '\u{1037}'),
+    ('\u{1039}', '\u{103a}'),
+    ('\u{103d}', '\u{103e}'),
+    ('\u{1058}', '\u{1059}'),
+    ('\u{105e}', '\u{1060}'),
+    ('\u{1071}', '\u{1074}'),
+    ('\u{1082}', '\u{1082}'),
+    ('\u{1085}', '\u{1086}'),
+    ('\u{108d}', '\u{108d}'),
+    ('\u{109d}', '\u{109d}'),
+    ('\u{135d}', '\u{135f}'),
+    ('\u{1712}', '\u{1715}'),
+    ('\u{1732}', '\u{1734}'),
+    ('\u{1752}', '\u{1753}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{17b4}', '\u{17b5}'),
+    ('\u{17b7}', '\u{17bd}'),
+    ('\u{17c6}', '\u{17c6}'),
+    ('\u{17c9}', '\u{17d3}'),
+    ('\u{17dd}', '\u{17dd}'),
+    ('\u{180b}', '\u{180d}'),
+    ('\u{180f}', '\u{180f}'),
+    ('\u{1885}', '\u{1886}'),
+    ('\u{18a9}', '\u{18a9}'),
+    ('\u{1920}', '\u{1922}'),
+    ('\u{1927}', '\u{1928}'),
+    ('\u{1932}', '\u{1932}'),
+    ('\u{1939}', '\u{193b}'),
+    ('\u{1a17}', '\u{1a18}'),
+    ('\u{1a1b}', '\u{1a1b}'),
+    ('\u{1a56}', '\u{1a56}'),
+    ('\u{1a58}', '\u{1a5e}'),
+    ('\u{1a60}', '\u{1a60}'),
+    ('\u{1a62}', '\u{1a62}'),
+    ('\u{1a65}', '\u{1a6c}'),
+    ('\u{1a73}', '\u{1a7c}'),
+    ('\u{1a7f}', '\u{1a7f}'),
+    ('\u{1ab0}', '\u{1ace}'),
+    ('\u{1b00}', '\u{1b03}'),
+    ('\u{1b34}', '\u{1b34}'),
+    ('\u{1b36}', '\u{1b3a}'),
+    ('\u{1b3c}', '\u{1b3c}'),
+    ('\u{1b42}', '\u{1b42}'),
+    ('\u{1b44}', '\u{1b44}'),
+    ('\u{1b6b}', '\u{1b73}'),
+    ('\u{1b80}', '\u{1b81}'),
+    ('\u{1ba2}', '\u{1ba5}'),
+    ('\u{1ba8}', '\u{1bad}'),
+    ('\u{1be6}', '\u{1be6}'),
+    ('\u{1be8}', '\u{1be9}'),
+    ('\u{1bed}', '\u{1bed}'),
+    ('\u{1bef}', '\u{1bf3}'),
+    ('\u{1c2c}', '\u{1c33}'),
+    ('\u{1c36}', '\u{1c37}'),
+    ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1ce0}'),
+    ('\u{1ce2}', '\u{1ce8}'),
+    ('\u{1ced}', '\u{1ced}'),
+    ('\u{1cf4}', '\u{1cf4}'),
+    ('\u{1cf8}', '\u{1cf9}'),
+    ('\u{1dc0}', '\u{1dff}'),
+    ('\u{20d0}', '\u{20f0}'),
+    ('\u{2cef}', '\u{2cf1}'),
+    ('\u{2d7f}', '\u{2d7f}'),
+    ('\u{2de0}', '\u{2dff}'),
+    ('\u{302a}', '\u{302f}'),
+    ('\u{3099}', '\u{309a}'),
+    ('\u{a66f}', '\u{a672}'),
+    ('\u{a674}', '\u{a67d}'),
+    ('\u{a69e}', '\u{a69f}'),
+    ('\u{a6f0}', '\u{a6f1}'),
+    ('\u{a802}', '\u{a802}'),
+    ('\u{a806}', '\u{a806}'),
+    ('\u{a80b}', '\u{a80b}'),
+    ('\u{a825}', '\u{a826}'),
+    ('\u{a82c}', '\u{a82c}'),
+    ('\u{a8c4}', '\u{a8c5}'),
+    ('\u{a8e0}', '\u{a8f1}'),
+    ('\u{a8ff}', '\u{a8ff}'),
+    ('\u{a926}', '\u{a92d}'),
+    ('\u{a947}', '\u{a951}'),
+    ('\u{a953}', '\u{a953}'),
+    ('\u{a980}', '\u{a982}'),
+    ('\u{a9b3}', '\u{a9b3}'),
+    ('\u{a9b6}', '\u{a9b9}'),
+    ('\u{a9bc}', '\u{a9bd}'),
+    ('\u{a9c0}', '\u{a9c0}'),
+    ('\u{a9e5}', '\u{a9e5}'),
+    ('\u{aa29}', '\u{aa2e}'),
+    ('\u{aa31}', '\u{aa32}'),
+    ('\u{aa35}', '\u{aa36}'),
+    ('\u{aa43}', '\u{aa43}'),
+    ('\u{aa4c}', '\u{aa4c}'),
+    ('\u{aa7c}', '\u{aa7c}'),
+    ('\u{aab0}', '\u{aab0}'),
+    ('\u{aab2}', '\u{aab4}'),
+    ('\u{aab7}', '\u{aab8}'),
+    ('\u{aabe}', '\u{aabf}'),
+    ('\u{aac1}', '\u{aac1}'),
+    ('\u{aaec}', '\u{aaed}'),
+    ('\u{aaf6}', '\u{aaf6}'),
+    ('\u{abe5}', '\u{abe5}'),
+    ('\u{abe8}', '\u{abe8}'),
+    ('\u{abed}', '\u{abed}'),
+    ('\u{fb1e}', '\u{fb1e}'),
+    ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2f}'),
+    ('\u{101fd}', '\u{101fd}'),
+    ('\u{102e0}', '\u{102e0}'),
+    ('\u{10376}', '\u{1037a}'),
+    ('\u{10a01}', '\u{10a03}'),
+    ('\u{10a05}', '\u{10a06}'),
+    ('\u{10a0c}', '\u{10a0f}'),
+    ('\u{10a38}', '\u{10a3a}'),
+    ('\u{10a3f}', '\u{10a3f}'),
+    ('\u{10ae5}', '\u{10ae6}'),
+    ('\u{10d24}', '\u{10d27}'),
+    ('\u{10eab}', '\u{10eac}'),
+    ('\u{10f46}', '\u{10f50}'),
+    ('\u{10f82}', '\u{10f85}'),
+    ('\u{11001}', '\u{11001}'),
+    ('\u{11038}', '\u{11046}'),
+    ('\u{11070}', '\u{11070}'),
+    ('\u{11073}', '\u{11074}'),
+    ('\u{1107f}', '\u{11081}'),
+    ('\u{110b3}', '\u{110b6}'),
+    ('\u{110b9}', '\u{110ba}'),
+    ('\u{110c2}', '\u{110c2}'),
+    ('\u{11100}', '\u{11102}'),
+    ('\u{11127}', '\u{1112b}'),
+    ('\u{1112d}', '\u{11134}'),
+    ('\u{11173}', '\u{11173}'),
+    ('\u{11180}', '\u{11181}'),
+    ('\u{111b6}', '\u{111be}'),
+    ('\u{111c0}', '\u{111c0}'),
+    ('\u{111c9}', '\u{111cc}'),
+    ('\u{111cf}', '\u{111cf}'),
+    ('\u{1122f}', '\u{11231}'),
+    ('\u{11234}', '\u{11237}'),
+    ('\u{1123e}', '\u{1123e}'),
+    ('\u{112df}', '\u{112df}'),
+    ('\u{112e3}', '\u{112ea}'),
+    ('\u{11300}', '\u{11301}'),
+    ('\u{1133b}', '\u{1133c}'),
+    ('\u{11340}', '\u{11340}'),
+    ('\u{1134d}', '\u{1134d}'),
+    ('\u{11366}', '\u{1136c}'),
+    ('\u{11370}', '\u{11374}'),
+    ('\u{11438}', '\u{1143f}'),
+    ('\u{11442}', '\u{11444}'),
+    ('\u{11446}', '\u{11446}'),
+    ('\u{1145e}', '\u{1145e}'),
+    ('\u{114b3}', '\u{114b8}'),
+    ('\u{114ba}', '\u{114ba}'),
+    ('\u{114bf}', '\u{114c0}'),
+    ('\u{114c2}', '\u{114c3}'),
+    ('\u{115b2}', '\u{115b5}'),
+    ('\u{115bc}', '\u{115bd}'),
+    ('\u{115bf}', '\u{115c0}'),
+    ('\u{115dc}', '\u{115dd}'),
+    ('\u{11633}', '\u{1163a}'),
+    ('\u{1163d}', '\u{1163d}'),
+    ('\u{1163f}', '\u{11640}'),
+    ('\u{116ab}', '\u{116ab}'),
+    ('\u{116ad}', '\u{116ad}'),
+    ('\u{116b0}', '\u{116b7}'),
+    ('\u{1171d}', '\u{1171f}'),
+    ('\u{11722}', '\u{11725}'),
+    ('\u{11727}', '\u{1172b}'),
+    ('\u{1182f}', '\u{11837}'),
+    ('\u{11839}', '\u{1183a}'),
+    ('\u{1193b}', '\u{1193e}'),
+    ('\u{11943}', '\u{11943}'),
+    ('\u{119d4}', '\u{119d7}'),
+    ('\u{119da}', '\u{119db}'),
+    ('\u{119e0}', '\u{119e0}'),
+    ('\u{11a01}', '\u{11a0a}'),
+    ('\u{11a33}', '\u{11a38}'),
+    ('\u{11a3b}', '\u{11a3e}'),
+    ('\u{11a47}', '\u{11a47}'),
+    ('\u{11a51}', '\u{11a56}'),
+    ('\u{11a59}', '\u{11a5b}'),
+    ('\u{11a8a}', '\u{11a96}'),
+    ('\u{11a98}', '\u{11a99}'),
+    ('\u{11c30}', '\u{11c36}'),
+    ('\u{11c38}', '\u{11c3d}'),
+    ('\u{11c3f}', '\u{11c3f}'),
+    ('\u{11c92}', '\u{11ca7}'),
+    ('\u{11caa}', '\u{11cb0}'),
+    ('\u{11cb2}', '\u{11cb3}'),
+    ('\u{11cb5}', '\u{11cb6}'),
+    ('\u{11d31}', '\u{11d36}'),
+    ('\u{11d3a}', '\u{11d3a}'),
+    ('\u{11d3c}', '\u{11d3d}'),
+    ('\u{11d3f}', '\u{11d45}'),
+    ('\u{11d47}', '\u{11d47}'),
+    ('\u{11d90}', '\u{11d91}'),
+    ('\u{11d95}', '\u{11d95}'),
+    ('\u{11d97}', '\u{11d97}'),
+    ('\u{11ef3}', '\u{11ef4}'),
+    ('\u{16af0}', '\u{16af4}'),
+    ('\u{16b30}', '\u{16b36}'),
+    ('\u{16f4f}', '\u{16f4f}'),
+    ('\u{16f8f}', '\u{16f92}'),
+    ('\u{16fe4}', '\u{16fe4}'),
+    ('\u{16ff0}', '\u{16ff1}'),
+    ('\u{1bc9d}', '\u{1bc9e}'),
+    ('\u{1cf00}', '\u{1cf2d}'),
+    ('\u{1cf30}', '\u{1cf46}'),
+    ('\u{1d165}', '\u{1d169}'),
+    ('\u{1d16d}', '\u{1d172}'),
+    ('\u{1d17b}', '\u{1d182}'),
+    ('\u{1d185}', '\u{1d18b}'),
+    ('\u{1d1aa}', '\u{1d1ad}'),
+    ('\u{1d242}', '\u{1d244}'),
+    ('\u{1da00}', '\u{1da36}'),
+    ('\u{1da3b}', '\u{1da6c}'),
+    ('\u{1da75}', '\u{1da75}'),
+    ('\u{1da84}', '\u{1da84}'),
+    ('\u{1da9b}', '\u{1da9f}'),
+    ('\u{1daa1}', '\u{1daaf}'),
+    ('\u{1e000}', '\u{1e006}'),
+    ('\u{1e008}', '\u{1e018}'),
+    ('\u{1e01b}', '\u{1e021}'),
+    ('\u{1e023}', '\u{1e024}'),
+    ('\u{1e026}', '\u{1e02a}'),
+    ('\u{1e130}', '\u{1e136}'),
+    ('\u{1e2ae}', '\u{1e2ae}'),
+    ('\u{1e2ec}', '\u{1e2ef}'),
+    ('\u{1e8d0}', '\u{1e8d6}'),
+    ('\u{1e944}', '\u{1e94a}'),
+    ('\u{e0100}', '\u{e01ef}'),
+];
+
+// Characters that fold to something other than themselves, sorted
+pub(crate) const FOLDS: &[(char, &str)] = &[
+    ('\u{0041}', "a"),
+    ('\u{0042}', "b"),
+    ('\u{0043}', "c"),
+    ('\u{0044}', "d"),
+    ('\u{0045}', "e"),
+    ('\u{0046}', "f"),
+    ('\u{0047}', "g"),
+    ('\u{0048}', "h"),
+    ('\u{0049}', "i"),
+    ('\u{004a}', "j"),
+    ('\u{004b}', "k"),
+    ('\u{004c}', "l"),
+    ('\u{004d}', "m"),
+    ('\u{004e}', "n"),
+    ('\u{004f}', "o"),
+    ('\u{0050}', "p"),
+    ('\u{0051}', "q"),
+    ('\u{0052}', "r"),
+    ('\u{0053}', "s"),
+    ('\u{0054}', "t"),
+    ('\u{0055}', "u"),
+    ('\u{0056}', "v"),
+    ('\u{0057}', "w"),
+    ('\u{0058}', "x"),
+    ('\u{0059}', "y"),
+    ('\u{005a}', "z"),
+    ('\u{00a0}', "\u{0020}"),
+    ('\u{00a8}', "\u{0020}"),
+    ('\u{00aa}', "a"),
+    ('\u{00af}', "\u{0020}"),
+    ('\u{00b2}', "2"),
+    ('\u{00b3}', "3"),
+    ('\u{00b4}', "\u{0020}"),
+    ('\u{00b5}', "\u{03bc}"),
+    ('\u{00b8}', "\u{0020}"),
+    ('\u{00b9}', "1"),
+    ('\u{00ba}', "o"),
+    ('\u{00bc}', "\u{0031}\u{2044}\u{0034}"),
+    ('\u{00bd}', "\u{0031}\u{2044}\u{0032}"),
+    ('\u{00be}', "\u{0033}\u{2044}\u{0034}"),
+    ('\u{00c0}', "a"),
+    ('\u{00c1}', "a"),
+    ('\u{00c2}', "a"),
+    ('\u{00c3}', "a"),
+    ('\u{00c4}', "a"),
+    ('\u{00c5}', "a"),
+    ('\u{00c6}', "ae"),
+    ('\u{00c7}', "c"),
+    ('\u{00c8}', "e"),
+    ('\u{00c9}', "e"),
+    ('\u{00ca}', "e"),
+    ('\u{00cb}', "e"),
+    ('\u{00cc}', "i"),
+    ('\u{00cd}', "i"),
+    ('\u{00ce}', "i"),
+    ('\u{00cf}', "i"),
+    ('\u{00d0}', "\u{00f0}"),
+    ('\u{00d1}', "n"),
+    ('\u{00d2}', "o"),
+    ('\u{00d3}', "o"),
+    ('\u{00d4}', "o"),
+    ('\u{00d5}', "o"),
+    ('\u{00d6}', "o"),
+    ('\u{00d8}', "o"),
+    ('\u{00d9}', "u"),
+    ('\u{00da}', "u"),
+    ('\u{00db}', "u"),
+    ('\u{00dc}', "u"),
+    ('\u{00dd}', "y"),
+    ('\u{00de}', "\u{00fe}"),
+    ('\u{00df}', "ss"),
+    ('\u{00e0}', "a"),
+    ('\u{00e1}', "a"),
+    ('\u{00e2}', "a"),
+    ('\u{00e3}', "a"),
+    ('\u{00e4}', "a"),
+    ('\u{00e5}', "a"),
+    ('\u{00e6}', "ae"),
+    ('\u{00e7}', "c"),
+    ('\u{00e8}', "e"),
+    ('\u{00e9}', "e"),
+    ('\u{00ea}', "e"),
+    ('\u{00eb}', "e"),
+    ('\u{00ec}', "i"),
+    ('\u{00ed}', "i"),
+    ('\u{00ee}', "i"),
+    ('\u{00ef}', "i"),
+    ('\u{00f1}', "n"),
+    ('\u{00f2}', "o"),
+    ('\u{00f3}', "o"),
+    ('\u{00f4}', "o"),
+    ('\u{00f5}', "o"),
+    ('\u{00f6}', "o"),
+    ('\u{00f8}', "o"),
+    ('\u{00f9}', "u"),
+    ('\u{00fa}', "u"),
+    ('\u{00fb}', "u"),
+    ('\u{00fc}', "u"),
+    ('\u{00fd}', "y"),
+    ('\u{00ff}', "y"),
+    ('\u{0100}', "a"),
+    ('\u{0101}', "a"),
+    ('\u{0102}', "a"),
+    ('\u{0103}', "a"),
+    ('\u{0104}', "a"),
+    ('\u{0105}', "a"),
+    ('\u{0106}', "c"),
+    ('\u{0107}', "c"),
+    ('\u{0108}', "c"),
+    ('\u{0109}', "c"),
+    ('\u{010a}', "c"),
+    ('\u{010b}', "c"),
+    ('\u{010c}', "c"),
+    ('\u{010d}', "c"),
+    ('\u{010e}', "d"),
+    ('\u{010f}', "d"),
+    ('\u{0110}', "d"),
+    ('\u{0111}', "d"),
+    ('\u{0112}', "e"),
+    ('\u{0113}', "e"),
+    ('\u{0114}', "e"),
+    ('\u{0115}', "e"),
+    ('\u{0116}', "e"),
+    ('\u{0117}', "e"),
+    ('\u{0118}', "e"),
+    ('\u{0119}', "e"),
+    ('\u{011a}', "e"),
+    ('\u{011b}', "e"),
+    ('\u{011c}', "g"),
+    ('\u{011d}', "g"),
+    ('\u{011e}', "g"),
+    ('\u{011f}', "g"),
+    ('\u{0120}', "g"),
+    ('\u{0121}', "g"),
+    ('\u{0122}', "g"),
+    ('\u{0123}', "g"),
+    ('\u{0124}', "h"),
+    ('\u{0125}', "h"),
+    ('\u{0126}', "h"),
+    ('\u{0127}', "h"),
+    ('\u{0128}', "i"),
+    ('\u{0129}', "i"),
+    ('\u{012a}', "i"),
+    ('\u{012b}', "i"),
+    ('\u{012c}', "i"),
+    ('\u{012d}', "i"),
+    ('\u{012e}', "i"),
+    ('\u{012f}', "i"),
+    ('\u{0130}', "i"),
+    ('\u{0132}', "ij"),
+    ('\u{0133}', "ij"),
+    ('\u{0134}', "j"),
+    ('\u{0135}', "j"),
+    ('\u{0136}', "k"),
+    ('\u{0137}', "k"),
+    ('\u{0139}', "l"),
+    ('\u{013a}', "l"),
+    ('\u{013b}', "l"),
+    ('\u{013c}', "l"),
+    ('\u{013d}', "l"),
+    ('\u{013e}', "l"),
+    ('\u{013f}', "\u{006c}\u{00b7}"),
+    ('\u{0140}', "\u{006c}\u{00b7}"),
+    ('\u{0141}', "l"),
+    ('\u{0142}', "l"),
+    ('\u{0143}', "n"),
+    ('\u{0144}', "n"),
+    ('\u{0145}', "n"),
+    ('\u{0146}', "n"),
+    ('\u{0147}', "n"),
+    ('\u{0148}', "n"),
+    ('\u{0149}', "\u{02bc}\u{006e}"),
+    ('\u{014a}', "\u{014b}"),
+    ('\u{014c}', "o"),
+    ('\u{014d}', "o"),
+    ('\u{014e}', "o"),
+    ('\u{014f}', "o"),
+    ('\u{0150}', "o"),
+    ('\u{0151}', "o"),
+    ('\u{0152}', "oe"),
+    ('\u{0153}', "oe"),
+    ('\u{0154}', "r"),
+    ('\u{0155}', "r"),
+    ('\u{0156}', "r"),
+    ('\u{0157}', "r"),
+    ('\u{0158}', "r"),
+    ('\u{0159}', "r"),
+    ('\u{015a}', "s"),
+    ('\u{015b}', "s"),
+    ('\u{015c}', "s"),
+    ('\u{015d}', "s"),
+    ('\u{015e}', "s"),
+    ('\u{015f}', "s"),
+    ('\u{0160}', "s"),
+    ('\u{0161}', "s"),
+    ('\u{0162}', "t"),
+    ('\u{0163}', "t"),
+    ('\u{0164}', "t"),
+    ('\u{0165}', "t"),
+    ('\u{0166}', "t"),
+    ('\u{0167}', "t"),
+    ('\u{0168}', "u"),
+    ('\u{0169}', "u"),
+    ('\u{016a}', "u"),
+    ('\u{016b}', "u"),
+    ('\u{016c}', "u"),
+    ('\u{016d}', "u"),
+    ('\u{016e}', "u"),
+    ('\u{016f}', "u"),
+    ('\u{0170}', "u"),
+    ('\u{0171}', "u"),
+    ('\u{0172}', "u"),
+    ('\u{0173}', "u"),
+    ('\u{0174}', "w"),
+    ('\u{0175}', "w"),
+    ('\u{0176}', "y"),
+    ('\u{0177}', "y"),
+    ('\u{0178}', "y"),
+    ('\u{0179}', "z"),
+    ('\u{017a}', "z"),
+    ('\u{017b}', "z"),
+    ('\u{017c}', "z"),
+    ('\u{017d}', "z"),
+    ('\u{017e}', "z"),
+    ('\u{017f}', "s"),
+    ('\u{0180}', "b"),
+    ('\u{0181}', "\u{0253}"),
+    ('\u{0182}', "\u{0183}"),
+    ('\u{0184}', "\u{0185}"),
+    ('\u{0186}', "\u{0254}"),
+    ('\u{0187}', "c"),
+    ('\u{0188}', "c"),
+    ('\u{0189}', "\u{0256}"),
+    ('\u{018a}', "\u{0257}"),
+    ('\u{018b}', "\u{018c}"),
+    ('\u{018e}', "\u{01dd}"),
+    ('\u{018f}', "\u{0259}"),
+    ('\u{0190}', "\u{025b}"),
+    ('\u{0191}', "\u{0192}"),
+    ('\u{0193}', "\u{0260}"),
+    ('\u{0194}', "\u{0263}"),
+    ('\u{0196}', "\u{0269}"),
+    ('\u{0197}', "\u{0268}"),
+    ('\u{0198}', "\u{0199}"),
+    ('\u{019c}', "\u{026f}"),
+    ('\u{019d}', "\u{0272}"),
+    ('\u{019f}', "\u{0275}"),
+    ('\u{01a0}', "o"),
+    ('\u{01a1}', "o"),
+    ('\u{01a2}', "\u{01a3}"),
+    ('\u{01a4}', "\u{01a5}"),
+    ('\u{01a6}', "\u{0280}"),
+    ('\u{01a7}', "\u{01a8}"),
+    ('\u{01a9}', "\u{0283}"),
+    ('\u{01ac}', "\u{01ad}"),
+    ('\u{01ae}', "\u{0288}"),
+    ('\u{01af}', "u"),
+    ('\u{01b0}', "u"),
+    ('\u{01b1}', "\u{028a}"),
+    ('\u{01b2}', "\u{028b}"),
+    ('\u{01b3}', "\u{01b4}"),
+    ('\u{01b5}', "\u{01b6}"),
+    ('\u{01b7}', "\u{0292}"),
+    ('\u{01b8}', "\u{01b9}"),
+    ('\u{01bc}', "\u{01bd}"),
+    ('\u{01c4}', "dz"),
+    ('\u{01c5}', "dz"),
+    ('\u{01c6}', "dz"),
+    ('\u{01c7}', "lj"),
+    ('\u{01c8}', "lj"),
+    ('\u{01c9}', "lj"),
+    ('\u{01ca}', "nj"),
+    ('\u{01cb}', "nj"),
+    ('\u{01cc}', "nj"),
+    ('\u{01cd}', "a"),
+    ('\u{01ce}', "a"),
+    ('\u{01cf}', "i"),
+    ('\u{01d0}', "i"),
+    ('\u{01d1}', "o"),
+    ('\u{01d2}', "o"),
+    ('\u{01d3}', "u"),
+    ('\u{01d4}', "u"),
+    ('\u{01d5}', "u"),
+    ('\u{01d6}', "u"),
+    ('\u{01d7}', "u"),
+    ('\u{01d8}', "u"),
+    ('\u{01d9}', "u"),
+    ('\u{01da}', "u"),
+    ('\u{01db}', "u"),
+    ('\u{01dc}', "u"),
+    ('\u{01de}', "a"),
+    ('\u{01df}', "a"),
+    ('\u{01e0}', "a"),
+    ('\u{01e1}', "a"),
+    ('\u{01e2}', "ae"),
+    ('\u{01e3}', "ae"),
+    ('\u{01e4}', "g"),
+    ('\u{01e5}', "g"),
+    ('\u{01e6}', "g"),
+    ('\u{01e7}', "g"),
+    ('\u{01e8}', "k"),
+    ('\u{01e9}', "k"),
+    ('\u{01ea}', "o"),
+    ('\u{01eb}', "o"),
+    ('\u{01ec}', "o"),
+    ('\u{01ed}', "o"),
+    ('\u{01ee}', "\u{0292}"),
+    ('\u{01ef}', "\u{0292}"),
+    ('\u{01f0}', "j"),
+    ('\u{01f1}', "dz"),
+    ('\u{01f2}', "dz"),
+    ('\u{01f3}', "dz"),
+    ('\u{01f4}', "g"),
+    ('\u{01f5}', "g"),
+    ('\u{01f6}', "\u{0195}"),
+    ('\u{01f7}', "\u{01bf}"),
+    ('\u{01f8}', "n"),
+    ('\u{01f9}', "n"),
+    ('\u{01fa}', "a"),
+    ('\u{01fb}', "a"),
+    ('\u{01fc}', "ae"),
+    ('\u{01fd}', "ae"),
+    ('\u{01fe}', "o"),
+    ('\u{01ff}', "o"),
+    ('\u{0200}', "a"),
+    ('\u{0201}', "a"),
+    ('\u{0202}', "a"),
+    ('\u{0203}', "a"),
+    ('\u{0204}', "e"),
+    ('\u{0205}', "e"),
+    ('\u{0206}', "e"),
+    ('\u{0207}', "e"),
+    ('\u{0208}', "i"),
+    ('\u{0209}', "i"),
+    ('\u{020a}', "i"),
+    ('\u{020b}', "i"),
+    ('\u{020c}', "o"),
+    ('\u{020d}', "o"),
+    ('\u{020e}', "o"),
+    ('\u{020f}', "o"),
+    ('\u{0210}', "r"),
+    ('\u{0211}', "r"),
+    ('\u{0212}', "r"),
+    ('\u{0213}', "r"),
+    ('\u{0214}', "u"),
+    ('\u{0215}', "u"),
+    ('\u{0216}', "u"),
+    ('\u{0217}', "u"),
+    ('\u{0218}', "s"),
+    ('\u{0219}', "s"),
+    ('\u{021a}', "t"),
+    ('\u{021b}', "t"),
+    ('\u{021c}', "\u{021d}"),
+    ('\u{021e}', "h"),
+    ('\u{021f}', "h"),
+    ('\u{0220}', "\u{019e}"),
+    ('\u{0222}', "\u{0223}"),
+    ('\u{0224}', "\u{0225}"),
+    ('\u{0226}', "a"),
+    ('\u{0227}', "a"),
+    ('\u{0228}', "e"),
+    ('\u{0229}', "e"),
+    ('\u{022a}', "o"),
+    ('\u{022b}', "o"),
+    ('\u{022c}', "o"),
+    ('\u{022d}', "o"),
+    ('\u{022e}', "o"),
+    ('\u{022f}', "o"),
+    ('\u{0230}', "o"),
+    ('\u{0231}', "o"),
+    ('\u{0232}', "y"),
+    ('\u{0233}', "y"),
+    ('\u{023a}', "\u{2c65}"),
+    ('\u{023b}', "\u{023c}"),
+    ('\u{023d}', "\u{019a}"),
+    ('\u{023e}', "\u{2c66}"),
+    ('\u{0241}', "\u{0242}"),
+    ('\u{0243}', "b"),
+    ('\u{0244}', "\u{0289}"),
+    ('\u{0245}', "\u{028c}"),
+    ('\u{0246}', "\u{0247}"),
+    ('\u{0248}', "\u{0249}"),
+    ('\u{024a}', "\u{024b}"),
+    ('\u{024c}', "\u{024d}"),
+    ('\u{024e}', "\u{024f}"),
+    ('\u{02b0}', "h"),
+    ('\u{02b1}', "\u{0266}"),
+    ('\u{02b2}', "j"),
+    ('\u{02b3}', "r"),
+    ('\u{02b4}', "\u{0279}"),
+    ('\u{02b5}', "\u{027b}"),
+    ('\u{02b6}', "\u{0281}"),
+    ('\u{02b7}', "w"),
+    ('\u{02b8}', "y"),
+    ('\u{02d8}', "\u{0020}"),
+    ('\u{02d9}', "\u{0020}"),
+    ('\u{02da}', "\u{0020}"),
+    ('\u{02db}', "\u{0020}"),
+    ('\u{02dc}', "\u{0020}"),
+    ('\u{02dd}', "\u{0020}"),
+    ('\u{02e0}', "\u{0263}"),
+    ('\u{02e1}', "l"),
+    ('\u{02e2}', "s"),
+    ('\u{02e3}', "x"),
+    ('\u{02e4}', "\u{0295}"),
+    ('\u{0370}', "\u{0371}"),
+    ('\u{0372}', "\u{0373}"),
+    ('\u{0374}', "\u{02b9}"),
+    ('\u{0376}', "\u{0377}"),
+    ('\u{037a}', "\u{0020}\u{03b9}"),
+    ('\u{037e}', "\u{003b}"),
+    ('\u{037f}', "\u{03f3}"),
+    ('\u{0384}', "\u{0020}"),
+    ('\u{0385}', "\u{0020}"),
+    ('\u{0386}', "\u{03b1}"),
+    ('\u{0387}', "\u{00b7}"),
+    ('\u{0388}', "\u{03b5}"),
+    ('\u{0389}', "\u{03b7}"),
+    ('\u{038a}', "\u{03b9}"),
+    ('\u{038c}', "\u{03bf}"),
+    ('\u{038e}', "\u{03c5}"),
+    ('\u{038f}', "\u{03c9}"),
+    ('\u{0390}', "\u{03b9}"),
+    ('\u{0391}', "\u{03b1}"),
+    ('\u{0392}', "\u{03b2}"),
+    ('\u{0393}', "\u{03b3}"),
+    ('\u{0394}', "\u{03b4}"),
+    ('\u{0395}', "\u{03b5}"),
+    ('\u{0396}', "\u{03b6}"),
+    ('\u{0397}', "\u{03b7}"),
+    ('\u{0398}', "\u{03b8}"),
+    ('\u{0399}', "\u{03b9}"),
+    ('\u{039a}', "\u{03ba}"),
+    ('\u{039b}', "\u{03bb}"),
+    ('\u{039c}', "\u{03bc}"),
+    ('\u{039d}', "\u{03bd}"),
+    ('\u{039e}', "\u{03be}"),
+    ('\u{039f}', "\u{03bf}"),
+    ('\u{03a0}', "\u{03c0}"),
+    ('\u{03a1}', "\u{03c1}"),
+    ('\u{03a3}', "\u{03c3}"),
+    ('\u{03a4}', "\u{03c4}"),
+    ('\u{03a5}', "\u{03c5}"),
+    ('\u{03a6}', "\u{03c6}"),
+    ('\u{03a7}', "\u{03c7}"),
+    ('\u{03a8}', "\u{03c8}"),
+    ('\u{03a9}', "\u{03c9}"),
+    ('\u{03aa}', "\u{03b9}"),
+    ('\u{03ab}', "\u{03c5}"),
+    ('\u{03ac}', "\u{03b1}"),
+    ('\u{03ad}', "\u{03b5}"),
+    ('\u{03ae}', "\u{03b7}"),
+    ('\u{03af}', "\u{03b9}"),
+    ('\u{03b0}', "\u{03c5}"),
+    ('\u{03c2}', "\u{03c3}"),
+    ('\u{03ca}', "\u{03b9}"),
+    ('\u{03cb}', "\u{03c5}"),
+    ('\u{03cc}', "\u{03bf}"),
+    ('\u{03cd}', "\u{03c5}"),
+    ('\u{03ce}', "\u{03c9}"),
+    ('\u{03cf}', "\u{03d7}"),
+    ('\u{03d0}', "\u{03b2}"),
+    ('\u{03d1}', "\u{03b8}"),
+    ('\u{03d2}', "\u{03c5}"),
+    ('\u{03d3}', "\u{03c5}"),
+    ('\u{03d4}', "\u{03c5}"),
+    ('\u{03d5}', "\u{03c6}"),
+    ('\u{03d6}', "\u{03c0}"),
+    ('\u{03d8}', "\u{03d9}"),
+    ('\u{03da}', "\u{03db}"),
+    ('\u{03dc}', "\u{03dd}"),
+    ('\u{03de}', "\u{03df}"),
+    ('\u{03e0}', "\u{03e1}"),
+    ('\u{03e2}', "\u{03e3}"),
+    ('\u{03e4}', "\u{03e5}"),
+    ('\u{03e6}', "\u{03e7}"),
+    ('\u{03e8}', "\u{03e9}"),
+    ('\u{03ea}', "\u{03eb}"),
+    ('\u{03ec}', "\u{03ed}"),
+    ('\u{03ee}', "\u{03ef}"),
+    ('\u{03f0}', "\u{03ba}"),
+    ('\u{03f1}', "\u{03c1}"),
+    ('\u{03f2}', "\u{03c3}"),
+    ('\u{03f4}', "\u{03b8}"),
+    ('\u{03f5}', "\u{03b5}"),
+    ('\u{03f7}', "\u{03f8}"),
+    ('\u{03f9}', "\u{03c3}"),
+    ('\u{03fa}', "\u{03fb}"),
+    ('\u{03fd}', "\u{037b}"),
+    ('\u{03fe}', "\u{037c}"),
+    ('\u{03ff}', "\u{037d}"),
+    ('\u{0400}', "\u{0435}"),
+    ('\u{0401}', "\u{0435}"),
+    ('\u{0402}', "\u{0452}"),
+    ('\u{0403}', "\u{0433}"),
+    ('\u{0404}', "\u{0454}"),
+    ('\u{0405}', "\u{0455}"),
+    ('\u{0406}', "\u{0456}"),
+    ('\u{0407}', "\u{0456}"),
+    ('\u{0408}', "\u{0458}"),
+    ('\u{0409}', "\u{0459}"),
+    ('\u{040a}', "\u{045a}"),
+    ('\u{040b}', "\u{045b}"),
+    ('\u{040c}', "\u{043a}"),
+    ('\u{040d}', "\u{0438}"),
+    ('\u{040e}', "\u{0443}"),
+    ('\u{040f}', "\u{045f}"),
+    ('\u{0410}', "\u{0430}"),
+    ('\u{0411}', "\u{0431}"),
+    ('\u{0412}', "\u{0432}"),
+    ('\u{0413}', "\u{0433}"),
+    ('\u{0414}', "\u{0434}"),
+    ('\u{0415}', "\u{0435}"),
+    ('\u{0416}', "\u{0436}"),
+    ('\u{0417}', "\u{0437}"),
+    ('\u{0418}', "\u{0438}"),
+    ('\u{0419}', "\u{0438}"),
+    ('\u{041a}', "\u{043a}"),
+    ('\u{041b}', "\u{043b}"),
+    ('\u{041c}', "\u{043c}"),
+    ('\u{041d}', "\u{043d}"),
+    ('\u{041e}', "\u{043e}"),
+    ('\u{041f}', "\u{043f}"),
+    ('\u{0420}', "\u{0440}"),
+    ('\u{0421}', "\u{0441}"),
+    ('\u{0422}', "\u{0442}"),
+    ('\u{0423}', "\u{0443}"),
+    ('\u{0424}', "\u{0444}"),
+    ('\u{0425}', "\u{0445}"),
+    ('\u{0426}', "\u{0446}"),
+    ('\u{0427}', "\u{0447}"),
+    ('\u{0428}', "\u{0448}"),
+    ('\u{0429}', "\u{0449}"),
+    ('\u{042a}', "\u{044a}"),
+    ('\u{042b}', "\u{044b}"),
+    ('\u{042c}', "\u{044c}"),
+    ('\u{042d}', "\u{044d}"),
+    ('\u{042e}', "\u{044e}"),
+    ('\u{042f}', "\u{044f}"),
+    ('\u{0439}', "\u{0438}"),
+    ('\u{0450}', "\u{0435}"),
+    ('\u{0451}', "\u{0435}"),
+    ('\u{0453}', "\u{0433}"),
+    ('\u{0457}', "\u{0456}"),
+    ('\u{045c}', "\u{043a}"),
+    ('\u{045d}', "\u{0438}"),
+    ('\u{045e}', "\u{0443}"),
+    ('\u{0460}', "\u{0461}"),
+    ('\u{0462}', "\u{0463}"),
+    ('\u{0464}', "\u{0465}"),
+    ('\u{0466}', "\u{0467}"),
+    ('\u{0468}', "\u{0469}"),
+    ('\u{046a}', "\u{046b}"),
+    ('\u{046c}', "\u{046d}"),
+    ('\u{046e}', "\u{046f}"),
+    ('\u{0470}', "\u{0471}"),
+    ('\u{0472}', "\u{0473}"),
+    ('\u{0474}', "\u{0475}"),
+    ('\u{0476}', "\u{0475}"),
+    ('\u{0477}', "\u{0475}"),
+    ('\u{0478}', "\u{0479}"),
+    ('\u{047a}', "\u{047b}"),
+    ('\u{047c}', "\u{047d}"),
+    ('\u{047e}', "\u{047f}"),
+    ('\u{0480}', "\u{0481}"),
+    ('\u{048a}', "\u{048b}"),
+    ('\u{048c}', "\u{048d}"),
+    ('\u{048e}', "\u{048f}"),
+    ('\u{0490}', "\u{0491}"),
+    ('\u{0492}', "\u{0493}"),
+    ('\u{0494}', "\u{0495}"),
+    ('\u{0496}', "\u{0497}"),
+    ('\u{0498}', "\u{0499}"),
+    ('\u{049a}', "\u{049b}"),
+    ('\u{049c}', "\u{049d}"),
+    ('\u{049e}', "\u{049f}"),
+    ('\u{04a0}', "\u{04a1}"),
+    ('\u{04a2}', "\u{04a3}"),
+    ('\u{04a4}', "\u{04a5}"),
+    ('\u{04a6}', "\u{04a7}"),
+    ('\u{04a8}', "\u{04a9}"),
+    ('\u{04aa}', "\u{04ab}"),
+    ('\u{04ac}', "\u{04ad}"),
+    ('\u{04ae}', "\u{04af}"),
+    ('\u{04b0}', "\u{04b1}"),
+    ('\u{04b2}', "\u{04b3}"),
+    ('\u{04b4}', "\u{04b5}"),
+    ('\u{04b6}', "\u{04b7}"),
+    ('\u{04b8}', "\u{04b9}"),
+    ('\u{04ba}', "\u{04bb}"),
+    ('\u{04bc}', "\u{04bd}"),
+    ('\u{04be}', "\u{04bf}"),
+    ('\u{04c0}', "\u{04cf}"),
+    ('\u{04c1}', "\u{0436}"),
+    ('\u{04c2}', "\u{0436}"),
+    ('\u{04c3}', "\u{04c4}"),
+    ('\u{04c5}', "\u{04c6}"),
+    ('\u{04c7}', "\u{04c8}"),
+    ('\u{04c9}', "\u{04ca}"),
+    ('\u{04cb}', "\u{04cc}"),
+    ('\u{04cd}', "\u{04ce}"),
+    ('\u{04d0}', "\u{0430}"),
+    ('\u{04d1}', "\u{0430}"),
+    ('\u{04d2}', "\u{0430}"),
+    ('\u{04d3}', "\u{0430}"),
+    ('\u{04d4}', "\u{04d5}"),
+    ('\u{04d6}', "\u{0435}"),
+    ('\u{04d7}', "\u{0435}"),
+    ('\u{04d8}', "\u{04d9}"),
+    ('\u{04da}', "\u{04d9}"),
+    ('\u{04db}', "\u{04d9}"),
+    ('\u{04dc}', "\u{0436}"),
+    ('\u{04dd}', "\u{0436}"),
+    ('\u{04de}', "\u{0437}"),
+    ('\u{04df}', "\u{0437}"),
+    ('\u{04e0}', "\u{04e1}"),
+    ('\u{04e2}', "\u{0438}"),
+    ('\u{04e3}', "\u{0438}"),
+    ('\u{04e4}', "\u{0438}"),
+    ('\u{04e5}', "\u{0438}"),
+    ('\u{04e6}', "\u{043e}"),
+    ('\u{04e7}', "\u{043e}"),
+    ('\u{04e8}', "\u{04e9}"),
+    ('\u{04ea}', "\u{04e9}"),
+    ('\u{04eb}', "\u{04e9}"),
+    ('\u{04ec}', "\u{044d}"),
+    ('\u{04ed}', "\u{044d}"),
+    ('\u{04ee}', "\u{0443}"),
+    ('\u{04ef}', "\u{0443}"),
+    ('\u{04f0}', "\u{0443}"),
+    ('\u{04f1}', "\u{0443}"),
+    ('\u{04f2}', "\u{0443}"),
+    ('\u{04f3}', "\u{0443}"),
+    ('\u{04f4}', "\u{0447}"),
+    ('\u{04f5}', "\u{0447}"),
+    ('\u{04f6}', "\u{04f7}"),
+    ('\u{04f8}', "\u{044b}"),
+    ('\u{04f9}', "\u{044b}"),
+    ('\u{04fa}', "\u{04fb}"),
+    ('\u{04fc}', "\u{04fd}"),
+    ('\u{04fe}', "\u{04ff}"),
+    ('\u{0500}', "\u{0501}"),
+    ('\u{0502}', "\u{0503}"),
+    ('\u{0504}', "\u{0505}"),
+    ('\u{0506}', "\u{0507}"),
+    ('\u{0508}', "\u{0509}"),
+    ('\u{050a}', "\u{050b}"),
+    ('\u{050c}', "\u{050d}"),
+    ('\u{050e}', "\u{050f}"),
+    ('\u{0510}', "\u{0511}"),
+    ('\u{0512}', "\u{0513}"),
+    ('\u{0514}', "\u{0515}"),
+    ('\u{0516}', "\u{0517}"),
+    ('\u{0518}', "\u{0519}"),
+    ('\u{051a}', "\u{051b}"),
+    ('\u{051c}', "\u{051d}"),
+    ('\u{051e}', "\u{051f}"),
+    ('\u{0520}', "\u{0521}"),
+    ('\u{0522}', "\u{0523}"),
+    ('\u{0524}', "\u{0525}"),
+    ('\u{0526}', "\u{0527}"),
+    ('\u{0528}', "\u{0529}"),
+    ('\u{052a}', "\u{052b}"),
+    ('\u{052c}', "\u{052d}"),
+    ('\u{052e}', "\u{052f}"),
+    ('\u{0531}', "\u{0561}"),
+    ('\u{0532}', "\u{0562}"),
+    ('\u{0533}', "\u{0563}"),
+    ('\u{0534}', "\u{0564}"),
+    ('\u{0535}', "\u{0565}"),
+    ('\u{0536}', "\u{0566}"),
+    ('\u{0537}', "\u{0567}"),
+    ('\u{0538}', "\u{0568}"),
+    ('\u{0539}', "\u{0569}"),
+    ('\u{053a}', "\u{056a}"),
+    ('\u{053b}', "\u{056b}"),
+    ('\u{053c}', "\u{056c}"),
+    ('\u{053d}', "\u{056d}"),
+    ('\u{053e}', "\u{056e}"),
+    ('\u{053f}', "\u{056f}"),
+    ('\u{0540}', "\u{0570}"),
+    ('\u{0541}', "\u{0571}"),
+    ('\u{0542}', "\u{0572}"),
+    ('\u{0543}', "\u{0573}"),
+    ('\u{0544}', "\u{0574}"),
+    ('\u{0545}', "\u{0575}"),
+    ('\u{0546}', "\u{0576}"),
+    ('\u{0547}', "\u{0577}"),
+    ('\u{0548}', "\u{0578}"),
+    ('\u{0549}', "\u{0579}"),
+    ('\u{054a}', "\u{057a}"),
+    ('\u{054b}', "\u{057b}"),
+    ('\u{054c}', "\u{057c}"),
+    ('\u{054d}', "\u{057d}"),
+    ('\u{054e}', "\u{057e}"),
+    ('\u{054f}', "\u{057f}"),
+    ('\u{0550}', "\u{0580}"),
+    ('\u{0551}', "\u{0581}"),
+    ('\u{0552}', "\u{0582}"),
+    ('\u{0553}', "\u{0583}"),
+    ('\u{0554}', "\u{0584}"),
+    ('\u{0555}', "\u{0585}"),
+    ('\u{0556}', "\u{0586}"),
+    ('\u{0587}', "\u{0565}\u{0582}"),
+    ('\u{0622}', "\u{0627}"),
+    ('\u{0623}', "\u{0627}"),
+    ('\u{0624}', "\u{0648}"),
+    ('\u{0625}', "\u{0627}"),
+    ('\u{0626}', "\u{064a}"),
+    ('\u{0675}', "\u{0627}\u{0674}"),
+    ('\u{0676}', "\u{0648}\u{0674}"),
+    ('\u{0677}', "\u{06c7}\u{0674}"),
+    ('\u{0678}', "\u{064a}\u{0674}"),
+    ('\u{06c0}', "\u{06d5}"),
+    ('\u{06c2}', "\u{06c1}"),
+    ('\u{06d3}', "\u{06d2}"),
+    ('\u{0929}', "\u{0928}"),
+    ('\u{0931}', "\u{0930}"),
+    ('\u{0934}', "\u{0933}"),
+    ('\u{0958}', "\u{0915}"),
+    ('\u{0959}', "\u{0916}"),
+    ('\u{095a}', "\u{0917}"),
+    ('\u{095b}', "\u{091c}"),
+    ('\u{095c}', "\u{0921}"),
+    ('\u{095d}', "\u{0922}"),
+    ('\u{095e}', "\u{092b}"),
+    ('\u{095f}', "\u{092f}"),
+    ('\u{09cb}', "\u{09c7}\u{09be}"),
+    ('\u{09cc}', "\u{09c7}\u{09d7}"),
+    ('\u{09dc}', "\u{09a1}"),
+    ('\u{09dd}', "\u{09a2}"),
+    ('\u{09df}', "\u{09af}"),
+    ('\u{0a33}', "\u{0a32}"),
+    ('\u{0a36}', "\u{0a38}"),
+    ('\u{0a59}', "\u{0a16}"),
+    ('\u{0a5a}', "\u{0a17}"),
+    ('\u{0a5b}', "\u{0a1c}"),
+    ('\u{0a5e}', "\u{0a2b}"),
+    ('\u{0b48}', "\u{0b47}"),
+    ('\u{0b4b}', "\u{0b47}\u{0b3e}"),
+    ('\u{0b4c}', "\u{0b47}\u{0b57}"),
+    ('\u{0b5c}', "\u{0b21}"),
+    ('\u{0b5d}', "\u{0b22}"),
+    ('\u{0b94}', "\u{0b92}\u{0bd7}"),
+    ('\u{0bca}', "\u{0bc6}\u{0bbe}"),
+    ('\u{0bcb}', "\u{0bc7}\u{0bbe}"),
+    ('\u{0bcc}', "\u{0bc6}\u{0bd7}"),
+    ('\u{0cc0}', "\u{0cd5}"),
+    ('\u{0cc7}', "\u{0cd5}"),
+    ('\u{0cc8}', "\u{0cd6}"),
+    ('\u{0cca}', "\u{0cc2}"),
+    ('\u{0ccb}', "\u{0cc2}\u{0cd5}"),
+    ('\u{0d4a}', "\u{0d46}\u{0d3e}"),
+    ('\u{0d4b}', "\u{0d47}\u{0d3e}"),
+    ('\u{0d4c}', "\u{0d46}\u{0d57}"),
+    ('\u{0dda}', "\u{0dd9}"),
+    ('\u{0ddc}', "\u{0dd9}\u{0dcf}"),
+    ('\u{0ddd}', "\u{0dd9}\u{0dcf}"),
+    ('\u{0dde}', "\u{0dd9}\u{0ddf}"),
+    ('\u{0e33}', "\u{0e32}"),
+    ('\u{0eb3}', "\u{0eb2}"),
+    ('\u{0edc}', "\u{0eab}\u{0e99}"),
+    ('\u{0edd}', "\u{0eab}\u{0ea1}"),
+    ('\u{0f0c}', "\u{0f0b}"),
+    ('\u{0f43}', "\u{0f42}"),
+    ('\u{0f4d}', "\u{0f4c}"),
+    ('\u{0f52}', "\u{0f51}"),
+    ('\u{0f57}', "\u{0f56}"),
+    ('\u{0f5c}', "\u{0f5b}"),
+    ('\u{0f69}', "\u{0f40}"),
+    ('\u{1026}', "\u{1025}"),
+    ('\u{10a0}', "\u{2d00}"),
+    ('\u{10a1}', "\u{2d01}"),
+    ('\u{10a2}', "\u{2d02}"),
+    ('\u{10a3}', "\u{2d03}"),
+    ('\u{10a4}', "\u{2d04}"),
+    ('\u{10a5}', "\u{2d05}"),
+    ('\u{10a6}', "\u{2d06}"),
+    ('\u{10a7}', "\u{2d07}"),
+    ('\u{10a8}', "\u{2d08}"),
+    ('\u{10a9}', "\u{2d09}"),
+    ('\u{10aa}', "\u{2d0a}"),
+    ('\u{10ab}', "\u{2d0b}"),
+    ('\u{10ac}', "\u{2d0c}"),
+    ('\u{10ad}', "\u{2d0d}"),
+    ('\u{10ae}', "\u{2d0e}"),
+    ('\u{10af}', "\u{2d0f}"),
+    ('\u{10b0}', "\u{2d10}"),
+    ('\u{10b1}', "\u{2d11}"),
+    ('\u{10b2}', "\u{2d12}"),
+    ('\u{10b3}', "\u{2d13}"),
+    ('\u{10b4}', "\u{2d14}"),
+    ('\u{10b5}', "\u{2d15}"),
+    ('\u{10b6}', "\u{2d16}"),
+    ('\u{10b7}', "\u{2d17}"),
+    ('\u{10b8}', "\u{2d18}"),
+    ('\u{10b9}', "\u{2d19}"),
+    ('\u{10ba}', "\u{2d1a}"),
+    ('\u{10bb}', "\u{2d1b}"),
+    ('\u{10bc}', "\u{2d1c}"),
+    ('\u{10bd}', "\u{2d1d}"),
+    ('\u{10be}', "\u{2d1e}"),
+    ('\u{10bf}', "\u{2d1f}"),
+    ('\u{10c0}', "\u{2d20}"),
+    ('\u{10c1}', "\u{2d21}"),
+    ('\u{10c2}', "\u{2d22}"),
+    ('\u{10c3}', "\u{2d23}"),
+    ('\u{10c4}', "\u{2d24}"),
+    ('\u{10c5}', "\u{2d25}"),
+    ('\u{10c7}', "\u{2d27}"),
+    ('\u{10cd}', "\u{2d2d}"),
+    ('\u{10fc}', "\u{10dc}"),
+    ('\u{13f8}', "\u{13f0}"),
+    ('\u{13f9}', "\u{13f1}"),
+    ('\u{13fa}', "\u{13f2}"),
+    ('\u{13fb}', "\u{13f3}"),
+    ('\u{13fc}', "\u{13f4}"),
+    ('\u{13fd}', "\u{13f5}"),
+    ('\u{1b06}', "\u{1b05}\u{1b35}"),
+    ('\u{1b08}', "\u{1b07}\u{1b35}"),
+    ('\u{1b0a}', "\u{1b09}\u{1b35}"),
+    ('\u{1b0c}', "\u{1b0b}\u{1b35}"),
+    ('\u{1b0e}', "\u{1b0d}\u{1b35}"),
+    ('\u{1b12}', "\u{1b11}\u{1b35}"),
+    ('\u{1b3b}', "\u{1b35}"),
+    ('\u{1b3d}', "\u{1b35}"),
+    ('\u{1b40}', "\u{1b3e}\u{1b35}"),
+    ('\u{1b41}', "\u{1b3f}\u{1b35}"),
+    ('\u{1b43}', "\u{1b35}"),
+    ('\u{1c80}', "\u{0432}"),
+    ('\u{1c81}', "\u{0434}"),
+    ('\u{1c82}', "\u{043e}"),
+    ('\u{1c83}', "\u{0441}"),
+    ('\u{1c84}', "\u{0442}"),
+    ('\u{1c85}', "\u{0442}"),
+    ('\u{1c86}', "\u{044a}"),
+    ('\u{1c87}', "\u{0463}"),
+    ('\u{1c88}', "\u{a64b}"),
+    ('\u{1c90}', "\u{10d0}"),
+    ('\u{1c91}', "\u{10d1}"),
+    ('\u{1c92}', "\u{10d2}"),
+    ('\u{1c93}', "\u{10d3}"),
+    ('\u{1c94}', "\u{10d4}"),
+    ('\u{1c95}', "\u{10d5}"),
+    ('\u{1c96}', "\u{10d6}"),
+    ('\u{1c97}', "\u{10d7}"),
+    ('\u{1c98}', "\u{10d8}"),
+    ('\u{1c99}', "\u{10d9}"),
+    ('\u{1c9a}', "\u{10da}"),
+    ('\u{1c9b}', "\u{10db}"),
+    ('\u{1c9c}', "\u{10dc}"),
+    ('\u{1c9d}', "\u{10dd}"),
+    ('\u{1c9e}', "\u{10de}"),
+    ('\u{1c9f}', "\u{10df}"),
+    ('\u{1ca0}', "\u{10e0}"),
+    ('\u{1ca1}', "\u{10e1}"),
+    ('\u{1ca2}', "\u{10e2}"),
+    ('\u{1ca3}', "\u{10e3}"),
+    ('\u{1ca4}', "\u{10e4}"),
+    ('\u{1ca5}', "\u{10e5}"),
+    ('\u{1ca6}', "\u{10e6}"),
+    ('\u{1ca7}', "\u{10e7}"),
+    ('\u{1ca8}', "\u{10e8}"),
+    ('\u{1ca9}', "\u{10e9}"),
+    ('\u{1caa}', "\u{10ea}"),
+    ('\u{1cab}', "\u{10eb}"),
+    ('\u{1cac}', "\u{10ec}"),
+    ('\u{1cad}', "\u{10ed}"),
+    ('\u{1cae}', "\u{10ee}"),
+    ('\u{1caf}', "\u{10ef}"),
+    ('\u{1cb0}', "\u{10f0}"),
+    ('\u{1cb1}', "\u{10f1}"),
+    ('\u{1cb2}', "\u{10f2}"),
+    ('\u{1cb3}', "\u{10f3}"),
+    ('\u{1cb4}', "\u{10f4}"),
+    ('\u{1cb5}', "\u{10f5}"),
+    ('\u{1cb6}', "\u{10f6}"),
+    ('\u{1cb7}', "\u{10f7}"),
+    ('\u{1cb8}', "\u{10f8}"),
+    ('\u{1cb9}', "\u{10f9}"),
+    ('\u{1cba}', "\u{10fa}"),
+    ('\u{1cbd}', "\u{10fd}"),
+    ('\u{1cbe}', "\u{10fe}"),
+    ('\u{1cbf}', "\u{10ff}"),
+    ('\u{1d2c}', "a"),
+    ('\u{1d2d}', "ae"),
+    ('\u{1d2e}', "b"),
+    ('\u{1d30}', "d"),
+    ('\u{1d31}', "e"),
+    ('\u{1d32}', "\u{01dd}"),
+    ('\u{1d33}', "g"),
+    ('\u{1d34}', "h"),
+    ('\u{1d35}', "i"),
+    ('\u{1d36}', "j"),
+    ('\u{1d37}', "k"),
+    ('\u{1d38}', "l"),
+    ('\u{1d39}', "m"),
+    ('\u{1d3a}', "n"),
+    ('\u{1d3c}', "o"),
+    ('\u{1d3d}', "\u{0223}"),
+    ('\u{1d3e}', "p"),
+    ('\u{1d3f}', "r"),
+    ('\u{1d40}', "t"),
+    ('\u{1d41}', "u"),
+    ('\u{1d42}', "w"),
+    ('\u{1d43}', "a"),
+    ('\u{1d44}', "\u{0250}"),
+    ('\u{1d45}', "\u{0251}"),
+    ('\u{1d46}', "\u{1d02}"),
+    ('\u{1d47}', "b"),
+    ('\u{1d48}', "d"),
+    ('\u{1d49}', "e"),
+    ('\u{1d4a}', "\u{0259}"),
+    ('\u{1d4b}', "\u{025b}"),
+    ('\u{1d4c}', "\u{025c}"),
+    ('\u{1d4d}', "g"),
+    ('\u{1d4f}', "k"),
+    ('\u{1d50}', "m"),
+    ('\u{1d51}', "\u{014b}"),
+    ('\u{1d52}', "o"),
+    ('\u{1d53}', "\u{0254}"),
+    ('\u{1d54}', "\u{1d16}"),
+    ('\u{1d55}', "\u{1d17}"),
+    ('\u{1d56}', "p"),
+    ('\u{1d57}', "t"),
+    ('\u{1d58}', "u"),
+    ('\u{1d59}', "\u{1d1d}"),
+    ('\u{1d5a}', "\u{026f}"),
+    ('\u{1d5b}', "v"),
+    ('\u{1d5c}', "\u{1d25}"),
+    ('\u{1d5d}', "\u{03b2}"),
+    ('\u{1d5e}', "\u{03b3}"),
+    ('\u{1d5f}', "\u{03b4}"),
+    ('\u{1d60}', "\u{03c6}"),
+    ('\u{1d61}', "\u{03c7}"),
+    ('\u{1d62}', "i"),
+    ('\u{1d63}', "r"),
+    ('\u{1d64}', "u"),
+    ('\u{1d65}', "v"),
+    ('\u{1d66}', "\u{03b2}"),
+    ('\u{1d67}', "\u{03b3}"),
+    ('\u{1d68}', "\u{03c1}"),
+    ('\u{1d69}', "\u{03c6}"),
+    ('\u{1d6a}', "\u{03c7}"),
+    ('\u{1d78}', "\u{043d}"),
+    ('\u{1d9b}', "\u{0252}"),
+    ('\u{1d9c}', "c"),
+    ('\u{1d9d}', "\u{0255}"),
+    ('\u{1d9e}', "\u{00f0}"),
+    ('\u{1d9f}', "\u{025c}"),
+    ('\u{1da0}', "f"),
+    ('\u{1da1}', "\u{025f}"),
+    ('\u{1da2}', "\u{0261}"),
+    ('\u{1da3}', "\u{0265}"),
+    ('\u{1da4}', "\u{0268}"),
+    ('\u{1da5}', "\u{0269}"),
+    ('\u{1da6}', "\u{026a}"),
+    ('\u{1da7}', "\u{1d7b}"),
+    ('\u{1da8}', "\u{029d}"),
+    ('\u{1da9}', "\u{026d}"),
+    ('\u{1daa}', "\u{1d85}"),
+    ('\u{1dab}', "\u{029f}"),
+    ('\u{1dac}', "\u{0271}"),
+    ('\u{1dad}', "\u{0270}"),
+    ('\u{1dae}', "\u{0272}"),
+    ('\u{1daf}', "\u{0273}"),
+    ('\u{1db0}', "\u{0274}"),
+    ('\u{1db1}', "\u{0275}"),
+    ('\u{1db2}', "\u{0278}"),
+    ('\u{1db3}', "\u{0282}"),
+    ('\u{1db4}', "\u{0283}"),
+    ('\u{1db5}', "\u{01ab}"),
+    ('\u{1db6}', "\u{0289}"),
+    ('\u{1db7}', "\u{028a}"),
+    ('\u{1db8}', "\u{1d1c}"),
+    ('\u{1db9}', "\u{028b}"),
+    ('\u{1dba}', "\u{028c}"),
+    ('\u{1dbb}', "z"),
+    ('\u{1dbc}', "\u{0290}"),
+    ('\u{1dbd}', "\u{0291}"),
+    ('\u{1dbe}', "\u{0292}"),
+    ('\u{1dbf}', "\u{03b8}"),
+    ('\u{1e00}', "a"),
+    ('\u{1e01}', "a"),
+    ('\u{1e02}', "b"),
+    ('\u{1e03}', "b"),
+    ('\u{1e04}', "b"),
+    ('\u{1e05}', "b"),
+    ('\u{1e06}', "b"),
+    ('\u{1e07}', "b"),
+    ('\u{1e08}', "c"),
+    ('\u{1e09}', "c"),
+    ('\u{1e0a}', "d"),
+    ('\u{1e0b}', "d"),
+    ('\u{1e0c}', "d"),
+    ('\u{1e0d}', "d"),
+    ('\u{1e0e}', "d"),
+    ('\u{1e0f}', "d"),
+    ('\u{1e10}', "d"),
+    ('\u{1e11}', "d"),
+    ('\u{1e12}', "d"),
+    ('\u{1e13}', "d"),
+    ('\u{1e14}', "e"),
+    ('\u{1e15}', "e"),
+    ('\u{1e16}', "e"),
+    ('\u{1e17}', "e"),
+    ('\u{1e18}', "e"),
+    ('\u{1e19}', "e"),
+    ('\u{1e1a}', "e"),
+    ('\u{1e1b}', "e"),
+    ('\u{1e1c}', "e"),
+    ('\u{1e1d}', "e"),
+    ('\u{1e1e}', "f"),
+    ('\u{1e1f}', "f"),
+    ('\u{1e20}', "g"),
+    ('\u{1e21}', "g"),
+    ('\u{1e22}', "h"),
+    ('\u{1e23}', "h"),
+    ('\u{1e24}', "h"),
+    ('\u{1e25}', "h"),
+    ('\u{1e26}', "h"),
+    ('\u{1e27}', "h"),
+    ('\u{1e28}', "h"),
+    ('\u{1e29}', "h"),
+    ('\u{1e2a}', "h"),
+    ('\u{1e2b}', "h"),
+    ('\u{1e2c}', "i"),
+    ('\u{1e2d}', "i"),
+    ('\u{1e2e}', "i"),
+    ('\u{1e2f}', "i"),
+    ('\u{1e30}', "k"),
+    ('\u{1e31}', "k"),
+    ('\u{1e32}', "k"),
+    ('\u{1e33}', "k"),
+    ('\u{1e34}', "k"),
+    ('\u{1e35}', "k"),
+    ('\u{1e36}', "l"),
+    ('\u{1e37}', "l"),
+    ('\u{1e38}', "l"),
+    ('\u{1e39}', "l"),
+    ('\u{1e3a}', "l"),
+    ('\u{1e3b}', "l"),
+    ('\u{1e3c}', "l"),
+    ('\u{1e3d}', "l"),
+    ('\u{1e3e}', "m"),
+    ('\u{1e3f}', "m"),
+    ('\u{1e40}', "m"),
+    ('\u{1e41}', "m"),
+    ('\u{1e42}', "m"),
+    ('\u{1e43}', "m"),
+    ('\u{1e44}', "n"),
+    ('\u{1e45}', "n"),
+    ('\u{1e46}', "n"),
+    ('\u{1e47}', "n"),
+    ('\u{1e48}', "n"),
+    ('\u{1e49}', "n"),
+    ('\u{1e4a}', "n"),
+    ('\u{1e4b}', "n"),
+    ('\u{1e4c}', "o"),
+    ('\u{1e4d}', "o"),
+    ('\u{1e4e}', "o"),
+    ('\u{1e4f}', "o"),
+    ('\u{1e50}', "o"),
+    ('\u{1e51}', "o"),
+    ('\u{1e52}', "o"),
+    ('\u{1e53}', "o"),
+    ('\u{1e54}', "p"),
+    ('\u{1e55}', "p"),
+    ('\u{1e56}', "p"),
+    ('\u{1e57}', "p"),
+    ('\u{1e58}', "r"),
+    ('\u{1e59}', "r"),
+    ('\u{1e5a}', "r"),
+    ('\u{1e5b}', "r"),
+    ('\u{1e5c}', "r"),
+    ('\u{1e5d}', "r"),
+    ('\u{1e5e}', "r"),
+    ('\u{1e5f}', "r"),
+    ('\u{1e60}', "s"),
+    ('\u{1e61}', "s"),
+    ('\u{1e62}', "s"),
+    ('\u{1e63}', "s"),
+    ('\u{1e64}', "s"),
+    ('\u{1e65}', "s"),
+    ('\u{1e66}', "s"),
+    ('\u{1e67}', "s"),
+    ('\u{1e68}', "s"),
+    ('\u{1e69}', "s"),
+    ('\u{1e6a}', "t"),
+    ('\u{1e6b}', "t"),
+    ('\u{1e6c}', "t"),
+    ('\u{1e6d}', "t"),
+    ('\u{1e6e}', "t"),
+    ('\u{1e6f}', "t"),
+    ('\u{1e70}', "t"),
+    ('\u{1e71}', "t"),
+    ('\u{1e72}', "u"),
+    ('\u{1e73}', "u"),
+    ('\u{1e74}', "u"),
+    ('\u{1e75}', "u"),
+    ('\u{1e76}', "u"),
+    ('\u{1e77}', "u"),
+    ('\u{1e78}', "u"),
+    ('\u{1e79}', "u"),
+    ('\u{1e7a}', "u"),
+    ('\u{1e7b}', "u"),
+    ('\u{1e7c}', "v"),
+    ('\u{1e7d}', "v"),
+    ('\u{1e7e}', "v"),
+    ('\u{1e7f}', "v"),
+    ('\u{1e80}', "w"),
+    ('\u{1e81}', "w"),
+    ('\u{1e82}', "w"),
+    ('\u{1e83}', "w"),
+    ('\u{1e84}', "w"),
+    ('\u{1e85}', "w"),
+    ('\u{1e86}', "w"),
+    ('\u{1e87}', "w"),
+    ('\u{1e88}', "w"),
+    ('\u{1e89}', "w"),
+    ('\u{1e8a}', "x"),
+    ('\u{1e8b}', "x"),
+    ('\u{1e8c}', "x"),
+    ('\u{1e8d}', "x"),
+    ('\u{1e8e}', "y"),
+    ('\u{1e8f}', "y"),
+    ('\u{1e90}', "z"),
+    ('\u{1e91}', "z"),
+    ('\u{1e92}', "z"),
+    ('\u{1e93}', "z"),
+    ('\u{1e94}', "z"),
+    ('\u{1e95}', "z"),
+    ('\u{1e96}', "h"),
+    ('\u{1e97}', "t"),
+    ('\u{1e98}', "w"),
+    ('\u{1e99}', "y"),
+    ('\u{1e9a}', "\u{0061}\u{02be}"),
+    ('\u{1e9b}', "s"),
+    ('\u{1e9e}', "ss"),
+    ('\u{1ea0}', "a"),
+    ('\u{1ea1}', "a"),
+    ('\u{1ea2}', "a"),
+    ('\u{1ea3}', "a"),
+    ('\u{1ea4}', "a"),
+    ('\u{1ea5}', "a"),
+    ('\u{1ea6}', "a"),
+    ('\u{1ea7}', "a"),
+    ('\u{1ea8}', "a"),
+    ('\u{1ea9}', "a"),
+    ('\u{1eaa}', "a"),
+    ('\u{1eab}', "a"),
+    ('\u{1eac}', "a"),
+    ('\u{1ead}', "a"),
+    ('\u{1eae}', "a"),
+    ('\u{1eaf}', "a"),
+    ('\u{1eb0}', "a"),
+    ('\u{1eb1}', "a"),
+    ('\u{1eb2}', "a"),
+    ('\u{1eb3}', "a"),
+    ('\u{1eb4}', "a"),
+    ('\u{1eb5}', "a"),
+    ('\u{1eb6}', "a"),
+    ('\u{1eb7}', "a"),
+    ('\u{1eb8}', "e"),
+    ('\u{1eb9}', "e"),
+    ('\u{1eba}', "e"),
+    ('\u{1ebb}', "e"),
+    ('\u{1ebc}', "e"),
+    ('\u{1ebd}', "e"),
+    ('\u{1ebe}', "e"),
+    ('\u{1ebf}', "e"),
+    ('\u{1ec0}', "e"),
+    ('\u{1ec1}', "e"),
+    ('\u{1ec2}', "e"),
+    ('\u{1ec3}', "e"),
+    ('\u{1ec4}', "e"),
+    ('\u{1ec5}', "e"),
+    ('\u{1ec6}', "e"),
+    ('\u{1ec7}', "e"),
+    ('\u{1ec8}', "i"),
+    ('\u{1ec9}', "i"),
+    ('\u{1eca}', "i"),
+    ('\u{1ecb}', "i"),
+    ('\u{1ecc}', "o"),
+    ('\u{1ecd}', "o"),
+    ('\u{1ece}', "o"),
+    ('\u{1ecf}', "o"),
+    ('\u{1ed0}', "o"),
+    ('\u{1ed1}', "o"),
+    ('\u{1ed2}', "o"),
+    ('\u{1ed3}', "o"),
+    ('\u{1ed4}', "o"),
+    ('\u{1ed5}', "o"),
+    ('\u{1ed6}', "o"),
+    ('\u{1ed7}', "o"),
+    ('\u{1ed8}', "o"),
+    ('\u{1ed9}', "o"),
+    ('\u{1eda}', "o"),
+    ('\u{1edb}', "o"),
+    ('\u{1edc}', "o"),
+    ('\u{1edd}', "o"),
+    ('\u{1ede}', "o"),
+    ('\u{1edf}', "o"),
+    ('\u{1ee0}', "o"),
+    ('\u{1ee1}', "o"),
+    ('\u{1ee2}', "o"),
+    ('\u{1ee3}', "o"),
+    ('\u{1ee4}', "u"),
+    ('\u{1ee5}', "u"),
+    ('\u{1ee6}', "u"),
+    ('\u{1ee7}', "u"),
+    ('\u{1ee8}', "u"),
+    ('\u{1ee9}', "u"),
+    ('\u{1eea}', "u"),
+    ('\u{1eeb}', "u"),
+    ('\u{1eec}', "u"),
+    ('\u{1eed}', "u"),
+    ('\u{1eee}', "u"),
+    ('\u{1eef}', "u"),
+    ('\u{1ef0}', "u"),
+    ('\u{1ef1}', "u"),
+    ('\u{1ef2}', "y"),
+    ('\u{1ef3}', "y"),
+    ('\u{1ef4}', "y"),
+    ('\u{1ef5}', "y"),
+    ('\u{1ef6}', "y"),
+    ('\u{1ef7}', "y"),
+    ('\u{1ef8}', "y"),
+    ('\u{1ef9}', "y"),
+    ('\u{1efa}', "\u{1efb}"),
+    ('\u{1efc}', "\u{1efd}"),
+    ('\u{1efe}', "\u{1eff}"),
+    ('\u{1f00}', "\u{03b1}"),
+    ('\u{1f01}', "\u{03b1}"),
+    ('\u{1f02}', "\u{03b1}"),
+    ('\u{1f03}', "\u{03b1}"),
+    ('\u{1f04}', "\u{03b1}"),
+    ('\u{1f05}', "\u{03b1}"),
+    ('\u{1f06}', "\u{03b1}"),
+    ('\u{1f07}', "\u{03b1}"),
+    ('\u{1f08}', "\u{03b1}"),
+    ('\u{1f09}', "\u{03b1}"),
+    ('\u{1f0a}', "\u{03b1}"),
+    ('\u{1f0b}', "\u{03b1}"),
+    ('\u{1f0c}', "\u{03b1}"),
+    ('\u{1f0d}', "\u{03b1}"),
+    ('\u{1f0e}', "\u{03b1}"),
+    ('\u{1f0f}', "\u{03b1}"),
+    ('\u{1f10}', "\u{03b5}"),
+    ('\u{1f11}', "\u{03b5}"),
+    ('\u{1f12}', "\u{03b5}"),
+    ('\u{1f13}', "\u{03b5}"),
+    ('\u{1f14}', "\u{03b5}"),
+    ('\u{1f15}', "\u{03b5}"),
+    ('\u{1f18}', "\u{03b5}"),
+    ('\u{1f19}', "\u{03b5}"),
+    ('\u{1f1a}', "\u{03b5}"),
+    ('\u{1f1b}', "\u{03b5}"),
+    ('\u{1f1c}', "\u{03b5}"),
+    ('\u{1f1d}', "\u{03b5}"),
+    ('\u{1f20}', "\u{03b7}"),
+    ('\u{1f21}', "\u{03b7}"),
+    ('\u{1f22}', "\u{03b7}"),
+    ('\u{1f23}', "\u{03b7}"),
+    ('\u{1f24}', "\u{03b7}"),
+    ('\u{1f25}', "\u{03b7}"),
+    ('\u{1f26}', "\u{03b7}"),
+    ('\u{1f27}', "\u{03b7}"),
+    ('\u{1f28}', "\u{03b7}"),
+    ('\u{1f29}', "\u{03b7}"),
+    ('\u{1f2a}', "\u{03b7}"),
+    ('\u{1f2b}', "\u{03b7}"),
+    ('\u{1f2c}', "\u{03b7}"),
+    ('\u{1f2d}', "\u{03b7}"),
+    ('\u{1f2e}', "\u{03b7}"),
+    ('\u{1f2f}', "\u{03b7}"),
+    ('\u{1f30}', "\u{03b9}"),
+    ('\u{1f31}', "\u{03b9}"),
+    ('\u{1f32}', "\u{03b9}"),
+    ('\u{1f33}', "\u{03b9}"),
+    ('\u{1f34}', "\u{03b9}"),
+    ('\u{1f35}', "\u{03b9}"),
+    ('\u{1f36}', "\u{03b9}"),
+    ('\u{1f37}', "\u{03b9}"),
+    ('\u{1f38}', "\u{03b9}"),
+    ('\u{1f39}', "\u{03b9}"),
+    ('\u{1f3a}', "\u{03b9}"),
+    ('\u{1f3b}', "\u{03b9}"),
+    ('\u{1f3c}', "\u{03b9}"),
+    ('\u{1f3d}', "\u{03b9}"),
+    ('\u{1f3e}', "\u{03b9}"),
+    ('\u{1f3f}', "\u{03b9}"),
+    ('\u{1f40}', "\u{03bf}"),
+    ('\u{1f41}', "\u{03bf}"),
+    ('\u{1f42}', "\u{03bf}"),
+    ('\u{1f43}', "\u{03bf}"),
+    ('\u{1f44}', "\u{03bf}"),
+    ('\u{1f45}', "\u{03bf}"),
+    ('\u{1f48}', "\u{03bf}"),
+    ('\u{1f49}', "\u{03bf}"),
+    ('\u{1f4a}', "\u{03bf}"),
+    ('\u{1f4b}', "\u{03bf}"),
+    ('\u{1f4c}', "\u{03bf}"),
+    ('\u{1f4d}', "\u{03bf}"),
+    ('\u{1f50}', "\u{03c5}"),
+    ('\u{1f51}', "\u{03c5}"),
+    ('\u{1f52}', "\u{03c5}"),
+    ('\u{1f53}', "\u{03c5}"),
+    ('\u{1f54}', "\u{03c5}"),
+    ('\u{1f55}', "\u{03c5}"),
+    ('\u{1f56}', "\u{03c5}"),
+    ('\u{1f57}', "\u{03c5}"),
+    ('\u{1f59}', "\u{03c5}"),
+    ('\u{1f5b}', "\u{03c5}"),
+    ('\u{1f5d}', "\u{03c5}"),
+    ('\u{1f5f}', "\u{03c5}"),
+    ('\u{1f60}', "\u{03c9}"),
+    ('\u{1f61}', "\u{03c9}"),
+    ('\u{1f62}', "\u{03c9}"),
+    ('\u{1f63}', "\u{03c9}"),
+    ('\u{1f64}', "\u{03c9}"),
+    ('\u{1f65}', "\u{03c9}"),
+    ('\u{1f66}', "\u{03c9}"),
+    ('\u{1f67}', "\u{03c9}"),
+    ('\u{1f68}', "\u{03c9}"),
+    ('\u{1f69}', "\u{03c9}"),
+    ('\u{1f6a}', "\u{03c9}"),
+    ('\u{1f6b}', "\u{03c9}"),
+    ('\u{1f6c}', "\u{03c9}"),
+    ('\u{1f6d}', "\u{03c9}"),
+    ('\u{1f6e}', "\u{03c9}"),
+    ('\u{1f6f}', "\u{03c9}"),
+    ('\u{1f70}', "\u{03b1}"),
+    ('\u{1f71}', "\u{03b1}"),
+    ('\u{1f72}', "\u{03b5}"),
+    ('\u{1f73}', "\u{03b5}"),
+    ('\u{1f74}', "\u{03b7}"),
+    ('\u{1f75}', "\u{03b7}"),
+    ('\u{1f76}', "\u{03b9}"),
+    ('\u{1f77}', "\u{03b9}"),
+    ('\u{1f78}', "\u{03bf}"),
+    ('\u{1f79}', "\u{03bf}"),
+    ('\u{1f7a}', "\u{03c5}"),
+    ('\u{1f7b}', "\u{03c5}"),
+    ('\u{1f7c}', "\u{03c9}"),
+    ('\u{1f7d}', "\u{03c9}"),
+    ('\u{1f80}', "\u{03b1}\u{03b9}"),
+    ('\u{1f81}', "\u{03b1}\u{03b9}"),
+    ('\u{1f82}', "\u{03b1}\u{03b9}"),
+    ('\u{1f83}', "\u{03b1}\u{03b9}"),
+    ('\u{1f84}', "\u{03b1}\u{03b9}"),
+    ('\u{1f85}', "\u{03b1}\u{03b9}"),
+    ('\u{1f86}', "\u{03b1}\u{03b9}"),
+    ('\u{1f87}', "\u{03b1}\u{03b9}"),
+    ('\u{1f88}', "\u{03b1}\u{03b9}"),
+    ('\u{1f89}', "\u{03b1}\u{03b9}"),
+    ('\u{1f8a}', "\u{03b1}\u{03b9}"),
+    ('\u{1f8b}', "\u{03b1}\u{03b9}"),
+    ('\u{1f8c}', "\u{03b1}\u{03b9}"),
+    ('\u{1f8d}', "\u{03b1}\u{03b9}"),
+    ('\u{1f8e}', "\u{03b1}\u{03b9}"),
+    ('\u{1f8f}', "\u{03b1}\u{03b9}"),
+    ('\u{1f90}', "\u{03b7}\u{03b9}"),
+    ('\u{1f91}', "\u{03b7}\u{03b9}"),
+    ('\u{1f92}', "\u{03b7}\u{03b9}"),
+    ('\u{1f93}', "\u{03b7}\u{03b9}"),
+    ('\u{1f94}', "\u{03b7}\u{03b9}"),
+    ('\u{1f95}', "\u{03b7}\u{03b9}"),
+    ('\u{1f96}', "\u{03b7}\u{03b9}"),
+    ('\u{1f97}', "\u{03b7}\u{03b9}"),
+    ('\u{1f98}', "\u{03b7}\u{03b9}"),
+    ('\u{1f99}', "\u{03b7}\u{03b9}"),
+    ('\u{1f9a}', "\u{03b7}\u{03b9}"),
+    ('\u{1f9b}', "\u{03b7}\u{03b9}"),
+    ('\u{1f9c}', "\u{03b7}\u{03b9}"),
+    ('\u{1f9d}', "\u{03b7}\u{03b9}"),
+    ('\u{1f9e}', "\u{03b7}\u{03b9}"),
+    ('\u{1f9f}', "\u{03b7}\u{03b9}"),
+    ('\u{1fa0}', "\u{03c9}\u{03b9}"),
+    ('\u{1fa1}', "\u{03c9}\u{03b9}"),
+    ('\u{1fa2}', "\u{03c9}\u{03b9}"),
+    ('\u{1fa3}', "\u{03c9}\u{03b9}"),
+    ('\u{1fa4}', "\u{03c9}\u{03b9}"),
+    ('\u{1fa5}', "\u{03c9}\u{03b9}"),
+    ('\u{1fa6}', "\u{03c9}\u{03b9}"),
+    ('\u{1fa7}', "\u{03c9}\u{03b9}"),
+    ('\u{1fa8}', "\u{03c9}\u{03b9}"),
+    ('\u{1fa9}', "\u{03c9}\u{03b9}"),
+    ('\u{1faa}', "\u{03c9}\u{03b9}"),
+    ('\u{1fab}', "\u{03c9}\u{03b9}"),
+    ('\u{1fac}', "\u{03c9}\u{03b9}"),
+    ('\u{1fad}', "\u{03c9}\u{03b9}"),
+    ('\u{1fae}', "\u{03c9}\u{03b9}"),
+    ('\u{1faf}', "\u{03c9}\u{03b9}"),
+    ('\u{1fb0}', "\u{03b1}"),
+    ('\u{1fb1}', "\u{03b1}"),
+    ('\u{1fb2}', "\u{03b1}\u{03b9}"),
+    ('\u{1fb3}', "\u{03b1}\u{03b9}"),
+    ('\u{1fb4}', "\u{03b1}\u{03b9}"),
+    ('\u{1fb6}', "\u{03b1}"),
+    ('\u{1fb7}', "\u{03b1}\u{03b9}"),
+    ('\u{1fb8}', "\u{03b1}"),
+    ('\u{1fb9}', "\u{03b1}"),
+    ('\u{1fba}', "\u{03b1}"),
+    ('\u{1fbb}', "\u{03b1}"),
+    ('\u{1fbc}', "\u{03b1}\u{03b9}"),
+    ('\u{1fbd}', "\u{0020}"),
+    ('\u{1fbe}', "\u{03b9}"),
+    ('\u{1fbf}', "\u{0020}"),
+    ('\u{1fc0}', "\u{0020}"),
+    ('\u{1fc1}', "\u{0020}"),
+    ('\u{1fc2}', "\u{03b7}\u{03b9}"),
+    ('\u{1fc3}', "\u{03b7}\u{03b9}"),
+    ('\u{1fc4}', "\u{03b7}\u{03b9}"),
+    ('\u{1fc6}', "\u{03b7}"),
+    ('\u{1fc7}', "\u{03b7}\u{03b9}"),
+    ('\u{1fc8}', "\u{03b5}"),
+    ('\u{1fc9}', "\u{03b5}"),
+    ('\u{1fca}', "\u{03b7}"),
+    ('\u{1fcb}', "\u{03b7}"),
+    ('\u{1fcc}', "\u{03b7}\u{03b9}"),
+    ('\u{1fcd}', "\u{0020}"),
+    ('\u{1fce}', "\u{0020}"),
+    ('\u{1fcf}', "\u{0020}"),
+    ('\u{1fd0}', "\u{03b9}"),
+    ('\u{1fd1}', "\u{03b9}"),
+    ('\u{1fd2}', "\u{03b9}"),
+    ('\u{1fd3}', "\u{03b9}"),
+    ('\u{1fd6}', "\u{03b9}"),
+    ('\u{1fd7}', "\u{03b9}"),
+    ('\u{1fd8}', "\u{03b9}"),
+    ('\u{1fd9}', "\u{03b9}"),
+    ('\u{1fda}', "\u{03b9}"),
+    ('\u{1fdb}', "\u{03b9}"),
+    ('\u{1fdd}', "\u{0020}"),
+    ('\u{1fde}', "\u{0020}"),
+    ('\u{1fdf}', "\u{0020}"),
+    ('\u{1fe0}', "\u{03c5}"),
+    ('\u{1fe1}', "\u{03c5}"),
+    ('\u{1fe2}', "\u{03c5}"),
+    ('\u{1fe3}', "\u{03c5}"),
+    ('\u{1fe4}', "\u{03c1}"),
+    ('\u{1fe5}', "\u{03c1}"),
+    ('\u{1fe6}', "\u{03c5}"),
+    ('\u{1fe7}', "\u{03c5}"),
+    ('\u{1fe8}', "\u{03c5}"),
+    ('\u{1fe9}', "\u{03c5}"),
+    ('\u{1fea}', "\u{03c5}"),
+    ('\u{1feb}', "\u{03c5}"),
+    ('\u{1fec}', "\u{03c1}"),
+    ('\u{1fed}', "\u{0020}"),
+    ('\u{1fee}', "\u{0020}"),
+    ('\u{1fef}', "\u{0060}"),
+    ('\u{1ff2}', "\u{03c9}\u{03b9}"),
+    ('\u{1ff3}', "\u{03c9}\u{03b9}"),
+    ('\u{1ff4}', "\u{03c9}\u{03b9}"),
+    ('\u{1ff6}', "\u{03c9}"),
+    ('\u{1ff7}', "\u{03c9}\u{03b9}"),
+    ('\u{1ff8}', "\u{03bf}"),
+    ('\u{1ff9}', "\u{03bf}"),
+    ('\u{1ffa}', "\u{03c9}"),
+    ('\u{1ffb}', "\u{03c9}"),
+    ('\u{1ffc}', "\u{03c9}\u{03b9}"),
+    ('\u{1ffd}', "\u{0020}"),
+    ('\u{1ffe}', "\u{0020}"),
+    ('\u{2000}', "\u{0020}"),
+    ('\u{2001}', "\u{0020}"),
+    ('\u{2002}', "\u{0020}"),
+    ('\u{2003}', "\u{0020}"),
+    ('\u{2004}', "\u{0020}"),
+    ('\u{2005}', "\u{0020}"),
+    ('\u{2006}', "\u{0020}"),
+    ('\u{2007}', "\u{0020}"),
+    ('\u{2008}', "\u{0020}"),
+    ('\u{2009}', "\u{0020}"),
+    ('\u{200a}', "\u{0020}"),
+    ('\u{2011}', "\u{2010}"),
+    ('\u{2017}', "\u{0020}"),
+    ('\u{2024}', "\u{002e}"),
+    ('\u{2025}', "\u{002e}\u{002e}"),
+    ('\u{2026}', "\u{002e}\u{002e}\u{002e}"),
+    ('\u{202f}', "\u{0020}"),
+    ('\u{2033}', "\u{2032}\u{2032}"),
+    ('\u{2034}', "\u{2032}\u{2032}\u{2032}"),
+    ('\u{2036}', "\u{2035}\u{2035}"),
+    ('\u{2037}', "\u{2035}\u{2035}\u{2035}"),
+    ('\u{203c}', "\u{0021}\u{0021}"),
+    ('\u{203e}', "\u{0020}"),
+    ('\u{2047}', "\u{003f}\u{003f}"),
+    ('\u{2048}', "\u{003f}\u{0021}"),
+    ('\u{2049}', "\u{0021}\u{003f}"),
+    ('\u{2057}', "\u{2032}\u{2032}\u{2032}\u{2032}"),
+    ('\u{205f}', "\u{0020}"),
+    ('\u{2070}', "0"),
+    ('\u{2071}', "i"),
+    ('\u{2074}', "4"),
+    ('\u{2075}', "5"),
+    ('\u{2076}', "6"),
+    ('\u{2077}', "7"),
+    ('\u{2078}', "8"),
+    ('\u{2079}', "9"),
+    ('\u{207a}', "\u{002b}"),
+    ('\u{207b}', "\u{2212}"),
+    ('\u{207c}', "\u{003d}"),
+    ('\u{207d}', "\u{0028}"),
+    ('\u{207e}', "\u{0029}"),
+    ('\u{207f}', "n"),
+    ('\u{2080}', "0"),
+    ('\u{2081}', "1"),
+    ('\u{2082}', "2"),
+    ('\u{2083}', "3"),
+    ('\u{2084}', "4"),
+    ('\u{2085}', "5"),
+    ('\u{2086}', "6"),
+    ('\u{2087}', "7"),
+    ('\u{2088}', "8"),
+    ('\u{2089}', "9"),
+    ('\u{208a}', "\u{002b}"),
+    ('\u{208b}', "\u{2212}"),
+    ('\u{208c}', "\u{003d}"),
+    ('\u{208d}', "\u{0028}"),
+    ('\u{208e}', "\u{0029}"),
+    ('\u{2090}', "a"),
+    ('\u{2091}', "e"),
+    ('\u{2092}', "o"),
+    ('\u{2093}', "x"),
+    ('\u{2094}', "\u{0259}"),
+    ('\u{2095}', "h"),
+    ('\u{2096}', "k"),
+    ('\u{2097}', "l"),
+    ('\u{2098}', "m"),
+    ('\u{2099}', "n"),
+    ('\u{209a}', "p"),
+    ('\u{209b}', "s"),
+    ('\u{209c}', "t"),
+    ('\u{20a8}', "rs"),
+    ('\u{2100}', "\u{0061}\u{002f}\u{0063}"),
+    ('\u{2101}', "\u{0061}\u{002f}\u{0073}"),
+    ('\u{2102}', "c"),
+    ('\u{2103}', "\u{00b0}\u{0063}"),
+    ('\u{2105}', "\u{0063}\u{002f}\u{006f}"),
+    ('\u{2106}', "\u{0063}\u{002f}\u{0075}"),
+    ('\u{2107}', "\u{025b}"),
+    ('\u{2109}', "\u{00b0}\u{0066}"),
+    ('\u{210a}', "g"),
+    ('\u{210b}', "h"),
+    ('\u{210c}', "h"),
+    ('\u{210d}', "h"),
+    ('\u{210e}', "h"),
+    ('\u{210f}', "h"),
+    ('\u{2110}', "i"),
+    ('\u{2111}', "i"),
+    ('\u{2112}', "l"),
+    ('\u{2113}', "l"),
+    ('\u{2115}', "n"),
+    ('\u{2116}', "no"),
+    ('\u{2119}', "p"),
+    ('\u{211a}', "q"),
+    ('\u{211b}', "r"),
+    ('\u{211c}', "r"),
+    ('\u{211d}', "r"),
+    ('\u{2120}', "sm"),
+    ('\u{2121}', "tel"),
+    ('\u{2122}', "tm"),
+    ('\u{2124}', "z"),
+    ('\u{2126}', "\u{03c9}"),
+    ('\u{2128}', "z"),
+    ('\u{212a}', "k"),
+    ('\u{212b}', "a"),
+    ('\u{212c}', "b"),
+    ('\u{212d}', "c"),
+    ('\u{212f}', "e"),
+    ('\u{2130}', "e"),
+    ('\u{2131}', "f"),
+    ('\u{2132}', "\u{214e}"),
+    ('\u{2133}', "m"),
+    ('\u{2134}', "o"),
+    ('\u{2135}', "\u{05d0}"),
+    ('\u{2136}', "\u{05d1}"),
+    ('\u{2137}', "\u{05d2}"),
+    ('\u{2138}', "\u{05d3}"),
+    ('\u{2139}', "i"),
+    ('\u{213b}', "fax"),
+    ('\u{213c}', "\u{03c0}"),
+    ('\u{213d}', "\u{03b3}"),
+    ('\u{213e}', "\u{03b3}"),
+    ('\u{213f}', "\u{03c0}"),
+    ('\u{2140}', "\u{2211}"),
+    ('\u{2145}', "d"),
+    ('\u{2146}', "d"),
+    ('\u{2147}', "e"),
+    ('\u{2148}', "i"),
+    ('\u{2149}', "j"),
+    ('\u{2150}', "\u{0031}\u{2044}\u{0037}"),
+    ('\u{2151}', "\u{0031}\u{2044}\u{0039}"),
+    ('\u{2152}', "\u{0031}\u{2044}\u{0031}\u{0030}"),
+    ('\u{2153}', "\u{0031}\u{2044}\u{0033}"),
+    ('\u{2154}', "\u{0032}\u{2044}\u{0033}"),
+    ('\u{2155}', "\u{0031}\u{2044}\u{0035}"),
+    ('\u{2156}', "\u{0032}\u{2044}\u{0035}"),
+    ('\u{2157}', "\u{0033}\u{2044}\u{0035}"),
+    ('\u{2158}', "\u{0034}\u{2044}\u{0035}"),
+    ('\u{2159}', "\u{0031}\u{2044}\u{0036}"),
+    ('\u{215a}', "\u{0035}\u{2044}\u{0036}"),
+    ('\u{215b}', "\u{0031}\u{2044}\u{0038}"),
+    ('\u{215c}', "\u{0033}\u{2044}\u{0038}"),
+    ('\u{215d}', "\u{0035}\u{2044}\u{0038}"),
+    ('\u{215e}', "\u{0037}\u{2044}\u{0038}"),
+    ('\u{215f}', "\u{0031}\u{2044}"),
+    ('\u{2160}', "i"),
+    ('\u{2161}', "ii"),
+    ('\u{2162}', "iii"),
+    ('\u{2163}', "iv"),
+    ('\u{2164}', "v"),
+    ('\u{2165}', "vi"),
+    ('\u{2166}', "vii"),
+    ('\u{2167}', "viii"),
+    ('\u{2168}', "ix"),
+    ('\u{2169}', "x"),
+    ('\u{216a}', "xi"),
+    ('\u{216b}', "xii"),
+    ('\u{216c}', "l"),
+    ('\u{216d}', "c"),
+    ('\u{216e}', "d"),
+    ('\u{216f}', "m"),
+    ('\u{2170}', "i"),
+    ('\u{2171}', "ii"),
+    ('\u{2172}', "iii"),
+    ('\u{2173}', "iv"),
+    ('\u{2174}', "v"),
+    ('\u{2175}', "vi"),
+    ('\u{2176}', "vii"),
+    ('\u{2177}', "viii"),
+    ('\u{2178}', "ix"),
+    ('\u{2179}', "x"),
+    ('\u{217a}', "xi"),
+    ('\u{217b}', "xii"),
+    ('\u{217c}', "l"),
+    ('\u{217d}', "c"),
+    ('\u{217e}', "d"),
+    ('\u{217f}', "m"),
+    ('\u{2183}', "\u{2184}"),
+    ('\u{2189}', "\u{0030}\u{2044}\u{0033}"),
+    ('\u{219a}', "\u{2190}"),
+    ('\u{219b}', "\u{2192}"),
+    ('\u{21ae}', "\u{2194}"),
+    ('\u{21cd}', "\u{21d0}"),
+    ('\u{21ce}', "\u{21d4}"),
+    ('\u{21cf}', "\u{21d2}"),
+    ('\u{2204}', "\u{2203}"),
+    ('\u{2209}', "\u{2208}"),
+    ('\u{220c}', "\u{220b}"),
+    ('\u{2224}', "\u{2223}"),
+    ('\u{2226}', "\u{2225}"),
+    ('\u{222c}', "\u{222b}\u{222b}"),
+    ('\u{222d}', "\u{222b}\u{222b}\u{222b}"),
+    ('\u{222f}', "\u{222e}\u{222e}"),
+    ('\u{2230}', "\u{222e}\u{222e}\u{222e}"),
+    ('\u{2241}', "\u{223c}"),
+    ('\u{2244}', "\u{2243}"),
+    ('\u{2247}', "\u{2245}"),
+    ('\u{2249}', "\u{2248}"),
+    ('\u{2260}', "\u{003d}"),
+    ('\u{2262}', "\u{2261}"),
+    ('\u{226d}', "\u{224d}"),
+    ('\u{226e}', "\u{003c}"),
+    ('\u{226f}', "\u{003e}"),
+    ('\u{2270}', "\u{2264}"),
+    ('\u{2271}', "\u{2265}"),
+    ('\u{2274}', "\u{2272}"),
+    ('\u{2275}', "\u{2273}"),
+    ('\u{2278}', "\u{2276}"),
+    ('\u{2279}', "\u{2277}"),
+    ('\u{2280}', "\u{227a}"),
+    ('\u{2281}', "\u{227b}"),
+    ('\u{2284}', "\u{2282}"),
+    ('\u{2285}', "\u{2283}"),
+    ('\u{2288}', "\u{2286}"),
+    ('\u{2289}', "\u{2287}"),
+    ('\u{22ac}', "\u{22a2}"),
+    ('\u{22ad}', "\u{22a8}"),
+    ('\u{22ae}', "\u{22a9}"),
+    ('\u{22af}', "\u{22ab}"),
+    ('\u{22e0}', "\u{227c}"),
+    ('\u{22e1}', "\u{227d}"),
+    ('\u{22e2}', "\u{2291}"),
+    ('\u{22e3}', "\u{2292}"),
+    ('\u{22ea}', "\u{22b2}"),
+    ('\u{22eb}', "\u{22b3}"),
+    ('\u{22ec}', "\u{22b4}"),
+    ('\u{22ed}', "\u{22b5}"),
+    ('\u{2329}', "\u{3008}"),
+    ('\u{232a}', "\u{3009}"),
+    ('\u{2460}', "1"),
+    ('\u{2461}', "2"),
+    ('\u{2462}', "3"),
+    ('\u{2463}', "4"),
+    ('\u{2464}', "5"),
+    ('\u{2465}', "6"),
+    ('\u{2466}', "7"),
+    ('\u{2467}', "8"),
+    ('\u{2468}', "9"),
+    ('\u{2469}', "10"),
+    ('\u{246a}', "11"),
+    ('\u{246b}', "12"),
+    ('\u{246c}', "13"),
+    ('\u{246d}', "14"),
+    ('\u{246e}', "15"),
+    ('\u{246f}', "16"),
+    ('\u{2470}', "17"),
+    ('\u{2471}', "18"),
+    ('\u{2472}', "19"),
+    ('\u{2473}', "20"),
+    ('\u{2474}', "\u{0028}\u{0031}\u{0029}"),
+    ('\u{2475}', "\u{0028}\u{0032}\u{0029}"),
+    ('\u{2476}', "\u{0028}\u{0033}\u{0029}"),
+    ('\u{2477}', "\u{0028}\u{0034}\u{0029}"),
+    ('\u{2478}', "\u{0028}\u{0035}\u{0029}"),
+    ('\u{2479}', "\u{0028}\u{0036}\u{0029}"),
+    ('\u{247a}', "\u{0028}\u{0037}\u{0029}"),
+    ('\u{247b}', "\u{0028}\u{0038}\u{0029}"),
+    ('\u{247c}', "\u{0028}\u{0039}\u{0029}"),
+    ('\u{247d}', "\u{0028}\u{0031}\u{0030}\u{0029}"),
+    ('\u{247e}', "\u{0028}\u{0031}\u{0031}\u{0029}"),
+    ('\u{247f}', "\u{0028}\u{0031}\u{0032}\u{0029}"),
+    ('\u{2480}', "\u{0028}\u{0031}\u{0033}\u{0029}"),
+    ('\u{2481}', "\u{0028}\u{0031}\u{0034}\u{0029}"),
+    ('\u{2482}', "\u{0028}\u{0031}\u{0035}\u{0029}"),
+    ('\u{2483}', "\u{0028}\u{0031}\u{0036}\u{0029}"),
+    ('\u{2484}', "\u{0028}\u{0031}\u{0037}\u{0029}"),
+    ('\u{2485}', "\u{0028}\u{0031}\u{0038}\u{0029}"),
+    ('\u{2486}', "\u{0028}\u{0031}\u{0039}\u{0029}"),
+    ('\u{2487}', "\u{0028}\u{0032}\u{0030}\u{0029}"),
+    ('\u{2488}', "\u{0031}\u{002e}"),
+    ('\u{2489}', "\u{0032}\u{002e}"),
+    ('\u{248a}', "\u{0033}\u{002e}"),
+    ('\u{248b}', "\u{0034}\u{002e}"),
+    ('\u{248c}', "\u{0035}\u{002e}"),
+    ('\u{248d}', "\u{0036}\u{002e}"),
+    ('\u{248e}', "\u{0037}\u{002e}"),
+    ('\u{248f}', "\u{0038}\u{002e}"),
+    ('\u{2490}', "\u{0039}\u{002e}"),
+    ('\u{2491}', "\u{0031}\u{0030}\u{002e}"),
+    ('\u{2492}', "\u{0031}\u{0031}\u{002e}"),
+    ('\u{2493}', "\u{0031}\u{0032}\u{002e}"),
+    ('\u{2494}', "\u{0031}\u{0033}\u{002e}"),
+    ('\u{2495}', "\u{0031}\u{0034}\u{002e}"),
+    ('\u{2496}', "\u{0031}\u{0035}\u{002e}"),
+    ('\u{2497}', "\u{0031}\u{0036}\u{002e}"),
+    ('\u{2498}', "\u{0031}\u{0037}\u{002e}"),
+    ('\u{2499}', "\u{0031}\u{0038}\u{002e}"),
+    ('\u{249a}', "\u{0031}\u{0039}\u{002e}"),
+    ('\u{249b}', "\u{0032}\u{0030}\u{002e}"),
+    ('\u{249c}', "\u{0028}\u{0061}\u{0029}"),
+    ('\u{249d}', "\u{0028}\u{0062}\u{0029}"),
+    ('\u{249e}', "\u{0028}\u{0063}\u{0029}"),
+    ('\u{249f}', "\u{0028}\u{0064}\u{0029}"),
+    ('\u{24a0}', "\u{0028}\u{0065}\u{0029}"),
+    ('\u{24a1}', "\u{0028}\u{0066}\u{0029}"),
+    ('\u{24a2}', "\u{0028}\u{0067}\u{0029}"),
+    ('\u{24a3}', "\u{0028}\u{0068}\u{0029}"),
+    ('\u{24a4}', "\u{0028}\u{0069}\u{0029}"),
+    ('\u{24a5}', "\u{0028}\u{006a}\u{0029}"),
+    ('\u{24a6}', "\u{0028}\u{006b}\u{0029}"),
+    ('\u{24a7}', "\u{0028}\u{006c}\u{0029}"),
+    ('\u{24a8}', "\u{0028}\u{006d}\u{0029}"),
+    ('\u{24a9}', "\u{0028}\u{006e}\u{0029}"),
+    ('\u{24aa}', "\u{0028}\u{006f}\u{0029}"),
+    ('\u{24ab}', "\u{0028}\u{0070}\u{0029}"),
+    ('\u{24ac}', "\u{0028}\u{0071}\u{0029}"),
+    ('\u{24ad}', "\u{0028}\u{0072}\u{0029}"),
+    ('\u{24ae}', "\u{0028}\u{0073}\u{0029}"),
+    ('\u{24af}', "\u{0028}\u{0074}\u{0029}"),
+    ('\u{24b0}', "\u{0028}\u{0075}\u{0029}"),
+    ('\u{24b1}', "\u{0028}\u{0076}\u{0029}"),
+    ('\u{24b2}', "\u{0028}\u{0077}\u{0029}"),
+    ('\u{24b3}', "\u{0028}\u{0078}\u{0029}"),
+    ('\u{24b4}', "\u{0028}\u{0079}\u{0029}"),
+    ('\u{24b5}', "\u{0028}\u{007a}\u{0029}"),
+    ('\u{24b6}', "a"),
+    ('\u{24b7}', "b"),
+    ('\u{24b8}', "c"),
+    ('\u{24b9}', "d"),
+    ('\u{24ba}', "e"),
+    ('\u{24bb}', "f"),
+    ('\u{24bc}', "g"),
+    ('\u{24bd}', "h"),
+    ('\u{24be}', "i"),
+    ('\u{24bf}', "j"),
+    ('\u{24c0}', "k"),
+    ('\u{24c1}', "l"),
+    ('\u{24c2}', "m"),
+    ('\u{24c3}', "n"),
+    ('\u{24c4}', "o"),
+    ('\u{24c5}', "p"),
+    ('\u{24c6}', "q"),
+    ('\u{24c7}', "r"),
+    ('\u{24c8}', "s"),
+    ('\u{24c9}', "t"),
+    ('\u{24ca}', "u"),
+    ('\u{24cb}', "v"),
+    ('\u{24cc}', "w"),
+    ('\u{24cd}', "x"),
+    ('\u{24ce}', "y"),
+    ('\u{24cf}', "z"),
+    ('\u{24d0}', "a"),
+    ('\u{24d1}', "b"),
+    ('\u{24d2}', "c"),
+    ('\u{24d3}', "d"),
+    ('\u{24d4}', "e"),
+    ('\u{24d5}', "f"),
+    ('\u{24d6}', "g"),
+    ('\u{24d7}', "h"),
+    ('\u{24d8}', "i"),
+    ('\u{24d9}', "j"),
+    ('\u{24da}', "k"),
+    ('\u{24db}', "l"),
+    ('\u{24dc}', "m"),
+    ('\u{24dd}', "n"),
+    ('\u{24de}', "o"),
+    ('\u{24df}', "p"),
+    ('\u{24e0}', "q"),
+    ('\u{24e1}', "r"),
+    ('\u{24e2}', "s"),
+    ('\u{24e3}', "t"),
+    ('\u{24e4}', "u"),
+    ('\u{24e5}', "v"),
+    ('\u{24e6}', "w"),
+    ('\u{24e7}', "x"),
+    ('\u{24e8}', "y"),
+    ('\u{24e9}', "z"),
+    ('\u{24ea}', "0"),
+    ('\u{2a0c}', "\u{222b}\u{222b}\u{222b}\u{222b}"),
+    ('\u{2a74}', "\u{003a}\u{003a}\u{003d}"),
+    ('\u{2a75}', "\u{003d}\u{003d}"),
+    ('\u{2a76}', "\u{003d}\u{003d}\u{003d}"),
+    ('\u{2adc}', "\u{2add}"),
+    ('\u{2c00}', "\u{2c30}"),
+    ('\u{2c01}', "\u{2c31}"),
+    ('\u{2c02}', "\u{2c32}"),
+    ('\u{2c03}', "\u{2c33}"),
+    ('\u{2c04}', "\u{2c34}"),
+    ('\u{2c05}', "\u{2c35}"),
+    ('\u{2c06}', "\u{2c36}"),
+    ('\u{2c07}', "\u{2c37}"),
+    ('\u{2c08}', "\u{2c38}"),
+    ('\u{2c09}', "\u{2c39}"),
+    ('\u{2c0a}', "\u{2c3a}"),
+    ('\u{2c0b}', "\u{2c3b}"),
+    ('\u{2c0c}', "\u{2c3c}"),
+    ('\u{2c0d}', "\u{2c3d}"),
+    ('\u{2c0e}', "\u{2c3e}"),
+    ('\u{2c0f}', "\u{2c3f}"),
+    ('\u{2c10}', "\u{2c40}"),
+    ('\u{2c11}', "\u{2c41}"),
+    ('\u{2c12}', "\u{2c42}"),
+    ('\u{2c13}', "\u{2c43}"),
+    ('\u{2c14}', "\u{2c44}"),
+    ('\u{2c15}', "\u{2c45}"),
+    ('\u{2c16}', "\u{2c46}"),
+    ('\u{2c17}', "\u{2c47}"),
+    ('\u{2c18}', "\u{2c48}"),
+    ('\u{2c19}', "\u{2c49}"),
+    ('\u{2c1a}', "\u{2c4a}"),
+    ('\u{2c1b}', "\u{2c4b}"),
+    ('\u{2c1c}', "\u{2c4c}"),
+    ('\u{2c1d}', "\u{2c4d}"),
+    ('\u{2c1e}', "\u{2c4e}"),
+    ('\u{2c1f}', "\u{2c4f}"),
+    ('\u{2c20}', "\u{2c50}"),
+    ('\u{2c21}', "\u{2c51}"),
+    ('\u{2c22}', "\u{2c52}"),
+    ('\u{2c23}', "\u{2c53}"),
+    ('\u{2c24}', "\u{2c54}"),
+    ('\u{2c25}', "\u{2c55}"),
+    ('\u{2c26}', "\u{2c56}"),
+    ('\u{2c27}', "\u{2c57}"),
+    ('\u{2c28}', "\u{2c58}"),
+    ('\u{2c29}', "\u{2c59}"),
+    ('\u{2c2a}', "\u{2c5a}"),
+    ('\u{2c2b}', "\u{2c5b}"),
+    ('\u{2c2c}', "\u{2c5c}"),
+    ('\u{2c2d}', "\u{2c5d}"),
+    ('\u{2c2e}', "\u{2c5e}"),
+    ('\u{2c2f}', "\u{2c5f}"),
+    ('\u{2c60}', "\u{2c61}"),
+    ('\u{2c62}', "\u{026b}"),
+    ('\u{2c63}', "\u{1d7d}"),
+    ('\u{2c64}', "\u{027d}"),
+    ('\u{2c67}', "\u{2c68}"),
+    ('\u{2c69}', "\u{2c6a}"),
+    ('\u{2c6b}', "\u{2c6c}"),
+    ('\u{2c6d}', "\u{0251}"),
+    ('\u{2c6e}', "\u{0271}"),
+    ('\u{2c6f}', "\u{0250}"),
+    ('\u{2c70}', "\u{0252}"),
+    ('\u{2c72}', "\u{2c73}"),
+    ('\u{2c75}', "\u{2c76}"),
+    ('\u{2c7c}', "j"),
+    ('\u{2c7d}', "v"),
+    ('\u{2c7e}', "\u{023f}"),
+    ('\u{2c7f}', "\u{0240}"),
+    ('\u{2c80}', "\u{2c81}"),
+    ('\u{2c82}', "\u{2c83}"),
+    ('\u{2c84}', "\u{2c85}"),
+    ('\u{2c86}', "\u{2c87}"),
+    ('\u{2c88}', "\u{2c89}"),
+    ('\u{2c8a}', "\u{2c8b}"),
+    ('\u{2c8c}', "\u{2c8d}"),
+    ('\u{2c8e}', "\u{2c8f}"),
+    ('\u{2c90}', "\u{2c91}"),
+    ('\u{2c92}', "\u{2c93}"),
+    ('\u{2c94}', "\u{2c95}"),
+    ('\u{2c96}', "\u{2c97}"),
+    ('\u{2c98}', "\u{2c99}"),
+    ('\u{2c9a}', "\u{2c9b}"),
+    ('\u{2c9c}', "\u{2c9d}"),
+    ('\u{2c9e}', "\u{2c9f}"),
+    ('\u{2ca0}', "\u{2ca1}"),
+    ('\u{2ca2}', "\u{2ca3}"),
+    ('\u{2ca4}', "\u{2ca5}"),
+    ('\u{2ca6}', "\u{2ca7}"),
+    ('\u{2ca8}', "\u{2ca9}"),
+    ('\u{2caa}', "\u{2cab}"),
+    ('\u{2cac}', "\u{2cad}"),
+    ('\u{2cae}', "\u{2caf}"),
+    ('\u{2cb0}', "\u{2cb1}"),
+    ('\u{2cb2}', "\u{2cb3}"),
+    ('\u{2cb4}', "\u{2cb5}"),
+    ('\u{2cb6}', "\u{2cb7}"),
+    ('\u{2cb8}', "\u{2cb9}"),
+    ('\u{2cba}', "\u{2cbb}"),
+    ('\u{2cbc}', "\u{2cbd}"),
+    ('\u{2cbe}', "\u{2cbf}"),
+    ('\u{2cc0}', "\u{2cc1}"),
+    ('\u{2cc2}', "\u{2cc3}"),
+    ('\u{2cc4}', "\u{2cc5}"),
+    ('\u{2cc6}', "\u{2cc7}"),
+    ('\u{2cc8}', "\u{2cc9}"),
+    ('\u{2cca}', "\u{2ccb}"),
+    ('\u{2ccc}', "\u{2ccd}"),
+    ('\u{2cce}', "\u{2ccf}"),
+    ('\u{2cd0}', "\u{2cd1}"),
+    ('\u{2cd2}', "\u{2cd3}"),
+    ('\u{2cd4}', "\u{2cd5}"),
+    ('\u{2cd6}', "\u{2cd7}"),
+    ('\u{2cd8}', "\u{2cd9}"),
+    ('\u{2cda}', "\u{2cdb}"),
+    ('\u{2cdc}', "\u{2cdd}"),
+    ('\u{2cde}', "\u{2cdf}"),
+    ('\u{2ce0}', "\u{2ce1}"),
+    ('\u{2ce2}', "\u{2ce3}"),
+    ('\u{2ceb}', "\u{2cec}"),
+    ('\u{2ced}', "\u{2cee}"),
+    ('\u{2cf2}', "\u{2cf3}"),
+    ('\u{2d6f}', "\u{2d61}"),
+    ('\u{2e9f}', "\u{6bcd}"),
+    ('\u{2ef3}', "\u{9f9f}"),
+    ('\u{2f00}', "\u{4e00}"),
+    ('\u{2f01}', "\u{4e28}"),
+    ('\u{2f02}', "\u{4e36}"),
+    ('\u{2f03}', "\u{4e3f}"),
+    ('\u{2f04}', "\u{4e59}"),
+    ('\u{2f05}', "\u{4e85}"),
+    ('\u{2f06}', "\u{4e8c}"),
+    ('\u{2f07}', "\u{4ea0}"),
+    ('\u{2f08}', "\u{4eba}"),
+    ('\u{2f09}', "\u{513f}"),
+    ('\u{2f0a}', "\u{5165}"),
+    ('\u{2f0b}', "\u{516b}"),
+    ('\u{2f0c}', "\u{5182}"),
+    ('\u{2f0d}', "\u{5196}"),
+    ('\u{2f0e}', "\u{51ab}"),
+    ('\u{2f0f}', "\u{51e0}"),
+    ('\u{2f10}', "\u{51f5}"),
+    ('\u{2f11}', "\u{5200}"),
+    ('\u{2f12}', "\u{529b}"),
+    ('\u{2f13}', "\u{52f9}"),
+    ('\u{2f14}', "\u{5315}"),
+    ('\u{2f15}', "\u{531a}"),
+    ('\u{2f16}', "\u{5338}"),
+    ('\u{2f17}', "\u{5341}"),
+    ('\u{2f18}', "\u{535c}"),
+    ('\u{2f19}', "\u{5369}"),
+    ('\u{2f1a}', "\u{5382}"),
+    ('\u{2f1b}', "\u{53b6}"),
+    ('\u{2f1c}', "\u{53c8}"),
+    ('\u{2f1d}', "\u{53e3}"),
+    ('\u{2f1e}', "\u{56d7}"),
+    ('\u{2f1f}', "\u{571f}"),
+    ('\u{2f20}', "\u{58eb}"),
+    ('\u{2f21}', "\u{5902}"),
+    ('\u{2f22}', "\u{590a}"),
+    ('\u{2f23}', "\u{5915}"),
+    ('\u{2f24}', "\u{5927}"),
+    ('\u{2f25}', "\u{5973}"),
+    ('\u{2f26}', "\u{5b50}"),
+    ('\u{2f27}', "\u{5b80}"),
+    ('\u{2f28}', "\u{5bf8}"),
+    ('\u{2f29}', "\u{5c0f}"),
+    ('\u{2f2a}', "\u{5c22}"),
+    ('\u{2f2b}', "\u{5c38}"),
+    ('\u{2f2c}', "\u{5c6e}"),
+    ('\u{2f2d}', "\u{5c71}"),
+    ('\u{2f2e}', "\u{5ddb}"),
+    ('\u{2f2f}', "\u{5de5}"),
+    ('\u{2f30}', "\u{5df1}"),
+    ('\u{2f31}', "\u{5dfe}"),
+    ('\u{2f32}', "\u{5e72}"),
+    ('\u{2f33}', "\u{5e7a}"),
+    ('\u{2f34}', "\u{5e7f}"),
+    ('\u{2f35}', "\u{5ef4}"),
+    ('\u{2f36}', "\u{5efe}"),
+    ('\u{2f37}', "\u{5f0b}"),
+    ('\u{2f38}', "\u{5f13}"),
+    ('\u{2f39}', "\u{5f50}"),
+    ('\u{2f3a}', "\u{5f61}"),
+    ('\u{2f3b}', "\u{5f73}"),
+    ('\u{2f3c}', "\u{5fc3}"),
+    ('\u{2f3d}', "\u{6208}"),
+    ('\u{2f3e}', "\u{6236}"),
+    ('\u{2f3f}', "\u{624b}"),
+    ('\u{2f40}', "\u{652f}"),
+    ('\u{2f41}', "\u{6534}"),
+    ('\u{2f42}', "\u{6587}"),
+    ('\u{2f43}', "\u{6597}"),
+    ('\u{2f44}', "\u{65a4}"),
+    ('\u{2f45}', "\u{65b9}"),
+    ('\u{2f46}', "\u{65e0}"),
+    ('\u{2f47}', "\u{65e5}"),
+    ('\u{2f48}', "\u{66f0}"),
+    ('\u{2f49}', "\u{6708}"),
+    ('\u{2f4a}', "\u{6728}"),
+    ('\u{2f4b}', "\u{6b20}"),
+    ('\u{2f4c}', "\u{6b62}"),
+    ('\u{2f4d}', "\u{6b79}"),
+    ('\u{2f4e}', "\u{6bb3}"),
+    ('\u{2f4f}', "\u{6bcb}"),
+    ('\u{2f50}', "\u{6bd4}"),
+    ('\u{2f51}', "\u{6bdb}"),
+    ('\u{2f52}', "\u{6c0f}"),
+    ('\u{2f53}', "\u{6c14}"),
+    ('\u{2f54}', "\u{6c34}"),
+    ('\u{2f55}', "\u{706b}"),
+    ('\u{2f56}', "\u{722a}"),
+    ('\u{2f57}', "\u{7236}"),
+    ('\u{2f58}', "\u{723b}"),
+    ('\u{2f59}', "\u{723f}"),
+    ('\u{2f5a}', "\u{7247}"),
+    ('\u{2f5b}', "\u{7259}"),
+    ('\u{2f5c}', "\u{725b}"),
+    ('\u{2f5d}', "\u{72ac}"),
+    ('\u{2f5e}', "\u{7384}"),
+    ('\u{2f5f}', "\u{7389}"),
+    ('\u{2f60}', "\u{74dc}"),
+    ('\u{2f61}', "\u{74e6}"),
+    ('\u{2f62}', "\u{7518}"),
+    ('\u{2f63}', "\u{751f}"),
+    ('\u{2f64}', "\u{7528}"),
+    ('\u{2f65}', "\u{7530}"),
+    ('\u{2f66}', "\u{758b}"),
+    ('\u{2f67}', "\u{7592}"),
+    ('\u{2f68}', "\u{7676}"),
+    ('\u{2f69}', "\u{767d}"),
+    ('\u{2f6a}', "\u{76ae}"),
+    ('\u{2f6b}', "\u{76bf}"),
+    ('\u{2f6c}', "\u{76ee}"),
+    ('\u{2f6d}', "\u{77db}"),
+    ('\u{2f6e}', "\u{77e2}"),
+    ('\u{2f6f}', "\u{77f3}"),
+    ('\u{2f70}', "\u{793a}"),
+    ('\u{2f71}', "\u{79b8}"),
+    ('\u{2f72}', "\u{79be}"),
+    ('\u{2f73}', "\u{7a74}"),
+    ('\u{2f74}', "\u{7acb}"),
+    ('\u{2f75}', "\u{7af9}"),
+    ('\u{2f76}', "\u{7c73}"),
+    ('\u{2f77}', "\u{7cf8}"),
+    ('\u{2f78}', "\u{7f36}"),
+    ('\u{2f79}', "\u{7f51}"),
+    ('\u{2f7a}', "\u{7f8a}"),
+    ('\u{2f7b}', "\u{7fbd}"),
+    ('\u{2f7c}', "\u{8001}"),
+    ('\u{2f7d}', "\u{800c}"),
+    ('\u{2f7e}', "\u{8012}"),
+    ('\u{2f7f}', "\u{8033}"),
+    ('\u{2f80}', "\u{807f}"),
+    ('\u{2f81}', "\u{8089}"),
+    ('\u{2f82}', "\u{81e3}"),
+    ('\u{2f83}', "\u{81ea}"),
+    ('\u{2f84}', "\u{81f3}"),
+    ('\u{2f85}', "\u{81fc}"),
+    ('\u{2f86}', "\u{820c}"),
+    ('\u{2f87}', "\u{821b}"),
+    ('\u{2f88}', "\u{821f}"),
+    ('\u{2f89}', "\u{826e}"),
+    ('\u{2f8a}', "\u{8272}"),
+    ('\u{2f8b}', "\u{8278}"),
+    ('\u{2f8c}', "\u{864d}"),
+    ('\u{2f8d}', "\u{866b}"),
+    ('\u{2f8e}', "\u{8840}"),
+    ('\u{2f8f}', "\u{884c}"),
+    ('\u{2f90}', "\u{8863}"),
+    ('\u{2f91}', "\u{897e}"),
+    ('\u{2f92}', "\u{898b}"),
+    ('\u{2f93}', "\u{89d2}"),
+    ('\u{2f94}', "\u{8a00}"),
+    ('\u{2f95}', "\u{8c37}"),
+    ('\u{2f96}', "\u{8c46}"),
+    ('\u{2f97}', "\u{8c55}"),
+    ('\u{2f98}', "\u{8c78}"),
+    ('\u{2f99}', "\u{8c9d}"),
+    ('\u{2f9a}', "\u{8d64}"),
+    ('\u{2f9b}', "\u{8d70}"),
+    ('\u{2f9c}', "\u{8db3}"),
+    ('\u{2f9d}', "\u{8eab}"),
+    ('\u{2f9e}', "\u{8eca}"),
+    ('\u{2f9f}', "\u{8f9b}"),
+    ('\u{2fa0}', "\u{8fb0}"),
+    ('\u{2fa1}', "\u{8fb5}"),
+    ('\u{2fa2}', "\u{9091}"),
+    ('\u{2fa3}', "\u{9149}"),
+    ('\u{2fa4}', "\u{91c6}"),
+    ('\u{2fa5}', "\u{91cc}"),
+    ('\u{2fa6}', "\u{91d1}"),
+    ('\u{2fa7}', "\u{9577}"),
+    ('\u{2fa8}', "\u{9580}"),
+    ('\u{2fa9}', "\u{961c}"),
+    ('\u{2faa}', "\u{96b6}"),
+    ('\u{2fab}', "\u{96b9}"),
+    ('\u{2fac}', "\u{96e8}"),
+    ('\u{2fad}', "\u{9751}"),
+    ('\u{2fae}', "\u{975e}"),
+    ('\u{2faf}', "\u{9762}"),
+    ('\u{2fb0}', "\u{9769}"),
+    ('\u{2fb1}', "\u{97cb}"),
+    ('\u{2fb2}', "\u{97ed}"),
+    ('\u{2fb3}', "\u{97f3}"),
+    ('\u{2fb4}', "\u{9801}"),
+    ('\u{2fb5}', "\u{98a8}"),
+    ('\u{2fb6}', "\u{98db}"),
+    ('\u{2fb7}', "\u{98df}"),
+    ('\u{2fb8}', "\u{9996}"),
+    ('\u{2fb9}', "\u{9999}"),
+    ('\u{2fba}', "\u{99ac}"),
+    ('\u{2fbb}', "\u{9aa8}"),
+    ('\u{2fbc}', "\u{9ad8}"),
+    ('\u{2fbd}', "\u{9adf}"),
+    ('\u{2fbe}', "\u{9b25}"),
+    ('\u{2fbf}', "\u{9b2f}"),
+    ('\u{2fc0}', "\u{9b32}"),
+    ('\u{2fc1}', "\u{9b3c}"),
+    ('\u{2fc2}', "\u{9b5a}"),
+    ('\u{2fc3}', "\u{9ce5}"),
+    ('\u{2fc4}', "\u{9e75}"),
+    ('\u{2fc5}', "\u{9e7f}"),
+    ('\u{2fc6}', "\u{9ea5}"),
+    ('\u{2fc7}', "\u{9ebb}"),
+    ('\u{2fc8}', "\u{9ec3}"),
+    ('\u{2fc9}', "\u{9ecd}"),
+    ('\u{2fca}', "\u{9ed1}"),
+    ('\u{2fcb}', "\u{9ef9}"),
+    ('\u{2fcc}', "\u{9efd}"),
+    ('\u{2fcd}', "\u{9f0e}"),
+    ('\u{2fce}', "\u{9f13}"),
+    ('\u{2fcf}', "\u{9f20}"),
+    ('\u{2fd0}', "\u{9f3b}"),
+    ('\u{2fd1}', "\u{9f4a}"),
+    ('\u{2fd2}', "\u{9f52}"),
+    ('\u{2fd3}', "\u{9f8d}"),
+    ('\u{2fd4}', "\u{9f9c}"),
+    ('\u{2fd5}', "\u{9fa0}"),
+    ('\u{3000}', "\u{0020}"),
+    ('\u{3036}', "\u{3012}"),
+    ('\u{3038}', "\u{5341}"),
+    ('\u{3039}', "\u{5344}"),
+    ('\u{303a}', "\u{5345}"),
+    ('\u{304c}', "\u{304b}"),
+    ('\u{304e}', "\u{304d}"),
+    ('\u{3050}', "\u{304f}"),
+    ('\u{3052}', "\u{3051}"),
+    ('\u{3054}', "\u{3053}"),
+    ('\u{3056}', "\u{3055}"),
+    ('\u{3058}', "\u{3057}"),
+    ('\u{305a}', "\u{3059}"),
+    ('\u{305c}', "\u{305b}"),
+    ('\u{305e}', "\u{305d}"),
+    ('\u{3060}', "\u{305f}"),
+    ('\u{3062}', "\u{3061}"),
+    ('\u{3065}', "\u{3064}"),
+    ('\u{3067}', "\u{3066}"),
+    ('\u{3069}', "\u{3068}"),
+    ('\u{3070}', "\u{306f}"),
+    ('\u{3071}', "\u{306f}"),
+    ('\u{3073}', "\u{3072}"),
+    ('\u{3074}', "\u{3072}"),
+    ('\u{3076}', "\u{3075}"),
+    ('\u{3077}', "\u{3075}"),
+    ('\u{3079}', "\u{3078}"),
+    ('\u{307a}', "\u{3078}"),
+    ('\u{307c}', "\u{307b}"),
+    ('\u{307d}', "\u{307b}"),
+    ('\u{3094}', "\u{3046}"),
+    ('\u{309b}', "\u{0020}"),
+    ('\u{309c}', "\u{0020}"),
+    ('\u{309e}', "\u{309d}"),
+    ('\u{309f}', "\u{3088}\u{308a}"),
+    ('\u{30ac}', "\u{30ab}"),
+    ('\u{30ae}', "\u{30ad}"),
+    ('\u{30b0}', "\u{30af}"),
+    ('\u{30b2}', "\u{30b1}"),
+    ('\u{30b4}', "\u{30b3}"),
+    ('\u{30b6}', "\u{30b5}"),
+    ('\u{30b8}', "\u{30b7}"),
+    ('\u{30ba}', "\u{30b9}"),
+    ('\u{30bc}', "\u{30bb}"),
+    ('\u{30be}', "\u{30bd}"),
+    ('\u{30c0}', "\u{30bf}"),
+    ('\u{30c2}', "\u{30c1}"),
+    ('\u{30c5}', "\u{30c4}"),
+    ('\u{30c7}', "\u{30c6}"),
+    ('\u{30c9}', "\u{30c8}"),
+    ('\u{30d0}', "\u{30cf}"),
+    ('\u{30d1}', "\u{30cf}"),
+    ('\u{30d3}', "\u{30d2}"),
+    ('\u{30d4}', "\u{30d2}"),
+    ('\u{30d6}', "\u{30d5}"),
+    ('\u{30d7}', "\u{30d5}"),
+    ('\u{30d9}', "\u{30d8}"),
+    ('\u{30da}', "\u{30d8}"),
+    ('\u{30dc}', "\u{30db}"),
+    ('\u{30dd}', "\u{30db}"),
+    ('\u{30f4}', "\u{30a6}"),
+    ('\u{30f7}', "\u{30ef}"),
+    ('\u{30f8}', "\u{30f0}"),
+    ('\u{30f9}', "\u{30f1}"),
+    ('\u{30fa}', "\u{30f2}"),
+    ('\u{30fe}', "\u{30fd}"),
+    ('\u{30ff}', "\u{30b3}\u{30c8}"),
+    ('\u{3131}', "\u{1100}"),
+    ('\u{3132}', "\u{1101}"),
+    ('\u{3133}', "\u{11aa}"),
+    ('\u{3134}', "\u{1102}"),
+    ('\u{3135}', "\u{11ac}"),
+    ('\u{3136}', "\u{11ad}"),
+    ('\u{3137}', "\u{1103}"),
+    ('\u{3138}', "\u{1104}"),
+    ('\u{3139}', "\u{1105}"),
+    ('\u{313a}', "\u{11b0}"),
+    ('\u{313b}', "\u{11b1}"),
+    ('\u{313c}', "\u{11b2}"),
+    ('\u{313d}', "\u{11b3}"),
+    ('\u{313e}', "\u{11b4}"),
+    ('\u{313f}', "\u{11b5}"),
+    ('\u{3140}', "\u{111a}"),
+    ('\u{3141}', "\u{1106}"),
+    ('\u{3142}', "\u{1107}"),
+    ('\u{3143}', "\u{1108}"),
+    ('\u{3144}', "\u{1121}"),
+    ('\u{3145}', "\u{1109}"),
+    ('\u{3146}', "\u{110a}"),
+    ('\u{3147}', "\u{110b}"),
+    ('\u{3148}', "\u{110c}"),
+    ('\u{3149}', "\u{110d}"),
+    ('\u{314a}', "\u{110e}"),
+    ('\u{314b}', "\u{110f}"),
+    ('\u{314c}', "\u{1110}"),
+    ('\u{314d}', "\u{1111}"),
+    ('\u{314e}', "\u{1112}"),
+    ('\u{314f}', "\u{1161}"),
+    ('\u{3150}', "\u{1162}"),
+    ('\u{3151}', "\u{1163}"),
+    ('\u{3152}', "\u{1164}"),
+    ('\u{3153}', "\u{1165}"),
+    ('\u{3154}', "\u{1166}"),
+    ('\u{3155}', "\u{1167}"),
+    ('\u{3156}', "\u{1168}"),
+    ('\u{3157}', "\u{1169}"),
+    ('\u{3158}', "\u{116a}"),
+    ('\u{3159}', "\u{116b}"),
+    ('\u{315a}', "\u{116c}"),
+    ('\u{315b}', "\u{116d}"),
+    ('\u{315c}', "\u{116e}"),
+    ('\u{315d}', "\u{116f}"),
+    ('\u{315e}', "\u{1170}"),
+    ('\u{315f}', "\u{1171}"),
+    ('\u{3160}', "\u{1172}"),
+    ('\u{3161}', "\u{1173}"),
+    ('\u{3162}', "\u{1174}"),
+    ('\u{3163}', "\u{1175}"),
+    ('\u{3164}', "\u{1160}"),
+    ('\u{3165}', "\u{1114}"),
+    ('\u{3166}', "\u{1115}"),
+    ('\u{3167}', "\u{11c7}"),
+    ('\u{3168}', "\u{11c8}"),
+    ('\u{3169}', "\u{11cc}"),
+    ('\u{316a}', "\u{11ce}"),
+    ('\u{316b}', "\u{11d3}"),
+    ('\u{316c}', "\u{11d7}"),
+    ('\u{316d}', "\u{11d9}"),
+    ('\u{316e}', "\u{111c}"),
+    ('\u{316f}', "\u{11dd}"),
+    ('\u{3170}', "\u{11df}"),
+    ('\u{3171}', "\u{111d}"),
+    ('\u{3172}', "\u{111e}"),
+    ('\u{3173}', "\u{1120}"),
+    ('\u{3174}', "\u{1122}"),
+    ('\u{3175}', "\u{1123}"),
+    ('\u{3176}', "\u{1127}"),
+    ('\u{3177}', "\u{1129}"),
+    ('\u{3178}', "\u{112b}"),
+    ('\u{3179}', "\u{112c}"),
+    ('\u{317a}', "\u{112d}"),
+    ('\u{317b}', "\u{112e}"),
+    ('\u{317c}', "\u{112f}"),
+    ('\u{317d}', "\u{1132}"),
+    ('\u{317e}', "\u{1136}"),
+    ('\u{317f}', "\u{1140}"),
+    ('\u{3180}', "\u{1147}"),
+    ('\u{3181}', "\u{114c}"),
+    ('\u{3182}', "\u{11f1}"),
+    ('\u{3183}', "\u{11f2}"),
+    ('\u{3184}', "\u{1157}"),
+    ('\u{3185}', "\u{1158}"),
+    ('\u{3186}', "\u{1159}"),
+    ('\u{3187}', "\u{1184}"),
+    ('\u{3188}', "\u{1185}"),
+    ('\u{3189}', "\u{1188}"),
+    ('\u{318a}', "\u{1191}"),
+    ('\u{318b}', "\u{1192}"),
+    ('\u{318c}', "\u{1194}"),
+    ('\u{318d}', "\u{119e}"),
+    ('\u{318e}', "\u{11a1}"),
+    ('\u{3192}', "\u{4e00}"),
+    ('\u{3193}', "\u{4e8c}"),
+    ('\u{3194}', "\u{4e09}"),
+    ('\u{3195}', "\u{56db}"),
+    ('\u{3196}', "\u{4e0a}"),
+    ('\u{3197}', "\u{4e2d}"),
+    ('\u{3198}', "\u{4e0b}"),
+    ('\u{3199}', "\u{7532}"),
+    ('\u{319a}', "\u{4e59}"),
+    ('\u{319b}', "\u{4e19}"),
+    ('\u{319c}', "\u{4e01}"),
+    ('\u{319d}', "\u{5929}"),
+    ('\u{319e}', "\u{5730}"),
+    ('\u{319f}', "\u{4eba}"),
+    ('\u{3200}', "\u{0028}\u{1100}\u{0029}"),
+    ('\u{3201}', "\u{0028}\u{1102}\u{0029}"),
+    ('\u{3202}', "\u{0028}\u{1103}\u{0029}"),
+    ('\u{3203}', "\u{0028}\u{1105}\u{0029}"),
+    ('\u{3204}', "\u{0028}\u{1106}\u{0029}"),
+    ('\u{3205}', "\u{0028}\u{1107}\u{0029}"),
+    ('\u{3206}', "\u{0028}\u{1109}\u{0029}"),
+    ('\u{3207}', "\u{0028}\u{110b}\u{0029}"),
+    ('\u{3208}', "\u{0028}\u{110c}\u{0029}"),
+    ('\u{3209}', "\u{0028}\u{110e}\u{0029}"),
+    ('\u{320a}', "\u{0028}\u{110f}\u{0029}"),
+    ('\u{320b}', "\u{0028}\u{1110}\u{0029}"),
+    ('\u{320c}', "\u{0028}\u{1111}\u{0029}"),
+    ('\u{320d}', "\u{0028}\u{1112}\u{0029}"),
+    ('\u{320e}', "\u{0028}\u{1100}\u{1161}\u{0029}"),
+    ('\u{320f}', "\u{0028}\u{1102}\u{1161}\u{0029}"),
+    ('\u{3210}', "\u{0028}\u{1103}\u{1161}\u{0029}"),
+    ('\u{3211}', "\u{0028}\u{1105}\u{1161}\u{0029}"),
+    ('\u{3212}', "\u{0028}\u{1106}\u{1161}\u{0029}"),
+    ('\u{3213}', "\u{0028}\u{1107}\u{1161}\u{0029}"),
+    ('\u{3214}', "\u{0028}\u{1109}\u{1161}\u{0029}"),
+    ('\u{3215}', "\u{0028}\u{110b}\u{1161}\u{0029}"),
+    ('\u{3216}', "\u{0028}\u{110c}\u{1161}\u{0029}"),
+    ('\u{3217}', "\u{0028}\u{110e}\u{1161}\u{0029}"),
+    ('\u{3218}', "\u{0028}\u{110f}\u{1161}\u{0029}"),
+    ('\u{3219}', "\u{0028}\u{1110}\u{1161}\u{0029}"),
+    ('\u{321a}', "\u{0028}\u{1111}\u{1161}\u{0029}"),
+    ('\u{321b}', "\u{0028}\u{1112}\u{1161}\u{0029}"),
+    ('\u{321c}', "\u{0028}\u{110c}\u{116e}\u{0029}"),
+    ('\u{321d}', "\u{0028}\u{110b}\u{1169}\u{110c}\u{1165}\u{11ab}\u{0029}"),
+    ('\u{321e}', "\u{0028}\u{110b}\u{1169}\u{1112}\u{116e}\u{0029}"),
+    ('\u{3220}', "\u{0028}\u{4e00}\u{0029}"),
+    ('\u{3221}', "\u{0028}\u{4e8c}\u{0029}"),
+    ('\u{3222}', "\u{0028}\u{4e09}\u{0029}"),
+    ('\u{3223}', "\u{0028}\u{56db}\u{0029}"),
+    ('\u{3224}', "\u{0028}\u{4e94}\u{0029}"),
+    ('\u{3225}', "\u{0028}\u{516d}\u{0029}"),
+    ('\u{3226}', "\u{0028}\u{4e03}\u{0029}"),
+    ('\u{3227}', "\u{0028}\u{516b}\u{0029}"),
+    ('\u{3228}', "\u{0028}\u{4e5d}\u{0029}"),
+    ('\u{3229}', "\u{0028}\u{5341}\u{0029}"),
+    ('\u{322a}', "\u{0028}\u{6708}\u{0029}"),
+    ('\u{322b}', "\u{0028}\u{706b}\u{0029}"),
+    ('\u{322c}', "\u{0028}\u{6c34}\u{0029}"),
+    ('\u{322d}', "\u{0028}\u{6728}\u{0029}"),
+    ('\u{322e}', "\u{0028}\u{91d1}\u{0029}"),
+    ('\u{322f}', "\u{0028}\u{571f}\u{0029}"),
+    ('\u{3230}', "\u{0028}\u{65e5}\u{0029}"),
+    ('\u{3231}', "\u{0028}\u{682a}\u{0029}"),
+    ('\u{3232}', "\u{0028}\u{6709}\u{0029}"),
+    ('\u{3233}', "\u{0028}\u{793e}\u{0029}"),
+    ('\u{3234}', "\u{0028}\u{540d}\u{0029}"),
+    ('\u{3235}', "\u{0028}\u{7279}\u{0029}"),
+    ('\u{3236}', "\u{0028}\u{8ca1}\u{0029}"),
+    ('\u{3237}', "\u{0028}\u{795d}\u{0029}"),
+    ('\u{3238}', "\u{0028}\u{52b4}\u{0029}"),
+    ('\u{3239}', "\u{0028}\u{4ee3}\u{0029}"),
+    ('\u{323a}', "\u{0028}\u{547c}\u{0029}"),
+    ('\u{323b}', "\u{0028}\u{5b66}\u{0029}"),
+    ('\u{323c}', "\u{0028}\u{76e3}\u{0029}"),
+    ('\u{323d}', "\u{0028}\u{4f01}\u{0029}"),
+    ('\u{323e}', "\u{0028}\u{8cc7}\u{0029}"),
+    ('\u{323f}', "\u{0028}\u{5354}\u{0029}"),
+    ('\u{3240}', "\u{0028}\u{796d}\u{0029}"),
+    ('\u{3241}', "\u{0028}\u{4f11}\u{0029}"),
+    ('\u{3242}', "\u{0028}\u{81ea}\u{0029}"),
+    ('\u{3243}', "\u{0028}\u{81f3}\u{0029}"),
+    ('\u{3244}', "\u{554f}"),
+    ('\u{3245}', "\u{5e7c}"),
+    ('\u{3246}', "\u{6587}"),
+    ('\u{3247}', "\u{7b8f}"),
+    ('\u{3250}', "pte"),
+    ('\u{3251}', "21"),
+    ('\u{3252}', "22"),
+    ('\u{3253}', "23"),
+    ('\u{3254}', "24"),
+    ('\u{3255}', "25"),
+    ('\u{3256}', "26"),
+    ('\u{3257}', "27"),
+    ('\u{3258}', "28"),
+    ('\u{3259}', "29"),
+    ('\u{325a}', "30"),
+    ('\u{325b}', "31"),
+    ('\u{325c}', "32"),
+    ('\u{325d}', "33"),
+    ('\u{325e}', "34"),
+    ('\u{325f}', "35"),
+    ('\u{3260}', "\u{1100}"),
+    ('\u{3261}', "\u{1102}"),
+    ('\u{3262}', "\u{1103}"),
+    ('\u{3263}', "\u{1105}"),
+    ('\u{3264}', "\u{1106}"),
+    ('\u{3265}', "\u{1107}"),
+    ('\u{3266}', "\u{1109}"),
+    ('\u{3267}', "\u{110b}"),
+    ('\u{3268}', "\u{110c}"),
+    ('\u{3269}', "\u{110e}"),
+    ('\u{326a}', "\u{110f}"),
+    ('\u{326b}', "\u{1110}"),
+    ('\u{326c}', "\u{1111}"),
+    ('\u{326d}', "\u{1112}"),
+    ('\u{326e}', "\u{1100}\u{1161}"),
+    ('\u{326f}', "\u{1102}\u{1161}"),
+    ('\u{3270}', "\u{1103}\u{1161}"),
+    ('\u{3271}', "\u{1105}\u{1161}"),
+    ('\u{3272}', "\u{1106}\u{1161}"),
+    ('\u{3273}', "\u{1107}\u{1161}"),
+    ('\u{3274}', "\u{1109}\u{1161}"),
+    ('\u{3275}', "\u{110b}\u{1161}"),
+    ('\u{3276}', "\u{110c}\u{1161}"),
+    ('\u{3277}', "\u{110e}\u{1161}"),
+    ('\u{3278}', "\u{110f}\u{1161}"),
+    ('\u{3279}', "\u{1110}\u{1161}"),
+    ('\u{327a}', "\u{1111}\u{1161}"),
+    ('\u{327b}', "\u{1112}\u{1161}"),
+    ('\u{327c}', "\u{110e}\u{1161}\u{11b7}\u{1100}\u{1169}"),
+    ('\u{327d}', "\u{110c}\u{116e}\u{110b}\u{1174}"),
+    ('\u{327e}', "\u{110b}\u{116e}"),
+    ('\u{3280}', "\u{4e00}"),
+    ('\u{3281}', "\u{4e8c}"),
+    ('\u{3282}', "\u{4e09}"),
+    ('\u{3283}', "\u{56db}"),
+    ('\u{3284}', "\u{4e94}"),
+    ('\u{3285}', "\u{516d}"),
+    ('\u{3286}', "\u{4e03}"),
+    ('\u{3287}', "\u{516b}"),
+    ('\u{3288}', "\u{4e5d}"),
+    ('\u{3289}', "\u{5341}"),
+    ('\u{328a}', "\u{6708}"),
+    ('\u{328b}', "\u{706b}"),
+    ('\u{328c}', "\u{6c34}"),
+    ('\u{328d}', "\u{6728}"),
+    ('\u{328e}', "\u{91d1}"),
+    ('\u{328f}', "\u{571f}"),
+    ('\u{3290}', "\u{65e5}"),
+    ('\u{3291}', "\u{682a}"),
+    ('\u{3292}', "\u{6709}"),
+    ('\u{3293}', "\u{793e}"),
+    ('\u{3294}', "\u{540d}"),
+    ('\u{3295}', "\u{7279}"),
+    ('\u{3296}', "\u{8ca1}"),
+    ('\u{3297}', "\u{795d}"),
+    ('\u{3298}', "\u{52b4}"),
+    ('\u{3299}', "\u{79d8}"),
+    ('\u{329a}', "\u{7537}"),
+    ('\u{329b}', "\u{5973}"),
+    ('\u{329c}', "\u{9069}"),
+    ('\u{329d}', "\u{512a}"),
+    ('\u{329e}', "\u{5370}"),
+    ('\u{329f}', "\u{6ce8}"),
+    ('\u{32a0}', "\u{9805}"),
+    ('\u{32a1}', "\u{4f11}"),
+    ('\u{32a2}', "\u{5199}"),
+    ('\u{32a3}', "\u{6b63}"),
+    ('\u{32a4}', "\u{4e0a}"),
+    ('\u{32a5}', "\u{4e2d}"),
+    ('\u{32a6}', "\u{4e0b}"),
+    ('\u{32a7}', "\u{5de6}"),
+    ('\u{32a8}', "\u{53f3}"),
+    ('\u{32a9}', "\u{533b}"),
+    ('\u{32aa}', "\u{5b97}"),
+    ('\u{32ab}', "\u{5b66}"),
+    ('\u{32ac}', "\u{76e3}"),
+    ('\u{32ad}', "\u{4f01}"),
+    ('\u{32ae}', "\u{8cc7}"),
+    ('\u{32af}', "\u{5354}"),
+    ('\u{32b0}', "\u{591c}"),
+    ('\u{32b1}', "36"),
+    ('\u{32b2}', "37"),
+    ('\u{32b3}', "38"),
+    ('\u{32b4}', "39"),
+    ('\u{32b5}', "40"),
+    ('\u{32b6}', "41"),
+    ('\u{32b7}', "42"),
+    ('\u{32b8}', "43"),
+    ('\u{32b9}', "44"),
+    ('\u{32ba}', "45"),
+    ('\u{32bb}', "46"),
+    ('\u{32bc}', "47"),
+    ('\u{32bd}', "48"),
+    ('\u{32be}', "49"),
+    ('\u{32bf}', "50"),
+    ('\u{32c0}', "\u{0031}\u{6708}"),
+    ('\u{32c1}', "\u{0032}\u{6708}"),
+    ('\u{32c2}', "\u{0033}\u{6708}"),
+    ('\u{32c3}', "\u{0034}\u{6708}"),
+    ('\u{32c4}', "\u{0035}\u{6708}"),
+    ('\u{32c5}', "\u{0036}\u{6708}"),
+    ('\u{32c6}', "\u{0037}\u{6708}"),
+    ('\u{32c7}', "\u{0038}\u{6708}"),
+    ('\u{32c8}', "\u{0039}\u{6708}"),
+    ('\u{32c9}', "\u{0031}\u{0030}\u{6708}"),
+    ('\u{32ca}', "\u{0031}\u{0031}\u{6708}"),
+    ('\u{32cb}', "\u{0031}\u{0032}\u{6708}"),
+    ('\u{32cc}', "hg"),
+    ('\u{32cd}', "erg"),
+    ('\u{32ce}', "ev"),
+    ('\u{32cf}', "ltd"),
+    ('\u{32d0}', "\u{30a2}"),
+    ('\u{32d1}', "\u{30a4}"),
+    ('\u{32d2}', "\u{30a6}"),
+    ('\u{32d3}', "\u{30a8}"),
+    ('\u{32d4}', "\u{30aa}"),
+    ('\u{32d5}', "\u{30ab}"),
+    ('\u{32d6}', "\u{30ad}"),
+    ('\u{32d7}', "\u{30af}"),
+    ('\u{32d8}', "\u{30b1}"),
+    ('\u{32d9}', "\u{30b3}"),
+    ('\u{32da}', "\u{30b5}"),
+    ('\u{32db}', "\u{30b7}"),
+    ('\u{32dc}', "\u{30b9}"),
+    ('\u{32dd}', "\u{30bb}"),
+    ('\u{32de}', "\u{30bd}"),
+    ('\u{32df}', "\u{30bf}"),
+    ('\u{32e0}', "\u{30c1}"),
+    ('\u{32e1}', "\u{30c4}"),
+    ('\u{32e2}', "\u{30c6}"),
+    ('\u{32e3}', "\u{30c8}"),
+    ('\u{32e4}', "\u{30ca}"),
+    ('\u{32e5}', "\u{30cb}"),
+    ('\u{32e6}', "\u{30cc}"),
+    ('\u{32e7}', "\u{30cd}"),
+    ('\u{32e8}', "\u{30ce}"),
+    ('\u{32e9}', "\u{30cf}"),
+    ('\u{32ea}', "\u{30d2}"),
+    ('\u{32eb}', "\u{30d5}"),
+    ('\u{32ec}', "\u{30d8}"),
+    ('\u{32ed}', "\u{30db}"),
+    ('\u{32ee}', "\u{30de}"),
+    ('\u{32ef}', "\u{30df}"),
+    ('\u{32f0}', "\u{30e0}"),
+    ('\u{32f1}', "\u{30e1}"),
+    ('\u{32f2}', "\u{30e2}"),
+    ('\u{32f3}', "\u{30e4}"),
+    ('\u{32f4}', "\u{30e6}"),
+    ('\u{32f5}', "\u{30e8}"),
+    ('\u{32f6}', "\u{30e9}"),
+    ('\u{32f7}', "\u{30ea}"),
+    ('\u{32f8}', "\u{30eb}"),
+    ('\u{32f9}', "\u{30ec}"),
+    ('\u{32fa}', "\u{30ed}"),
+    ('\u{32fb}', "\u{30ef}"),
+    ('\u{32fc}', "\u{30f0}"),
+    ('\u{32fd}', "\u{30f1}"),
+    ('\u{32fe}', "\u{30f2}"),
+    ('\u{32ff}', "\u{4ee4}\u{548c}"),
+    ('\u{3300}', "\u{30a2}\u{30cf}\u{30fc}\u{30c8}"),
+    ('\u{3301}', "\u{30a2}\u{30eb}\u{30d5}\u{30a1}"),
+    ('\u{3302}', "\u{30a2}\u{30f3}\u{30d8}\u{30a2}"),
+    ('\u{3303}', "\u{30a2}\u{30fc}\u{30eb}"),
+    ('\u{3304}', "\u{30a4}\u{30cb}\u{30f3}\u{30af}"),
+    ('\u{3305}', "\u{30a4}\u{30f3}\u{30c1}"),
+    ('\u{3306}', "\u{30a6}\u{30a9}\u{30f3}"),
+    ('\u{3307}', "\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c8}"),
+    ('\u{3308}', "\u{30a8}\u{30fc}\u{30ab}\u{30fc}"),
+    ('\u{3309}', "\u{30aa}\u{30f3}\u{30b9}"),
+    ('\u{330a}', "\u{30aa}\u{30fc}\u{30e0}"),
+    ('\u{330b}', "\u{30ab}\u{30a4}\u{30ea}"),
+    ('\u{330c}', "\u{30ab}\u{30e9}\u{30c3}\u{30c8}"),
+    ('\u{330d}', "\u{30ab}\u{30ed}\u{30ea}\u{30fc}"),
+    ('\u{330e}', "\u{30ab}\u{30ed}\u{30f3}"),
+    ('\u{330f}', "\u{30ab}\u{30f3}\u{30de}"),
+    ('\u{3310}', "\u{30ad}\u{30ab}"),
+    ('\u{3311}', "\u{30ad}\u{30cb}\u{30fc}"),
+    ('\u{3312}', "\u{30ad}\u{30e5}\u{30ea}\u{30fc}"),
+    ('\u{3313}', "\u{30ad}\u{30eb}\u{30bf}\u{30fc}"),
+    ('\u{3314}', "\u{30ad}\u{30ed}"),
+    ('\u{3315}', "\u{30ad}\u{30ed}\u{30af}\u{30e9}\u{30e0}"),
+    ('\u{3316}', "\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    ('\u{3317}', "\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}"),
+    ('\u{3318}', "\u{30af}\u{30e9}\u{30e0}"),
+    ('\u{3319}', "\u{30af}\u{30e9}\u{30e0}\u{30c8}\u{30f3}"),
+    ('\u{331a}', "\u{30af}\u{30eb}\u{30bb}\u{30a4}\u{30ed}"),
+    ('\u{331b}', "\u{30af}\u{30ed}\u{30fc}\u{30cd}"),
+    ('\u{331c}', "\u{30b1}\u{30fc}\u{30b9}"),
+    ('\u{331d}', "\u{30b3}\u{30eb}\u{30ca}"),
+    ('\u{331e}', "\u{30b3}\u{30fc}\u{30db}"),
+    ('\u{331f}', "\u{30b5}\u{30a4}\u{30af}\u{30eb}"),
+    ('\u{3320}', "\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}"),
+    ('\u{3321}', "\u{30b7}\u{30ea}\u{30f3}\u{30af}"),
+    ('\u{3322}', "\u{30bb}\u{30f3}\u{30c1}"),
+    ('\u{3323}', "\u{30bb}\u{30f3}\u{30c8}"),
+    ('\u{3324}', "\u{30bf}\u{30fc}\u{30b9}"),
+    ('\u{3325}', "\u{30c6}\u{30b7}"),
+    ('\u{3326}', "\u{30c8}\u{30eb}"),
+    ('\u{3327}', "\u{30c8}\u{30f3}"),
+    ('\u{3328}', "\u{30ca}\u{30ce}"),
+    ('\u{3329}', "\u{30ce}\u{30c3}\u{30c8}"),
+    ('\u{332a}', "\u{30cf}\u{30a4}\u{30c4}"),
+    ('\u{332b}', "\u{30cf}\u{30fc}\u{30bb}\u{30f3}\u{30c8}"),
+    ('\u{332c}', "\u{30cf}\u{30fc}\u{30c4}"),
+    ('\u{332d}', "\u{30cf}\u{30fc}\u{30ec}\u{30eb}"),
+    ('\u{332e}', "\u{30d2}\u{30a2}\u{30b9}\u{30c8}\u{30eb}"),
+    ('\u{332f}', "\u{30d2}\u{30af}\u{30eb}"),
+    ('\u{3330}', "\u{30d2}\u{30b3}"),
+    ('\u{3331}', "\u{30d2}\u{30eb}"),
+    ('\u{3332}', "\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c8}"),
+    ('\u{3333}', "\u{30d5}\u{30a3}\u{30fc}\u{30c8}"),
+    ('\u{3334}', "\u{30d5}\u{30c3}\u{30b7}\u{30a7}\u{30eb}"),
+    ('\u{3335}', "\u{30d5}\u{30e9}\u{30f3}"),
+    ('\u{3336}', "\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}"),
+    ('\u{3337}', "\u{30d8}\u{30bd}"),
+    ('\u{3338}', "\u{30d8}\u{30cb}\u{30d2}"),
+    ('\u{3339}', "\u{30d8}\u{30eb}\u{30c4}"),
+    ('\u{333a}', "\u{30d8}\u{30f3}\u{30b9}"),
+    ('\u{333b}', "\u{30d8}\u{30fc}\u{30b7}"),
+    ('\u{333c}', "\u{30d8}\u{30fc}\u{30bf}"),
+    ('\u{333d}', "\u{30db}\u{30a4}\u{30f3}\u{30c8}"),
+    ('\u{333e}', "\u{30db}\u{30eb}\u{30c8}"),
+    ('\u{333f}', "\u{30db}\u{30f3}"),
+    ('\u{3340}', "\u{30db}\u{30f3}\u{30c8}"),
+    ('\u{3341}', "\u{30db}\u{30fc}\u{30eb}"),
+    ('\u{3342}', "\u{30db}\u{30fc}\u{30f3}"),
+    ('\u{3343}', "\u{30de}\u{30a4}\u{30af}\u{30ed}"),
+    ('\u{3344}', "\u{30de}\u{30a4}\u{30eb}"),
+    ('\u{3345}', "\u{30de}\u{30c3}\u{30cf}"),
+    ('\u{3346}', "\u{30de}\u{30eb}\u{30af}"),
+    ('\u{3347}', "\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}"),
+    ('\u{3348}', "\u{30df}\u{30af}\u{30ed}\u{30f3}"),
+    ('\u{3349}', "\u{30df}\u{30ea}"),
+    ('\u{334a}', "\u{30df}\u{30ea}\u{30cf}\u{30fc}\u{30eb}"),
+    ('\u{334b}', "\u{30e1}\u{30ab}"),
+    ('\u{334c}', "\u{30e1}\u{30ab}\u{30c8}\u{30f3}"),
+    ('\u{334d}', "\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    ('\u{334e}', "\u{30e4}\u{30fc}\u{30c8}"),
+    ('\u{334f}', "\u{30e4}\u{30fc}\u{30eb}"),
+    ('\u{3350}', "\u{30e6}\u{30a2}\u{30f3}"),
+    ('\u{3351}', "\u{30ea}\u{30c3}\u{30c8}\u{30eb}"),
+    ('\u{3352}', "\u{30ea}\u{30e9}"),
+    ('\u{3353}', "\u{30eb}\u{30d2}\u{30fc}"),
+    ('\u{3354}', "\u{30eb}\u{30fc}\u{30d5}\u{30eb}"),
+    ('\u{3355}', "\u{30ec}\u{30e0}"),
+    ('\u{3356}', "\u{30ec}\u{30f3}\u{30c8}\u{30b1}\u{30f3}"),
+    ('\u{3357}', "\u{30ef}\u{30c3}\u{30c8}"),
+    ('\u{3358}', "\u{0030}\u{70b9}"),
+    ('\u{3359}', "\u{0031}\u{70b9}"),
+    ('\u{335a}', "\u{0032}\u{70b9}"),
+    ('\u{335b}', "\u{0033}\u{70b9}"),
+    ('\u{335c}', "\u{0034}\u{70b9}"),
+    ('\u{335d}', "\u{0035}\u{70b9}"),
+    ('\u{335e}', "\u{0036}\u{70b9}"),
+    ('\u{335f}', "\u{0037}\u{70b9}"),
+    ('\u{3360}', "\u{0038}\u{70b9}"),
+    ('\u{3361}', "\u{0039}\u{70b9}"),
+    ('\u{3362}', "\u{0031}\u{0030}\u{70b9}"),
+    ('\u{3363}', "\u{0031}\u{0031}\u{70b9}"),
+    ('\u{3364}', "\u{0031}\u{0032}\u{70b9}"),
+    ('\u{3365}', "\u{0031}\u{0033}\u{70b9}"),
+    ('\u{3366}', "\u{0031}\u{0034}\u{70b9}"),
+    ('\u{3367}', "\u{0031}\u{0035}\u{70b9}"),
+    ('\u{3368}', "\u{0031}\u{0036}\u{70b9}"),
+    ('\u{3369}', "\u{0031}\u{0037}\u{70b9}"),
+    ('\u{336a}', "\u{0031}\u{0038}\u{70b9}"),
+    ('\u{336b}', "\u{0031}\u{0039}\u{70b9}"),
+    ('\u{336c}', "\u{0032}\u{0030}\u{70b9}"),
+    ('\u{336d}', "\u{0032}\u{0031}\u{70b9}"),
+    ('\u{336e}', "\u{0032}\u{0032}\u{70b9}"),
+    ('\u{336f}', "\u{0032}\u{0033}\u{70b9}"),
+    ('\u{3370}', "\u{0032}\u{0034}\u{70b9}"),
+    ('\u{3371}', "hpa"),
+    ('\u{3372}', "da"),
+    ('\u{3373}', "au"),
+    ('\u{3374}', "bar"),
+    ('\u{3375}', "ov"),
+    ('\u{3376}', "pc"),
+    ('\u{3377}', "dm"),
+    ('\u{3378}', "dm2"),
+    ('\u{3379}', "dm3"),
+    ('\u{337a}', "iu"),
+    ('\u{337b}', "\u{5e73}\u{6210}"),
+    ('\u{337c}', "\u{662d}\u{548c}"),
+    ('\u{337d}', "\u{5927}\u{6b63}"),
+    ('\u{337e}', "\u{660e}\u{6cbb}"),
+    ('\u{337f}', "\u{682a}\u{5f0f}\u{4f1a}\u{793e}"),
+    ('\u{3380}', "pa"),
+    ('\u{3381}', "na"),
+    ('\u{3382}', "\u{03bc}\u{0061}"),
+    ('\u{3383}', "ma"),
+    ('\u{3384}', "ka"),
+    ('\u{3385}', "kb"),
+    ('\u{3386}', "mb"),
+    ('\u{3387}', "gb"),
+    ('\u{3388}', "cal"),
+    ('\u{3389}', "kcal"),
+    ('\u{338a}', "pf"),
+    ('\u{338b}', "nf"),
+    ('\u{338c}', "\u{03bc}\u{0066}"),
+    ('\u{338d}', "\u{03bc}\u{0067}"),
+    ('\u{338e}', "mg"),
+    ('\u{338f}', "kg"),
+    ('\u{3390}', "hz"),
+    ('\u{3391}', "khz"),
+    ('\u{3392}', "mhz"),
+    ('\u{3393}', "ghz"),
+    ('\u{3394}', "thz"),
+    ('\u{3395}', "\u{03bc}\u{006c}"),
+    ('\u{3396}', "ml"),
+    ('\u{3397}', "dl"),
+    ('\u{3398}', "kl"),
+    ('\u{3399}', "fm"),
+    ('\u{339a}', "nm"),
+    ('\u{339b}', "\u{03bc}\u{006d}"),
+    ('\u{339c}', "mm"),
+    ('\u{339d}', "cm"),
+    ('\u{339e}', "km"),
+    ('\u{339f}', "mm2"),
+    ('\u{33a0}', "cm2"),
+    ('\u{33a1}', "m2"),
+    ('\u{33a2}', "km2"),
+    ('\u{33a3}', "mm3"),
+    ('\u{33a4}', "cm3"),
+    ('\u{33a5}', "m3"),
+    ('\u{33a6}', "km3"),
+    ('\u{33a7}', "\u{006d}\u{2215}\u{0073}"),
+    ('\u{33a8}', "\u{006d}\u{2215}\u{0073}\u{0032}"),
+    ('\u{33a9}', "pa"),
+    ('\u{33aa}', "kpa"),
+    ('\u{33ab}', "mpa"),
+    ('\u{33ac}', "gpa"),
+    ('\u{33ad}', "rad"),
+    ('\u{33ae}', "\u{0072}\u{0061}\u{0064}\u{2215}\u{0073}"),
+    ('\u{33af}', "\u{0072}\u{0061}\u{0064}\u{2215}\u{0073}\u{0032}"),
+    ('\u{33b0}', "ps"),
+    ('\u{33b1}', "ns"),
+    ('\u{33b2}', "\u{03bc}\u{0073}"),
+    ('\u{33b3}', "ms"),
+    ('\u{33b4}', "pv"),
+    ('\u{33b5}', "nv"),
+    ('\u{33b6}', "\u{03bc}\u{0076}"),
+    ('\u{33b7}', "mv"),
+    ('\u{33b8}', "kv"),
+    ('\u{33b9}', "mv"),
+    ('\u{33ba}', "pw"),
+    ('\u{33bb}', "nw"),
+    ('\u{33bc}', "\u{03bc}\u{0077}"),
+    ('\u{33bd}', "mw"),
+    ('\u{33be}', "kw"),
+    ('\u{33bf}', "mw"),
+    ('\u{33c0}', "\u{006b}\u{03c9}"),
+    ('\u{33c1}', "\u{006d}\u{03c9}"),
+    ('\u{33c2}', "\u{0061}\u{002e}\u{006d}\u{002e}"),
+    ('\u{33c3}', "bq"),
+    ('\u{33c4}', "cc"),
+    ('\u{33c5}', "cd"),
+    ('\u{33c6}', "\u{0063}\u{2215}\u{006b}\u{0067}"),
+    ('\u{33c7}', "\u{0063}\u{006f}\u{002e}"),
+    ('\u{33c8}', "db"),
+    ('\u{33c9}', "gy"),
+    ('\u{33ca}', "ha"),
+    ('\u{33cb}', "hp"),
+    ('\u{33cc}', "in"),
+    ('\u{33cd}', "kk"),
+    ('\u{33ce}', "km"),
+    ('\u{33cf}', "kt"),
+    ('\u{33d0}', "lm"),
+    ('\u{33d1}', "ln"),
+    ('\u{33d2}', "log"),
+    ('\u{33d3}', "lx"),
+    ('\u{33d4}', "mb"),
+    ('\u{33d5}', "mil"),
+    ('\u{33d6}', "mol"),
+    ('\u{33d7}', "ph"),
+    ('\u{33d8}', "\u{0070}\u{002e}\u{006d}\u{002e}"),
+    ('\u{33d9}', "ppm"),
+    ('\u{33da}', "pr"),
+    ('\u{33db}', "sr"),
+    ('\u{33dc}', "sv"),
+    ('\u{33dd}', "wb"),
+    ('\u{33de}', "\u{0076}\u{2215}\u{006d}"),
+    ('\u{33df}', "\u{0061}\u{2215}\u{006d}"),
+    ('\u{33e0}', "\u{0031}\u{65e5}"),
+    ('\u{33e1}', "\u{0032}\u{65e5}"),
+    ('\u{33e2}', "\u{0033}\u{65e5}"),
+    ('\u{33e3}', "\u{0034}\u{65e5}"),
+    ('\u{33e4}', "\u{0035}\u{65e5}"),
+    ('\u{33e5}', "\u{0036}\u{65e5}"),
+    ('\u{33e6}', "\u{0037}\u{65e5}"),
+    ('\u{33e7}', "\u{0038}\u{65e5}"),
+    ('\u{33e8}', "\u{0039}\u{65e5}"),
+    ('\u{33e9}', "\u{0031}\u{0030}\u{65e5}"),
+    ('\u{33ea}', "\u{0031}\u{0031}\u{65e5}"),
+    ('\u{33eb}', "\u{0031}\u{0032}\u{65e5}"),
+    ('\u{33ec}', "\u{0031}\u{0033}\u{65e5}"),
+    ('\u{33ed}', "\u{0031}\u{0034}\u{65e5}"),
+    ('\u{33ee}', "\u{0031}\u{0035}\u{65e5}"),
+    ('\u{33ef}', "\u{0031}\u{0036}\u{65e5}"),
+    ('\u{33f0}', "\u{0031}\u{0037}\u{65e5}"),
+    ('\u{33f1}', "\u{0031}\u{0038}\u{65e5}"),
+    ('\u{33f2}', "\u{0031}\u{0039}\u{65e5}"),
+    ('\u{33f3}', "\u{0032}\u{0030}\u{65e5}"),
+    ('\u{33f4}', "\u{0032}\u{0031}\u{65e5}"),
+    ('\u{33f5}', "\u{0032}\u{0032}\u{65e5}"),
+    ('\u{33f6}', "\u{0032}\u{0033}\u{65e5}"),
+    ('\u{33f7}', "\u{0032}\u{0034}\u{65e5}"),
+    ('\u{33f8}', "\u{0032}\u{0035}\u{65e5}"),
+    ('\u{33f9}', "\u{0032}\u{0036}\u{65e5}"),
+    ('\u{33fa}', "\u{0032}\u{0037}\u{65e5}"),
+    ('\u{33fb}', "\u{0032}\u{0038}\u{65e5}"),
+    ('\u{33fc}', "\u{0032}\u{0039}\u{65e5}"),
+    ('\u{33fd}', "\u{0033}\u{0030}\u{65e5}"),
+    ('\u{33fe}', "\u{0033}\u{0031}\u{65e5}"),
+    ('\u{33ff}', "gal"),
+    ('\u{a640}', "\u{a641}"),
+    ('\u{a642}', "\u{a643}"),
+    ('\u{a644}', "\u{a645}"),
+    ('\u{a646}', "\u{a647}"),
+    ('\u{a648}', "\u{a649}"),
+    ('\u{a64a}', "\u{a64b}"),
+    ('\u{a64c}', "\u{a64d}"),
+    ('\u{a64e}', "\u{a64f}"),
+    ('\u{a650}', "\u{a651}"),
+    ('\u{a652}', "\u{a653}"),
+    ('\u{a654}', "\u{a655}"),
+    ('\u{a656}', "\u{a657}"),
+    ('\u{a658}', "\u{a659}"),
+    ('\u{a65a}', "\u{a65b}"),
+    ('\u{a65c}', "\u{a65d}"),
+    ('\u{a65e}', "\u{a65f}"),
+    ('\u{a660}', "\u{a661}"),
+    ('\u{a662}', "\u{a663}"),
+    ('\u{a664}', "\u{a665}"),
+    ('\u{a666}', "\u{a667}"),
+    ('\u{a668}', "\u{a669}"),
+    ('\u{a66a}', "\u{a66b}"),
+    ('\u{a66c}', "\u{a66d}"),
+    ('\u{a680}', "\u{a681}"),
+    ('\u{a682}', "\u{a683}"),
+    ('\u{a684}', "\u{a685}"),
+    ('\u{a686}', "\u{a687}"),
+    ('\u{a688}', "\u{a689}"),
+    ('\u{a68a}', "\u{a68b}"),
+    ('\u{a68c}', "\u{a68d}"),
+    ('\u{a68e}', "\u{a68f}"),
+    ('\u{a690}', "\u{a691}"),
+    ('\u{a692}', "\u{a693}"),
+    ('\u{a694}', "\u{a695}"),
+    ('\u{a696}', "\u{a697}"),
+    ('\u{a698}', "\u{a699}"),
+    ('\u{a69a}', "\u{a69b}"),
+    ('\u{a69c}', "\u{044a}"),
+    ('\u{a69d}', "\u{044c}"),
+    ('\u{a722}', "\u{a723}"),
+    ('\u{a724}', "\u{a725}"),
+    ('\u{a726}', "\u{a727}"),
+    ('\u{a728}', "\u{a729}"),
+    ('\u{a72a}', "\u{a72b}"),
+    ('\u{a72c}', "\u{a72d}"),
+    ('\u{a72e}', "\u{a72f}"),
+    ('\u{a732}', "\u{a733}"),
+    ('\u{a734}', "\u{a735}"),
+    ('\u{a736}', "\u{a737}"),
+    ('\u{a738}', "\u{a739}"),
+    ('\u{a73a}', "\u{a73b}"),
+    ('\u{a73c}', "\u{a73d}"),
+    ('\u{a73e}', "\u{a73f}"),
+    ('\u{a740}', "\u{a741}"),
+    ('\u{a742}', "\u{a743}"),
+    ('\u{a744}', "\u{a745}"),
+    ('\u{a746}', "\u{a747}"),
+    ('\u{a748}', "\u{a749}"),
+    ('\u{a74a}', "\u{a74b}"),
+    ('\u{a74c}', "\u{a74d}"),
+    ('\u{a74e}', "\u{a74f}"),
+    ('\u{a750}', "\u{a751}"),
+    ('\u{a752}', "\u{a753}"),
+    ('\u{a754}', "\u{a755}"),
+    ('\u{a756}', "\u{a757}"),
+    ('\u{a758}', "\u{a759}"),
+    ('\u{a75a}', "\u{a75b}"),
+    ('\u{a75c}', "\u{a75d}"),
+    ('\u{a75e}', "\u{a75f}"),
+    ('\u{a760}', "\u{a761}"),
+    ('\u{a762}', "\u{a763}"),
+    ('\u{a764}', "\u{a765}"),
+    ('\u{a766}', "\u{a767}"),
+    ('\u{a768}', "\u{a769}"),
+    ('\u{a76a}', "\u{a76b}"),
+    ('\u{a76c}', "\u{a76d}"),
+    ('\u{a76e}', "\u{a76f}"),
+    ('\u{a770}', "\u{a76f}"),
+    ('\u{a779}', "\u{a77a}"),
+    ('\u{a77b}', "\u{a77c}"),
+    ('\u{a77d}', "\u{1d79}"),
+    ('\u{a77e}', "\u{a77f}"),
+    ('\u{a780}', "\u{a781}"),
+    ('\u{a782}', "\u{a783}"),
+    ('\u{a784}', "\u{a785}"),
+    ('\u{a786}', "\u{a787}"),
+    ('\u{a78b}', "\u{a78c}"),
+    ('\u{a78d}', "\u{0265}"),
+    ('\u{a790}', "\u{a791}"),
+    ('\u{a792}', "\u{a793}"),
+    ('\u{a796}', "\u{a797}"),
+    ('\u{a798}', "\u{a799}"),
+    ('\u{a79a}', "\u{a79b}"),
+    ('\u{a79c}', "\u{a79d}"),
+    ('\u{a79e}', "\u{a79f}"),
+    ('\u{a7a0}', "\u{a7a1}"),
+    ('\u{a7a2}', "\u{a7a3}"),
+    ('\u{a7a4}', "\u{a7a5}"),
+    ('\u{a7a6}', "\u{a7a7}"),
+    ('\u{a7a8}', "\u{a7a9}"),
+    ('\u{a7aa}', "\u{0266}"),
+    ('\u{a7ab}', "\u{025c}"),
+    ('\u{a7ac}', "\u{0261}"),
+    ('\u{a7ad}', "\u{026c}"),
+    ('\u{a7ae}', "\u{026a}"),
+    ('\u{a7b0}', "\u{029e}"),
+    ('\u{a7b1}', "\u{0287}"),
+    ('\u{a7b2}', "\u{029d}"),
+    ('\u{a7b3}', "\u{ab53}"),
+    ('\u{a7b4}', "\u{a7b5}"),
+    ('\u{a7b6}', "\u{a7b7}"),
+    ('\u{a7b8}', "\u{a7b9}"),
+    ('\u{a7ba}', "\u{a7bb}"),
+    ('\u{a7bc}', "\u{a7bd}"),
+    ('\u{a7be}', "\u{a7bf}"),
+    ('\u{a7c0}', "\u{a7c1}"),
+    ('\u{a7c2}', "\u{a7c3}"),
+    ('\u{a7c4}', "\u{a794}"),
+    ('\u{a7c5}', "\u{0282}"),
+    ('\u{a7c6}', "\u{1d8e}"),
+    ('\u{a7c7}', "\u{a7c8}"),
+    ('\u{a7c9}', "\u{a7ca}"),
+    ('\u{a7d0}', "\u{a7d1}"),
+    ('\u{a7d6}', "\u{a7d7}"),
+    ('\u{a7d8}', "\u{a7d9}"),
+    ('\u{a7f2}', "c"),
+    ('\u{a7f3}', "f"),
+    ('\u{a7f4}', "q"),
+    ('\u{a7f5}', "\u{a7f6}"),
+    ('\u{a7f8}', "h"),
+    ('\u{a7f9}', "oe"),
+    ('\u{ab5c}', "\u{a727}"),
+    ('\u{ab5d}', "\u{ab37}"),
+    ('\u{ab5e}', "\u{026b}"),
+    ('\u{ab5f}', "\u{ab52}"),
+    ('\u{ab69}', "\u{028d}"),
+    ('\u{ab70}', "\u{13a0}"),
+    ('\u{ab71}', "\u{13a1}"),
+    ('\u{ab72}', "\u{13a2}"),
+    ('\u{ab73}', "\u{13a3}"),
+    ('\u{ab74}', "\u{13a4}"),
+    ('\u{ab75}', "\u{13a5}"),
+    ('\u{ab76}', "\u{13a6}"),
+    ('\u{ab77}', "\u{13a7}"),
+    ('\u{ab78}', "\u{13a8}"),
+    ('\u{ab79}', "\u{13a9}"),
+    ('\u{ab7a}', "\u{13aa}"),
+    ('\u{ab7b}', "\u{13ab}"),
+    ('\u{ab7c}', "\u{13ac}"),
+    ('\u{ab7d}', "\u{13ad}"),
+    ('\u{ab7e}', "\u{13ae}"),
+    ('\u{ab7f}', "\u{13af}"),
+    ('\u{ab80}', "\u{13b0}"),
+    ('\u{ab81}', "\u{13b1}"),
+    ('\u{ab82}', "\u{13b2}"),
+    ('\u{ab83}', "\u{13b3}"),
+    ('\u{ab84}', "\u{13b4}"),
+    ('\u{ab85}', "\u{13b5}"),
+    ('\u{ab86}', "\u{13b6}"),
+    ('\u{ab87}', "\u{13b7}"),
+    ('\u{ab88}', "\u{13b8}"),
+    ('\u{ab89}', "\u{13b9}"),
+    ('\u{ab8a}', "\u{13ba}"),
+    ('\u{ab8b}', "\u{13bb}"),
+    ('\u{ab8c}', "\u{13bc}"),
+    ('\u{ab8d}', "\u{13bd}"),
+    ('\u{ab8e}', "\u{13be}"),
+    ('\u{ab8f}', "\u{13bf}"),
+    ('\u{ab90}', "\u{13c0}"),
+    ('\u{ab91}', "\u{13c1}"),
+    ('\u{ab92}', "\u{13c2}"),
+    ('\u{ab93}', "\u{13c3}"),
+    ('\u{ab94}', "\u{13c4}"),
+    ('\u{ab95}', "\u{13c5}"),
+    ('\u{ab96}', "\u{13c6}"),
+    ('\u{ab97}', "\u{13c7}"),
+    ('\u{ab98}', "\u{13c8}"),
+    ('\u{ab99}', "\u{13c9}"),
+    ('\u{ab9a}', "\u{13ca}"),
+    ('\u{ab9b}', "\u{13cb}"),
+    ('\u{ab9c}', "\u{13cc}"),
+    ('\u{ab9d}', "\u{13cd}"),
+    ('\u{ab9e}', "\u{13ce}"),
+    ('\u{ab9f}', "\u{13cf}"),
+    ('\u{aba0}', "\u{13d0}"),
+    ('\u{aba1}', "\u{13d1}"),
+    ('\u{aba2}', "\u{13d2}"),
+    ('\u{aba3}', "\u{13d3}"),
+    ('\u{aba4}', "\u{13d4}"),
+    ('\u{aba5}', "\u{13d5}"),
+    ('\u{aba6}', "\u{13d6}"),
+    ('\u{aba7}', "\u{13d7}"),
+    ('\u{aba8}', "\u{13d8}"),
+    ('\u{aba9}', "\u{13d9}"),
+    ('\u{abaa}', "\u{13da}"),
+    ('\u{abab}', "\u{13db}"),
+    ('\u{abac}', "\u{13dc}"),
+    ('\u{abad}', "\u{13dd}"),
+    ('\u{abae}', "\u{13de}"),
+    ('\u{abaf}', "\u{13df}"),
+    ('\u{abb0}', "\u{13e0}"),
+    ('\u{abb1}', "\u{13e1}"),
+    ('\u{abb2}', "\u{13e2}"),
+    ('\u{abb3}', "\u{13e3}"),
+    ('\u{abb4}', "\u{13e4}"),
+    ('\u{abb5}', "\u{13e5}"),
+    ('\u{abb6}', "\u{13e6}"),
+    ('\u{abb7}', "\u{13e7}"),
+    ('\u{abb8}', "\u{13e8}"),
+    ('\u{abb9}', "\u{13e9}"),
+    ('\u{abba}', "\u{13ea}"),
+    ('\u{abbb}', "\u{13eb}"),
+    ('\u{abbc}', "\u{13ec}"),
+    ('\u{abbd}', "\u{13ed}"),
+    ('\u{abbe}', "\u{13ee}"),
+    ('\u{abbf}', "\u{13ef}"),
+    ('\u{f900}', "\u{8c48}"),
+    ('\u{f901}', "\u{66f4}"),
+    ('\u{f902}', "\u{8eca}"),
+    ('\u{f903}', "\u{8cc8}"),
+    ('\u{f904}', "\u{6ed1}"),
+    ('\u{f905}', "\u{4e32}"),
+    ('\u{f906}', "\u{53e5}"),
+    ('\u{f907}', "\u{9f9c}"),
+    ('\u{f908}', "\u{9f9c}"),
+    ('\u{f909}', "\u{5951}"),
+    ('\u{f90a}', "\u{91d1}"),
+    ('\u{f90b}', "\u{5587}"),
+    ('\u{f90c}', "\u{5948}"),
+    ('\u{f90d}', "\u{61f6}"),
+    ('\u{f90e}', "\u{7669}"),
+    ('\u{f90f}', "\u{7f85}"),
+    ('\u{f910}', "\u{863f}"),
+    ('\u{f911}', "\u{87ba}"),
+    ('\u{f912}', "\u{88f8}"),
+    ('\u{f913}', "\u{908f}"),
+    ('\u{f914}', "\u{6a02}"),
+    ('\u{f915}', "\u{6d1b}"),
+    ('\u{f916}', "\u{70d9}"),
+    ('\u{f917}', "\u{73de}"),
+    ('\u{f918}', "\u{843d}"),
+    ('\u{f919}', "\u{916a}"),
+    ('\u{f91a}', "\u{99f1}"),
+    ('\u{f91b}', "\u{4e82}"),
+    ('\u{f91c}', "\u{5375}"),
+    ('\u{f91d}', "\u{6b04}"),
+    ('\u{f91e}', "\u{721b}"),
+    ('\u{f91f}', "\u{862d}"),
+    ('\u{f920}', "\u{9e1e}"),
+    ('\u{f921}', "\u{5d50}"),
+    ('\u{f922}', "\u{6feb}"),
+    ('\u{f923}', "\u{85cd}"),
+    ('\u{f924}', "\u{8964}"),
+    ('\u{f925}', "\u{62c9}"),
+    ('\u{f926}', "\u{81d8}"),
+    ('\u{f927}', "\u{881f}"),
+    ('\u{f928}', "\u{5eca}"),
+    ('\u{f929}', "\u{6717}"),
+    ('\u{f92a}', "\u{6d6a}"),
+    ('\u{f92b}', "\u{72fc}"),
+    ('\u{f92c}', "\u{90ce}"),
+    ('\u{f92d}', "\u{4f86}"),
+    ('\u{f92e}', "\u{51b7}"),
+    ('\u{f92f}', "\u{52de}"),
+    ('\u{f930}', "\u{64c4}"),
+    ('\u{f931}', "\u{6ad3}"),
+    ('\u{f932}', "\u{7210}"),
+    ('\u{f933}', "\u{76e7}"),
+    ('\u{f934}', "\u{8001}"),
+    ('\u{f935}', "\u{8606}"),
+    ('\u{f936}', "\u{865c}"),
+    ('\u{f937}', "\u{8def}"),
+    ('\u{f938}', "\u{9732}"),
+    ('\u{f939}', "\u{9b6f}"),
+    ('\u{f93a}', "\u{9dfa}"),
+    ('\u{f93b}', "\u{788c}"),
+    ('\u{f93c}', "\u{797f}"),
+    ('\u{f93d}', "\u{7da0}"),
+    ('\u{f93e}', "\u{83c9}"),
+    ('\u{f93f}', "\u{9304}"),
+    ('\u{f940}', "\u{9e7f}"),
+    ('\u{f941}', "\u{8ad6}"),
+    ('\u{f942}', "\u{58df}"),
+    ('\u{f943}', "\u{5f04}"),
+    ('\u{f944}', "\u{7c60}"),
+    ('\u{f945}', "\u{807e}"),
+    ('\u{f946}', "\u{7262}"),
+    ('\u{f947}', "\u{78ca}"),
+    ('\u{f948}', "\u{8cc2}"),
+    ('\u{f949}', "\u{96f7}"),
+    ('\u{f94a}', "\u{58d8}"),
+    ('\u{f94b}', "\u{5c62}"),
+    ('\u{f94c}', "\u{6a13}"),
+    ('\u{f94d}', "\u{6dda}"),
+    ('\u{f94e}', "\u{6f0f}"),
+    ('\u{f94f}', "\u{7d2f}"),
+    ('\u{f950}', "\u{7e37}"),
+    ('\u{f951}', "\u{964b}"),
+    ('\u{f952}', "\u{52d2}"),
+    ('\u{f953}', "\u{808b}"),
+    ('\u{f954}', "\u{51dc}"),
+    ('\u{f955}', "\u{51cc}"),
+    ('\u{f956}', "\u{7a1c}"),
+    ('\u{f957}', "\u{7dbe}"),
+    ('\u{f958}', "\u{83f1}"),
+    ('\u{f959}', "\u{9675}"),
+    ('\u{f95a}', "\u{8b80}"),
+    ('\u{f95b}', "\u{62cf}"),
+    ('\u{f95c}', "\u{6a02}"),
+    ('\u{f95d}', "\u{8afe}"),
+    ('\u{f95e}', "\u{4e39}"),
+    ('\u{f95f}', "\u{5be7}"),
+    ('\u{f960}', "\u{6012}"),
+    ('\u{f961}', "\u{7387}"),
+    ('\u{f962}', "\u{7570}"),
+    ('\u{f963}', "\u{5317}"),
+    ('\u{f964}', "\u{78fb}"),
+    ('\u{f965}', "\u{4fbf}"),
+    ('\u{f966}', "\u{5fa9}"),
+    ('\u{f967}', "\u{4e0d}"),
+    ('\u{f968}', "\u{6ccc}"),
+    ('\u{f969}', "\u{6578}"),
+    ('\u{f96a}', "\u{7d22}"),
+    ('\u{f96b}', "\u{53c3}"),
+    ('\u{f96c}', "\u{585e}"),
+    ('\u{f96d}', "\u{7701}"),
+    ('\u{f96e}', "\u{8449}"),
+    ('\u{f96f}', "\u{8aaa}"),
+    ('\u{f970}', "\u{6bba}"),
+    ('\u{f971}', "\u{8fb0}"),
+    ('\u{f972}', "\u{6c88}"),
+    ('\u{f973}', "\u{62fe}"),
+    ('\u{f974}', "\u{82e5}"),
+    ('\u{f975}', "\u{63a0}"),
+    ('\u{f976}', "\u{7565}"),
+    ('\u{f977}', "\u{4eae}"),
+    ('\u{f978}', "\u{5169}"),
+    ('\u{f979}', "\u{51c9}"),
+    ('\u{f97a}', "\u{6881}"),
+    ('\u{f97b}', "\u{7ce7}"),
+    ('\u{f97c}', "\u{826f}"),
+    ('\u{f97d}', "\u{8ad2}"),
+    ('\u{f97e}', "\u{91cf}"),
+    ('\u{f97f}', "\u{52f5}"),
+    ('\u{f980}', "\u{5442}"),
+    ('\u{f981}', "\u{5973}"),
+    ('\u{f982}', "\u{5eec}"),
+    ('\u{f983}', "\u{65c5}"),
+    ('\u{f984}', "\u{6ffe}"),
+    ('\u{f985}', "\u{792a}"),
+    ('\u{f986}', "\u{95ad}"),
+    ('\u{f987}', "\u{9a6a}"),
+    ('\u{f988}', "\u{9e97}"),
+    ('\u{f989}', "\u{9ece}"),
+    ('\u{f98a}', "\u{529b}"),
+    ('\u{f98b}', "\u{66c6}"),
+    ('\u{f98c}', "\u{6b77}"),
+    ('\u{f98d}', "\u{8f62}"),
+    ('\u{f98e}', "\u{5e74}"),
+    ('\u{f98f}', "\u{6190}"),
+    ('\u{f990}', "\u{6200}"),
+    ('\u{f991}', "\u{649a}"),
+    ('\u{f992}', "\u{6f23}"),
+    ('\u{f993}', "\u{7149}"),
+    ('\u{f994}', "\u{7489}"),
+    ('\u{f995}', "\u{79ca}"),
+    ('\u{f996}', "\u{7df4}"),
+    ('\u{f997}', "\u{806f}"),
+    ('\u{f998}', "\u{8f26}"),
+    ('\u{f999}', "\u{84ee}"),
+    ('\u{f99a}', "\u{9023}"),
+    ('\u{f99b}', "\u{934a}"),
+    ('\u{f99c}', "\u{5217}"),
+    ('\u{f99d}', "\u{52a3}"),
+    ('\u{f99e}', "\u{54bd}"),
+    ('\u{f99f}', "\u{70c8}"),
+    ('\u{f9a0}', "\u{88c2}"),
+    ('\u{f9a1}', "\u{8aaa}"),
+    ('\u{f9a2}', "\u{5ec9}"),
+    ('\u{f9a3}', "\u{5ff5}"),
+    ('\u{f9a4}', "\u{637b}"),
+    ('\u{f9a5}', "\u{6bae}"),
+    ('\u{f9a6}', "\u{7c3e}"),
+    ('\u{f9a7}', "\u{7375}"),
+    ('\u{f9a8}', "\u{4ee4}"),
+    ('\u{f9a9}', "\u{56f9}"),
+    ('\u{f9aa}', "\u{5be7}"),
+    ('\u{f9ab}', "\u{5dba}"),
+    ('\u{f9ac}', "\u{601c}"),
+    ('\u{f9ad}', "\u{73b2}"),
+    ('\u{f9ae}', "\u{7469}"),
+    ('\u{f9af}', "\u{7f9a}"),
+    ('\u{f9b0}', "\u{8046}"),
+    ('\u{f9b1}', "\u{9234}"),
+    ('\u{f9b2}', "\u{96f6}"),
+    ('\u{f9b3}', "\u{9748}"),
+    ('\u{f9b4}', "\u{9818}"),
+    ('\u{f9b5}', "\u{4f8b}"),
+    ('\u{f9b6}', "\u{79ae}"),
+    ('\u{f9b7}', "\u{91b4}"),
+    ('\u{f9b8}', "\u{96b8}"),
+    ('\u{f9b9}', "\u{60e1}"),
+    ('\u{f9ba}', "\u{4e86}"),
+    ('\u{f9bb}', "\u{50da}"),
+    ('\u{f9bc}', "\u{5bee}"),
+    ('\u{f9bd}', "\u{5c3f}"),
+    ('\u{f9be}', "\u{6599}"),
+    ('\u{f9bf}', "\u{6a02}"),
+    ('\u{f9c0}', "\u{71ce}"),
+    ('\u{f9c1}', "\u{7642}"),
+    ('\u{f9c2}', "\u{84fc}"),
+    ('\u{f9c3}', "\u{907c}"),
+    ('\u{f9c4}', "\u{9f8d}"),
+    ('\u{f9c5}', "\u{6688}"),
+    ('\u{f9c6}', "\u{962e}"),
+    ('\u{f9c7}', "\u{5289}"),
+    ('\u{f9c8}', "\u{677b}"),
+    ('\u{f9c9}', "\u{67f3}"),
+    ('\u{f9ca}', "\u{6d41}"),
+    ('\u{f9cb}', "\u{6e9c}"),
+    ('\u{f9cc}', "\u{7409}"),
+    ('\u{f9cd}', "\u{7559}"),
+    ('\u{f9ce}', "\u{786b}"),
+    ('\u{f9cf}', "\u{7d10}"),
+    ('\u{f9d0}', "\u{985e}"),
+    ('\u{f9d1}', "\u{516d}"),
+    ('\u{f9d2}', "\u{622e}"),
+    ('\u{f9d3}', "\u{9678}"),
+    ('\u{f9d4}', "\u{502b}"),
+    ('\u{f9d5}', "\u{5d19}"),
+    ('\u{f9d6}', "\u{6dea}"),
+    ('\u{f9d7}', "\u{8f2a}"),
+    ('\u{f9d8}', "\u{5f8b}"),
+    ('\u{f9d9}', "\u{6144}"),
+    ('\u{f9da}', "\u{6817}"),
+    ('\u{f9db}', "\u{7387}"),
+    ('\u{f9dc}', "\u{9686}"),
+    ('\u{f9dd}', "\u{5229}"),
+    ('\u{f9de}', "\u{540f}"),
+    ('\u{f9df}', "\u{5c65}"),
+    ('\u{f9e0}', "\u{6613}"),
+    ('\u{f9e1}', "\u{674e}"),
+    ('\u{f9e2}', "\u{68a8}"),
+    ('\u{f9e3}', "\u{6ce5}"),
+    ('\u{f9e4}', "\u{7406}"),
+    ('\u{f9e5}', "\u{75e2}"),
+    ('\u{f9e6}', "\u{7f79}"),
+    ('\u{f9e7}', "\u{88cf}"),
+    ('\u{f9e8}', "\u{88e1}"),
+    ('\u{f9e9}', "\u{91cc}"),
+    ('\u{f9ea}', "\u{96e2}"),
+    ('\u{f9eb}', "\u{533f}"),
+    ('\u{f9ec}', "\u{6eba}"),
+    ('\u{f9ed}', "\u{541d}"),
+    ('\u{f9ee}', "\u{71d0}"),
+    ('\u{f9ef}', "\u{7498}"),
+    ('\u{f9f0}', "\u{85fa}"),
+    ('\u{f9f1}', "\u{96a3}"),
+    ('\u{f9f2}', "\u{9c57}"),
+    ('\u{f9f3}', "\u{9e9f}"),
+    ('\u{f9f4}', "\u{6797}"),
+    ('\u{f9f5}', "\u{6dcb}"),
+    ('\u{f9f6}', "\u{81e8}"),
+    ('\u{f9f7}', "\u{7acb}"),
+    ('\u{f9f8}', "\u{7b20}"),
+    ('\u{f9f9}', "\u{7c92}"),
+    ('\u{f9fa}', "\u{72c0}"),
+    ('\u{f9fb}', "\u{7099}"),
+    ('\u{f9fc}', "\u{8b58}"),
+    ('\u{f9fd}', "\u{4ec0}"),
+    ('\u{f9fe}', "\u{8336}"),
+    ('\u{f9ff}', "\u{523a}"),
+    ('\u{fa00}', "\u{5207}"),
+    ('\u{fa01}', "\u{5ea6}"),
+    ('\u{fa02}', "\u{62d3}"),
+    ('\u{fa03}', "\u{7cd6}"),
+    ('\u{fa04}', "\u{5b85}"),
+    ('\u{fa05}', "\u{6d1e}"),
+    ('\u{fa06}', "\u{66b4}"),
+    ('\u{fa07}', "\u{8f3b}"),
+    ('\u{fa08}', "\u{884c}"),
+    ('\u{fa09}', "\u{964d}"),
+    ('\u{fa0a}', "\u{898b}"),
+    ('\u{fa0b}', "\u{5ed3}"),
+    ('\u{fa0c}', "\u{5140}"),
+    ('\u{fa0d}', "\u{55c0}"),
+    ('\u{fa10}', "\u{585a}"),
+    ('\u{fa12}', "\u{6674}"),
+    ('\u{fa15}', "\u{51de}"),
+    ('\u{fa16}', "\u{732a}"),
+    ('\u{fa17}', "\u{76ca}"),
+    ('\u{fa18}', "\u{793c}"),
+    ('\u{fa19}', "\u{795e}"),
+    ('\u{fa1a}', "\u{7965}"),
+    ('\u{fa1b}', "\u{798f}"),
+    ('\u{fa1c}', "\u{9756}"),
+    ('\u{fa1d}', "\u{7cbe}"),
+    ('\u{fa1e}', "\u{7fbd}"),
+    ('\u{fa20}', "\u{8612}"),
+    ('\u{fa22}', "\u{8af8}"),
+    ('\u{fa25}', "\u{9038}"),
+    ('\u{fa26}', "\u{90fd}"),
+    ('\u{fa2a}', "\u{98ef}"),
+    ('\u{fa2b}', "\u{98fc}"),
+    ('\u{fa2c}', "\u{9928}"),
+    ('\u{fa2d}', "\u{9db4}"),
+    ('\u{fa2e}', "\u{90de}"),
+    ('\u{fa2f}', "\u{96b7}"),
+    ('\u{fa30}', "\u{4fae}"),
+    ('\u{fa31}', "\u{50e7}"),
+    ('\u{fa32}', "\u{514d}"),
+    ('\u{fa33}', "\u{52c9}"),
+    ('\u{fa34}', "\u{52e4}"),
+    ('\u{fa35}', "\u{5351}"),
+    ('\u{fa36}', "\u{559d}"),
+    ('\u{fa37}', "\u{5606}"),
+    ('\u{fa38}', "\u{5668}"),
+    ('\u{fa39}', "\u{5840}"),
+    ('\u{fa3a}', "\u{58a8}"),
+    ('\u{fa3b}', "\u{5c64}"),
+    ('\u{fa3c}', "\u{5c6e}"),
+    ('\u{fa3d}', "\u{6094}"),
+    ('\u{fa3e}', "\u{6168}"),
+    ('\u{fa3f}', "\u{618e}"),
+    ('\u{fa40}', "\u{61f2}"),
+    ('\u{fa41}', "\u{654f}"),
+    ('\u{fa42}', "\u{65e2}"),
+    ('\u{fa43}', "\u{6691}"),
+    ('\u{fa44}', "\u{6885}"),
+    ('\u{fa45}', "\u{6d77}"),
+    ('\u{fa46}', "\u{6e1a}"),
+    ('\u{fa47}', "\u{6f22}"),
+    ('\u{fa48}', "\u{716e}"),
+    ('\u{fa49}', "\u{722b}"),
+    ('\u{fa4a}', "\u{7422}"),
+    ('\u{fa4b}', "\u{7891}"),
+    ('\u{fa4c}', "\u{793e}"),
+    ('\u{fa4d}', "\u{7949}"),
+    ('\u{fa4e}', "\u{7948}"),
+    ('\u{fa4f}', "\u{7950}"),
+    ('\u{fa50}', "\u{7956}"),
+    ('\u{fa51}', "\u{795d}"),
+    ('\u{fa52}', "\u{798d}"),
+    ('\u{fa53}', "\u{798e}"),
+    ('\u{fa54}', "\u{7a40}"),
+    ('\u{fa55}', "\u{7a81}"),
+    ('\u{fa56}', "\u{7bc0}"),
+    ('\u{fa57}', "\u{7df4}"),
+    ('\u{fa58}', "\u{7e09}"),
+    ('\u{fa59}', "\u{7e41}"),
+    ('\u{fa5a}', "\u{7f72}"),
+    ('\u{fa5b}', "\u{8005}"),
+    ('\u{fa5c}', "\u{81ed}"),
+    ('\u{fa5d}', "\u{8279}"),
+    ('\u{fa5e}', "\u{8279}"),
+    ('\u{fa5f}', "\u{8457}"),
+    ('\u{fa60}', "\u{8910}"),
+    ('\u{fa61}', "\u{8996}"),
+    ('\u{fa62}', "\u{8b01}"),
+    ('\u{fa63}', "\u{8b39}"),
+    ('\u{fa64}', "\u{8cd3}"),
+    ('\u{fa65}', "\u{8d08}"),
+    ('\u{fa66}', "\u{8fb6}"),
+    ('\u{fa67}', "\u{9038}"),
+    ('\u{fa68}', "\u{96e3}"),
+    ('\u{fa69}', "\u{97ff}"),
+    ('\u{fa6a}', "\u{983b}"),
+    ('\u{fa6b}', "\u{6075}"),
+    ('\u{fa6c}', "\u{242ee}"),
+    ('\u{fa6d}', "\u{8218}"),
+    ('\u{fa70}', "\u{4e26}"),
+    ('\u{fa71}', "\u{51b5}"),
+    ('\u{fa72}', "\u{5168}"),
+    ('\u{fa73}', "\u{4f80}"),
+    ('\u{fa74}', "\u{5145}"),
+    ('\u{fa75}', "\u{5180}"),
+    ('\u{fa76}', "\u{52c7}"),
+    ('\u{fa77}', "\u{52fa}"),
+    ('\u{fa78}', "\u{559d}"),
+    ('\u{fa79}', "\u{5555}"),
+    ('\u{fa7a}', "\u{5599}"),
+    ('\u{fa7b}', "\u{55e2}"),
+    ('\u{fa7c}', "\u{585a}"),
+    ('\u{fa7d}', "\u{58b3}"),
+    ('\u{fa7e}', "\u{5944}"),
+    ('\u{fa7f}', "\u{5954}"),
+    ('\u{fa80}', "\u{5a62}"),
+    ('\u{fa81}', "\u{5b28}"),
+    ('\u{fa82}', "\u{5ed2}"),
+    ('\u{fa83}', "\u{5ed9}"),
+    ('\u{fa84}', "\u{5f69}"),
+    ('\u{fa85}', "\u{5fad}"),
+    ('\u{fa86}', "\u{60d8}"),
+    ('\u{fa87}', "\u{614e}"),
+    ('\u{fa88}', "\u{6108}"),
+    ('\u{fa89}', "\u{618e}"),
+    ('\u{fa8a}', "\u{6160}"),
+    ('\u{fa8b}', "\u{61f2}"),
+    ('\u{fa8c}', "\u{6234}"),
+    ('\u{fa8d}', "\u{63c4}"),
+    ('\u{fa8e}', "\u{641c}"),
+    ('\u{fa8f}', "\u{6452}"),
+    ('\u{fa90}', "\u{6556}"),
+    ('\u{fa91}', "\u{6674}"),
+    ('\u{fa92}', "\u{6717}"),
+    ('\u{fa93}', "\u{671b}"),
+    ('\u{fa94}', "\u{6756}"),
+    ('\u{fa95}', "\u{6b79}"),
+    ('\u{fa96}', "\u{6bba}"),
+    ('\u{fa97}', "\u{6d41}"),
+    ('\u{fa98}', "\u{6edb}"),
+    ('\u{fa99}', "\u{6ecb}"),
+    ('\u{fa9a}', "\u{6f22}"),
+    ('\u{fa9b}', "\u{701e}"),
+    ('\u{fa9c}', "\u{716e}"),
+    ('\u{fa9d}', "\u{77a7}"),
+    ('\u{fa9e}', "\u{7235}"),
+    ('\u{fa9f}', "\u{72af}"),
+    ('\u{faa0}', "\u{732a}"),
+    ('\u{faa1}', "\u{7471}"),
+    ('\u{faa2}', "\u{7506}"),
+    ('\u{faa3}', "\u{753b}"),
+    ('\u{faa4}', "\u{761d}"),
+    ('\u{faa5}', "\u{761f}"),
+    ('\u{faa6}', "\u{76ca}"),
+    ('\u{faa7}', "\u{76db}"),
+    ('\u{faa8}', "\u{76f4}"),
+    ('\u{faa9}', "\u{774a}"),
+    ('\u{faaa}', "\u{7740}"),
+    ('\u{faab}', "\u{78cc}"),
+    ('\u{faac}', "\u{7ab1}"),
+    ('\u{faad}', "\u{7bc0}"),
+    ('\u{faae}', "\u{7c7b}"),
+    ('\u{faaf}', "\u{7d5b}"),
+    ('\u{fab0}', "\u{7df4}"),
+    ('\u{fab1}', "\u{7f3e}"),
+    ('\u{fab2}', "\u{8005}"),
+    ('\u{fab3}', "\u{8352}"),
+    ('\u{fab4}', "\u{83ef}"),
+    ('\u{fab5}', "\u{8779}"),
+    ('\u{fab6}', "\u{8941}"),
+    ('\u{fab7}', "\u{8986}"),
+    ('\u{fab8}', "\u{8996}"),
+    ('\u{fab9}', "\u{8abf}"),
+    ('\u{faba}', "\u{8af8}"),
+    ('\u{fabb}', "\u{8acb}"),
+    ('\u{fabc}', "\u{8b01}"),
+    ('\u{fabd}', "\u{8afe}"),
+    ('\u{fabe}', "\u{8aed}"),
+    ('\u{fabf}', "\u{8b39}"),
+    ('\u{fac0}', "\u{8b8a}"),
+    ('\u{fac1}', "\u{8d08}"),
+    ('\u{fac2}', "\u{8f38}"),
+    ('\u{fac3}', "\u{9072}"),
+    ('\u{fac4}', "\u{9199}"),
+    ('\u{fac5}', "\u{9276}"),
+    ('\u{fac6}', "\u{967c}"),
+    ('\u{fac7}', "\u{96e3}"),
+    ('\u{fac8}', "\u{9756}"),
+    ('\u{fac9}', "\u{97db}"),
+    ('\u{faca}', "\u{97ff}"),
+    ('\u{facb}', "\u{980b}"),
+    ('\u{facc}', "\u{983b}"),
+    ('\u{facd}', "\u{9b12}"),
+    ('\u{face}', "\u{9f9c}"),
+    ('\u{facf}', "\u{2284a}"),
+    ('\u{fad0}', "\u{22844}"),
+    ('\u{fad1}', "\u{233d5}"),
+    ('\u{fad2}', "\u{3b9d}"),
+    ('\u{fad3}', "\u{4018}"),
+    ('\u{fad4}', "\u{4039}"),
+    ('\u{fad5}', "\u{25249}"),
+    ('\u{fad6}', "\u{25cd0}"),
+    ('\u{fad7}', "\u{27ed3}"),
+    ('\u{fad8}', "\u{9f43}"),
+    ('\u{fad9}', "\u{9f8e}"),
+    ('\u{fb00}', "ff"),
+    ('\u{fb01}', "fi"),
+    ('\u{fb02}', "fl"),
+    ('\u{fb03}', "ffi"),
+    ('\u{fb04}', "ffl"),
+    ('\u{fb05}', "st"),
+    ('\u{fb06}', "st"),
+    ('\u{fb13}', "\u{0574}\u{0576}"),
+    ('\u{fb14}', "\u{0574}\u{0565}"),
+    ('\u{fb15}', "\u{0574}\u{056b}"),
+    ('\u{fb16}', "\u{057e}\u{0576}"),
+    ('\u{fb17}', "\u{0574}\u{056d}"),
+    ('\u{fb1d}', "\u{05d9}"),
+    ('\u{fb1f}', "\u{05f2}"),
+    ('\u{fb20}', "\u{05e2}"),
+    ('\u{fb21}', "\u{05d0}"),
+    ('\u{fb22}', "\u{05d3}"),
+    ('\u{fb23}', "\u{05d4}"),
+    ('\u{fb24}', "\u{05db}"),
+    ('\u{fb25}', "\u{05dc}"),
+    ('\u{fb26}', "\u{05dd}"),
+    ('\u{fb27}', "\u{05e8}"),
+    ('\u{fb28}', "\u{05ea}"),
+    ('\u{fb29}', "\u{002b}"),
+    ('\u{fb2a}', "\u{05e9}"),
+    ('\u{fb2b}', "\u{05e9}"),
+    ('\u{fb2c}', "\u{05e9}"),
+    ('\u{fb2d}', "\u{05e9}"),
+    ('\u{fb2e}', "\u{05d0}"),
+    ('\u{fb2f}', "\u{05d0}"),
+    ('\u{fb30}', "\u{05d0}"),
+    ('\u{fb31}', "\u{05d1}"),
+    ('\u{fb32}', "\u{05d2}"),
+    ('\u{fb33}', "\u{05d3}"),
+    ('\u{fb34}', "\u{05d4}"),
+    ('\u{fb35}', "\u{05d5}"),
+    ('\u{fb36}', "\u{05d6}"),
+    ('\u{fb38}', "\u{05d8}"),
+    ('\u{fb39}', "\u{05d9}"),
+    ('\u{fb3a}', "\u{05da}"),
+    ('\u{fb3b}', "\u{05db}"),
+    ('\u{fb3c}', "\u{05dc}"),
+    ('\u{fb3e}', "\u{05de}"),
+    ('\u{fb40}', "\u{05e0}"),
+    ('\u{fb41}', "\u{05e1}"),
+    ('\u{fb43}', "\u{05e3}"),
+    ('\u{fb44}', "\u{05e4}"),
+    ('\u{fb46}', "\u{05e6}"),
+    ('\u{fb47}', "\u{05e7}"),
+    ('\u{fb48}', "\u{05e8}"),
+    ('\u{fb49}', "\u{05e9}"),
+    ('\u{fb4a}', "\u{05ea}"),
+    ('\u{fb4b}', "\u{05d5}"),
+    ('\u{fb4c}', "\u{05d1}"),
+    ('\u{fb4d}', "\u{05db}"),
+    ('\u{fb4e}', "\u{05e4}"),
+    ('\u{fb4f}', "\u{05d0}\u{05dc}"),
+    ('\u{fb50}', "\u{0671}"),
+    ('\u{fb51}', "\u{0671}"),
+    ('\u{fb52}', "\u{067b}"),
+    ('\u{fb53}', "\u{067b}"),
+    ('\u{fb54}', "\u{067b}"),
+    ('\u{fb55}', "\u{067b}"),
+    ('\u{fb56}', "\u{067e}"),
+    ('\u{fb57}', "\u{067e}"),
+    ('\u{fb58}', "\u{067e}"),
+    ('\u{fb59}', "\u{067e}"),
+    ('\u{fb5a}', "\u{0680}"),
+    ('\u{fb5b}', "\u{0680}"),
+    ('\u{fb5c}', "\u{0680}"),
+    ('\u{fb5d}', "\u{0680}"),
+    ('\u{fb5e}', "\u{067a}"),
+    ('\u{fb5f}', "\u{067a}"),
+    ('\u{fb60}', "\u{067a}"),
+    ('\u{fb61}', "\u{067a}"),
+    ('\u{fb62}', "\u{067f}"),
+    ('\u{fb63}', "\u{067f}"),
+    ('\u{fb64}', "\u{067f}"),
+    ('\u{fb65}', "\u{067f}"),
+    ('\u{fb66}', "\u{0679}"),
+    ('\u{fb67}', "\u{0679}"),
+    ('\u{fb68}', "\u{0679}"),
+    ('\u{fb69}', "\u{0679}"),
+    ('\u{fb6a}', "\u{06a4}"),
+    ('\u{fb6b}', "\u{06a4}"),
+    ('\u{fb6c}', "\u{06a4}"),
+    ('\u{fb6d}', "\u{06a4}"),
+    ('\u{fb6e}', "\u{06a6}"),
+    ('\u{fb6f}', "\u{06a6}"),
+    ('\u{fb70}', "\u{06a6}"),
+    ('\u{fb71}', "\u{06a6}"),
+    ('\u{fb72}', "\u{0684}"),
+    ('\u{fb73}', "\u{0684}"),
+    ('\u{fb74}', "\u{0684}"),
+    ('\u{fb75}', "\u{0684}"),
+    ('\u{fb76}', "\u{0683}"),
+    ('\u{fb77}', "\u{0683}"),
+    ('\u{fb78}', "\u{0683}"),
+    ('\u{fb79}', "\u{0683}"),
+    ('\u{fb7a}', "\u{0686}"),
+    ('\u{fb7b}', "\u{0686}"),
+    ('\u{fb7c}', "\u{0686}"),
+    ('\u{fb7d}', "\u{0686}"),
+    ('\u{fb7e}', "\u{0687}"),
+    ('\u{fb7f}', "\u{0687}"),
+    ('\u{fb80}', "\u{0687}"),
+    ('\u{fb81}', "\u{0687}"),
+    ('\u{fb82}', "\u{068d}"),
+    ('\u{fb83}', "\u{068d}"),
+    ('\u{fb84}', "\u{068c}"),
+    ('\u{fb85}', "\u{068c}"),
+    ('\u{fb86}', "\u{068e}"),
+    ('\u{fb87}', "\u{068e}"),
+    ('\u{fb88}', "\u{0688}"),
+    ('\u{fb89}', "\u{0688}"),
+    ('\u{fb8a}', "\u{0698}"),
+    ('\u{fb8b}', "\u{0698}"),
+    ('\u{fb8c}', "\u{0691}"),
+    ('\u{fb8d}', "\u{0691}"),
+    ('\u{fb8e}', "\u{06a9}"),
+    ('\u{fb8f}', "\u{06a9}"),
+    ('\u{fb90}', "\u{06a9}"),
+    ('\u{fb91}', "\u{06a9}"),
+    ('\u{fb92}', "\u{06af}"),
+    ('\u{fb93}', "\u{06af}"),
+    ('\u{fb94}', "\u{06af}"),
+    ('\u{fb95}', "\u{06af}"),
+    ('\u{fb96}', "\u{06b3}"),
+    ('\u{fb97}', "\u{06b3}"),
+    ('\u{fb98}', "\u{06b3}"),
+    ('\u{fb99}', "\u{06b3}"),
+    ('\u{fb9a}', "\u{06b1}"),
+    ('\u{fb9b}', "\u{06b1}"),
+    ('\u{fb9c}', "\u{06b1}"),
+    ('\u{fb9d}', "\u{06b1}"),
+    ('\u{fb9e}', "\u{06ba}"),
+    ('\u{fb9f}', "\u{06ba}"),
+    ('\u{fba0}', "\u{06bb}"),
+    ('\u{fba1}', "\u{06bb}"),
+    ('\u{fba2}', "\u{06bb}"),
+    ('\u{fba3}', "\u{06bb}"),
+    ('\u{fba4}', "\u{06d5}"),
+    ('\u{fba5}', "\u{06d5}"),
+    ('\u{fba6}', "\u{06c1}"),
+    ('\u{fba7}', "\u{06c1}"),
+    ('\u{fba8}', "\u{06c1}"),
+    ('\u{fba9}', "\u{06c1}"),
+    ('\u{fbaa}', "\u{06be}"),
+    ('\u{fbab}', "\u{06be}"),
+    ('\u{fbac}', "\u{06be}"),
+    ('\u{fbad}', "\u{06be}"),
+    ('\u{fbae}', "\u{06d2}"),
+    ('\u{fbaf}', "\u{06d2}"),
+    ('\u{fbb0}', "\u{06d2}"),
+    ('\u{fbb1}', "\u{06d2}"),
+    ('\u{fbd3}', "\u{06ad}"),
+    ('\u{fbd4}', "\u{06ad}"),
+    ('\u{fbd5}', "\u{06ad}"),
+    ('\u{fbd6}', "\u{06ad}"),
+    ('\u{fbd7}', "\u{06c7}"),
+    ('\u{fbd8}', "\u{06c7}"),
+    ('\u{fbd9}', "\u{06c6}"),
+    ('\u{fbda}', "\u{06c6}"),
+    ('\u{fbdb}', "\u{06c8}"),
+    ('\u{fbdc}', "\u{06c8}"),
+    ('\u{fbdd}', "\u{06c7}\u{0674}"),
+    ('\u{fbde}', "\u{06cb}"),
+    ('\u{fbdf}', "\u{06cb}"),
+    ('\u{fbe0}', "\u{06c5}"),
+    ('\u{fbe1}', "\u{06c5}"),
+    ('\u{fbe2}', "\u{06c9}"),
+    ('\u{fbe3}', "\u{06c9}"),
+    ('\u{fbe4}', "\u{06d0}"),
+    ('\u{fbe5}', "\u{06d0}"),
+    ('\u{fbe6}', "\u{06d0}"),
+    ('\u{fbe7}', "\u{06d0}"),
+    ('\u{fbe8}', "\u{0649}"),
+    ('\u{fbe9}', "\u{0649}"),
+    ('\u{fbea}', "\u{064a}\u{0627}"),
+    ('\u{fbeb}', "\u{064a}\u{0627}"),
+    ('\u{fbec}', "\u{064a}\u{06d5}"),
+    ('\u{fbed}', "\u{064a}\u{06d5}"),
+    ('\u{fbee}', "\u{064a}\u{0648}"),
+    ('\u{fbef}', "\u{064a}\u{0648}"),
+    ('\u{fbf0}', "\u{064a}\u{06c7}"),
+    ('\u{fbf1}', "\u{064a}\u{06c7}"),
+    ('\u{fbf2}', "\u{064a}\u{06c6}"),
+    ('\u{fbf3}', "\u{064a}\u{06c6}"),
+    ('\u{fbf4}', "\u{064a}\u{06c8}"),
+    ('\u{fbf5}', "\u{064a}\u{06c8}"),
+    ('\u{fbf6}', "\u{064a}\u{06d0}"),
+    ('\u{fbf7}', "\u{064a}\u{06d0}"),
+    ('\u{fbf8}', "\u{064a}\u{06d0}"),
+    ('\u{fbf9}', "\u{064a}\u{0649}"),
+    ('\u{fbfa}', "\u{064a}\u{0649}"),
+    ('\u{fbfb}', "\u{064a}\u{0649}"),
+    ('\u{fbfc}', "\u{06cc}"),
+    ('\u{fbfd}', "\u{06cc}"),
+    ('\u{fbfe}', "\u{06cc}"),
+    ('\u{fbff}', "\u{06cc}"),
+    ('\u{fc00}', "\u{064a}\u{062c}"),
+    ('\u{fc01}', "\u{064a}\u{062d}"),
+    ('\u{fc02}', "\u{064a}\u{0645}"),
+    ('\u{fc03}', "\u{064a}\u{0649}"),
+    ('\u{fc04}', "\u{064a}\u{064a}"),
+    ('\u{fc05}', "\u{0628}\u{062c}"),
+    ('\u{fc06}', "\u{0628}\u{062d}"),
+    ('\u{fc07}', "\u{0628}\u{062e}"),
+    ('\u{fc08}', "\u{0628}\u{0645}"),
+    ('\u{fc09}', "\u{0628}\u{0649}"),
+    ('\u{fc0a}', "\u{0628}\u{064a}"),
+    ('\u{fc0b}', "\u{062a}\u{062c}"),
+    ('\u{fc0c}', "\u{062a}\u{062d}"),
+    ('\u{fc0d}', "\u{062a}\u{062e}"),
+    ('\u{fc0e}', "\u{062a}\u{0645}"),
+    ('\u{fc0f}', "\u{062a}\u{0649}"),
+    ('\u{fc10}', "\u{062a}\u{064a}"),
+    ('\u{fc11}', "\u{062b}\u{062c}"),
+    ('\u{fc12}', "\u{062b}\u{0645}"),
+    ('\u{fc13}', "\u{062b}\u{0649}"),
+    ('\u{fc14}', "\u{062b}\u{064a}"),
+    ('\u{fc15}', "\u{062c}\u{062d}"),
+    ('\u{fc16}', "\u{062c}\u{0645}"),
+    ('\u{fc17}', "\u{062d}\u{062c}"),
+    ('\u{fc18}', "\u{062d}\u{0645}"),
+    ('\u{fc19}', "\u{062e}\u{062c}"),
+    ('\u{fc1a}', "\u{062e}\u{062d}"),
+    ('\u{fc1b}', "\u{062e}\u{0645}"),
+    ('\u{fc1c}', "\u{0633}\u{062c}"),
+    ('\u{fc1d}', "\u{0633}\u{062d}"),
+    ('\u{fc1e}', "\u{0633}\u{062e}"),
+    ('\u{fc1f}', "\u{0633}\u{0645}"),
+    ('\u{fc20}', "\u{0635}\u{062d}"),
+    ('\u{fc21}', "\u{0635}\u{0645}"),
+    ('\u{fc22}', "\u{0636}\u{062c}"),
+    ('\u{fc23}', "\u{0636}\u{062d}"),
+    ('\u{fc24}', "\u{0636}\u{062e}"),
+    ('\u{fc25}', "\u{0636}\u{0645}"),
+    ('\u{fc26}', "\u{0637}\u{062d}"),
+    ('\u{fc27}', "\u{0637}\u{0645}"),
+    ('\u{fc28}', "\u{0638}\u{0645}"),
+    ('\u{fc29}', "\u{0639}\u{062c}"),
+    ('\u{fc2a}', "\u{0639}\u{0645}"),
+    ('\u{fc2b}', "\u{063a}\u{062c}"),
+    ('\u{fc2c}', "\u{063a}\u{0645}"),
+    ('\u{fc2d}', "\u{0641}\u{062c}"),
+    ('\u{fc2e}', "\u{0641}\u{062d}"),
+    ('\u{fc2f}', "\u{0641}\u{062e}"),
+    ('\u{fc30}', "\u{0641}\u{0645}"),
+    ('\u{fc31}', "\u{0641}\u{0649}"),
+    ('\u{fc32}', "\u{0641}\u{064a}"),
+    ('\u{fc33}', "\u{0642}\u{062d}"),
+    ('\u{fc34}', "\u{0642}\u{0645}"),
+    ('\u{fc35}', "\u{0642}\u{0649}"),
+    ('\u{fc36}', "\u{0642}\u{064a}"),
+    ('\u{fc37}', "\u{0643}\u{0627}"),
+    ('\u{fc38}', "\u{0643}\u{062c}"),
+    ('\u{fc39}', "\u{0643}\u{062d}"),
+    ('\u{fc3a}', "\u{0643}\u{062e}"),
+    ('\u{fc3b}', "\u{0643}\u{0644}"),
+    ('\u{fc3c}', "\u{0643}\u{0645}"),
+    ('\u{fc3d}', "\u{0643}\u{0649}"),
+    ('\u{fc3e}', "\u{0643}\u{064a}"),
+    ('\u{fc3f}', "\u{0644}\u{062c}"),
+    ('\u{fc40}', "\u{0644}\u{062d}"),
+    ('\u{fc41}', "\u{0644}\u{062e}"),
+    ('\u{fc42}', "\u{0644}\u{0645}"),
+    ('\u{fc43}', "\u{0644}\u{0649}"),
+    ('\u{fc44}', "\u{0644}\u{064a}"),
+    ('\u{fc45}', "\u{0645}\u{062c}"),
+    ('\u{fc46}', "\u{0645}\u{062d}"),
+    ('\u{fc47}', "\u{0645}\u{062e}"),
+    ('\u{fc48}', "\u{0645}\u{0645}"),
+    ('\u{fc49}', "\u{0645}\u{0649}"),
+    ('\u{fc4a}', "\u{0645}\u{064a}"),
+    ('\u{fc4b}', "\u{0646}\u{062c}"),
+    ('\u{fc4c}', "\u{0646}\u{062d}"),
+    ('\u{fc4d}', "\u{0646}\u{062e}"),
+    ('\u{fc4e}', "\u{0646}\u{0645}"),
+    ('\u{fc4f}', "\u{0646}\u{0649}"),
+    ('\u{fc50}', "\u{0646}\u{064a}"),
+    ('\u{fc51}', "\u{0647}\u{062c}"),
+    ('\u{fc52}', "\u{0647}\u{0645}"),
+    ('\u{fc53}', "\u{0647}\u{0649}"),
+    ('\u{fc54}', "\u{0647}\u{064a}"),
+    ('\u{fc55}', "\u{064a}\u{062c}"),
+    ('\u{fc56}', "\u{064a}\u{062d}"),
+    ('\u{fc57}', "\u{064a}\u{062e}"),
+    ('\u{fc58}', "\u{064a}\u{0645}"),
+    ('\u{fc59}', "\u{064a}\u{0649}"),
+    ('\u{fc5a}', "\u{064a}\u{064a}"),
+    ('\u{fc5b}', "\u{0630}"),
+    ('\u{fc5c}', "\u{0631}"),
+    ('\u{fc5d}', "\u{0649}"),
+    ('\u{fc5e}', "\u{0020}"),
+    ('\u{fc5f}', "\u{0020}"),
+    ('\u{fc60}', "\u{0020}"),
+    ('\u{fc61}', "\u{0020}"),
+    ('\u{fc62}', "\u{0020}"),
+    ('\u{fc63}', "\u{0020}"),
+    ('\u{fc64}', "\u{064a}\u{0631}"),
+    ('\u{fc65}', "\u{064a}\u{0632}"),
+    ('\u{fc66}', "\u{064a}\u{0645}"),
+    ('\u{fc67}', "\u{064a}\u{0646}"),
+    ('\u{fc68}', "\u{064a}\u{0649}"),
+    ('\u{fc69}', "\u{064a}\u{064a}"),
+    ('\u{fc6a}', "\u{0628}\u{0631}"),
+    ('\u{fc6b}', "\u{0628}\u{0632}"),
+    ('\u{fc6c}', "\u{0628}\u{0645}"),
+    ('\u{fc6d}', "\u{0628}\u{0646}"),
+    ('\u{fc6e}', "\u{0628}\u{0649}"),
+    ('\u{fc6f}', "\u{0628}\u{064a}"),
+    ('\u{fc70}', "\u{062a}\u{0631}"),
+    ('\u{fc71}', "\u{062a}\u{0632}"),
+    ('\u{fc72}', "\u{062a}\u{0645}"),
+    ('\u{fc73}', "\u{062a}\u{0646}"),
+    ('\u{fc74}', "\u{062a}\u{0649}"),
+    ('\u{fc75}', "\u{062a}\u{064a}"),
+    ('\u{fc76}', "\u{062b}\u{0631}"),
+    ('\u{fc77}', "\u{062b}\u{0632}"),
+    ('\u{fc78}', "\u{062b}\u{0645}"),
+    ('\u{fc79}', "\u{062b}\u{0646}"),
+    ('\u{fc7a}', "\u{062b}\u{0649}"),
+    ('\u{fc7b}', "\u{062b}\u{064a}"),
+    ('\u{fc7c}', "\u{0641}\u{0649}"),
+    ('\u{fc7d}', "\u{0641}\u{064a}"),
+    ('\u{fc7e}', "\u{0642}\u{0649}"),
+    ('\u{fc7f}', "\u{0642}\u{064a}"),
+    ('\u{fc80}', "\u{0643}\u{0627}"),
+    ('\u{fc81}', "\u{0643}\u{0644}"),
+    ('\u{fc82}', "\u{0643}\u{0645}"),
+    ('\u{fc83}', "\u{0643}\u{0649}"),
+    ('\u{fc84}', "\u{0643}\u{064a}"),
+    ('\u{fc85}', "\u{0644}\u{0645}"),
+    ('\u{fc86}', "\u{0644}\u{0649}"),
+    ('\u{fc87}', "\u{0644}\u{064a}"),
+    ('\u{fc88}', "\u{0645}\u{0627}"),
+    ('\u{fc89}', "\u{0645}\u{0645}"),
+    ('\u{fc8a}', "\u{0646}\u{0631}"),
+    ('\u{fc8b}', "\u{0646}\u{0632}"),
+    ('\u{fc8c}', "\u{0646}\u{0645}"),
+    ('\u{fc8d}', "\u{0646}\u{0646}"),
+    ('\u{fc8e}', "\u{0646}\u{0649}"),
+    ('\u{fc8f}', "\u{0646}\u{064a}"),
+    ('\u{fc90}', "\u{0649}"),
+    ('\u{fc91}', "\u{064a}\u{0631}"),
+    ('\u{fc92}', "\u{064a}\u{0632}"),
+    ('\u{fc93}', "\u{064a}\u{0645}"),
+    ('\u{fc94}', "\u{064a}\u{0646}"),
+    ('\u{fc95}', "\u{064a}\u{0649}"),
+    ('\u{fc96}', "\u{064a}\u{064a}"),
+    ('\u{fc97}', "\u{064a}\u{062c}"),
+    ('\u{fc98}', "\u{064a}\u{062d}"),
+    ('\u{fc99}', "\u{064a}\u{062e}"),
+    ('\u{fc9a}', "\u{064a}\u{0645}"),
+    ('\u{fc9b}', "\u{064a}\u{0647}"),
+    ('\u{fc9c}', "\u{0628}\u{062c}"),
+    ('\u{fc9d}', "\u{0628}\u{062d}"),
+    ('\u{fc9e}', "\u{0628}\u{062e}"),
+    ('\u{fc9f}', "\u{0628}\u{0645}"),
+    ('\u{fca0}', "\u{0628}\u{0647}"),
+    ('\u{fca1}', "\u{062a}\u{062c}"),
+    ('\u{fca2}', "\u{062a}\u{062d}"),
+    ('\u{fca3}', "\u{062a}\u{062e}"),
+    ('\u{fca4}', "\u{062a}\u{0645}"),
+    ('\u{fca5}', "\u{062a}\u{0647}"),
+    ('\u{fca6}', "\u{062b}\u{0645}"),
+    ('\u{fca7}', "\u{062c}\u{062d}"),
+    ('\u{fca8}', "\u{062c}\u{0645}"),
+    ('\u{fca9}', "\u{062d}\u{062c}"),
+    ('\u{fcaa}', "\u{062d}\u{0645}"),
+    ('\u{fcab}', "\u{062e}\u{062c}"),
+    ('\u{fcac}', "\u{062e}\u{0645}"),
+    ('\u{fcad}', "\u{0633}\u{062c}"),
+    ('\u{fcae}', "\u{0633}\u{062d}"),
+    ('\u{fcaf}', "\u{0633}\u{062e}"),
+    ('\u{fcb0}', "\u{0633}\u{0645}"),
+    ('\u{fcb1}', "\u{0635}\u{062d}"),
+    ('\u{fcb2}', "\u{0635}\u{062e}"),
+    ('\u{fcb3}', "\u{0635}\u{0645}"),
+    ('\u{fcb4}', "\u{0636}\u{062c}"),
+    ('\u{fcb5}', "\u{0636}\u{062d}"),
+    ('\u{fcb6}', "\u{0636}\u{062e}"),
+    ('\u{fcb7}', "\u{0636}\u{0645}"),
+    ('\u{fcb8}', "\u{0637}\u{062d}"),
+    ('\u{fcb9}', "\u{0638}\u{0645}"),
+    ('\u{fcba}', "\u{0639}\u{062c}"),
+    ('\u{fcbb}', "\u{0639}\u{0645}"),
+    ('\u{fcbc}', "\u{063a}\u{062c}"),
+    ('\u{fcbd}', "\u{063a}\u{0645}"),
+    ('\u{fcbe}', "\u{0641}\u{062c}"),
+    ('\u{fcbf}', "\u{0641}\u{062d}"),
+    ('\u{fcc0}', "\u{0641}\u{062e}"),
+    ('\u{fcc1}', "\u{0641}\u{0645}"),
+    ('\u{fcc2}', "\u{0642}\u{062d}"),
+    ('\u{fcc3}', "\u{0642}\u{0645}"),
+    ('\u{fcc4}', "\u{0643}\u{062c}"),
+    ('\u{fcc5}', "\u{0643}\u{062d}"),
+    ('\u{fcc6}', "\u{0643}\u{062e}"),
+    ('\u{fcc7}', "\u{0643}\u{0644}"),
+    ('\u{fcc8}', "\u{0643}\u{0645}"),
+    ('\u{fcc9}', "\u{0644}\u{062c}"),
+    ('\u{fcca}', "\u{0644}\u{062d}"),
+    ('\u{fccb}', "\u{0644}\u{062e}"),
+    ('\u{fccc}', "\u{0644}\u{0645}"),
+    ('\u{fccd}', "\u{0644}\u{0647}"),
+    ('\u{fcce}', "\u{0645}\u{062c}"),
+    ('\u{fccf}', "\u{0645}\u{062d}"),
+    ('\u{fcd0}', "\u{0645}\u{062e}"),
+    ('\u{fcd1}', "\u{0645}\u{0645}"),
+    ('\u{fcd2}', "\u{0646}\u{062c}"),
+    ('\u{fcd3}', "\u{0646}\u{062d}"),
+    ('\u{fcd4}', "\u{0646}\u{062e}"),
+    ('\u{fcd5}', "\u{0646}\u{0645}"),
+    ('\u{fcd6}', "\u{0646}\u{0647}"),
+    ('\u{fcd7}', "\u{0647}\u{062c}"),
+    ('\u{fcd8}', "\u{0647}\u{0645}"),
+    ('\u{fcd9}', "\u{0647}"),
+    ('\u{fcda}', "\u{064a}\u{062c}"),
+    ('\u{fcdb}', "\u{064a}\u{062d}"),
+    ('\u{fcdc}', "\u{064a}\u{062e}"),
+    ('\u{fcdd}', "\u{064a}\u{0645}"),
+    ('\u{fcde}', "\u{064a}\u{0647}"),
+    ('\u{fcdf}', "\u{064a}\u{0645}"),
+    ('\u{fce0}', "\u{064a}\u{0647}"),
+    ('\u{fce1}', "\u{0628}\u{0645}"),
+    ('\u{fce2}', "\u{0628}\u{0647}"),
+    ('\u{fce3}', "\u{062a}\u{0645}"),
+    ('\u{fce4}', "\u{062a}\u{0647}"),
+    ('\u{fce5}', "\u{062b}\u{0645}"),
+    ('\u{fce6}', "\u{062b}\u{0647}"),
+    ('\u{fce7}', "\u{0633}\u{0645}"),
+    ('\u{fce8}', "\u{0633}\u{0647}"),
+    ('\u{fce9}', "\u{0634}\u{0645}"),
+    ('\u{fcea}', "\u{0634}\u{0647}"),
+    ('\u{fceb}', "\u{0643}\u{0644}"),
+    ('\u{fcec}', "\u{0643}\u{0645}"),
+    ('\u{fced}', "\u{0644}\u{0645}"),
+    ('\u{fcee}', "\u{0646}\u{0645}"),
+    ('\u{fcef}', "\u{0646}\u{0647}"),
+    ('\u{fcf0}', "\u{064a}\u{0645}"),
+    ('\u{fcf1}', "\u{064a}\u{0647}"),
+    ('\u{fcf2}', "\u{0640}"),
+    ('\u{fcf3}', "\u{0640}"),
+    ('\u{fcf4}', "\u{0640}"),
+    ('\u{fcf5}', "\u{0637}\u{0649}"),
+    ('\u{fcf6}', "\u{0637}\u{064a}"),
+    ('\u{fcf7}', "\u{0639}\u{0649}"),
+    ('\u{fcf8}', "\u{0639}\u{064a}"),
+    ('\u{fcf9}', "\u{063a}\u{0649}"),
+    ('\u{fcfa}', "\u{063a}\u{064a}"),
+    ('\u{fcfb}', "\u{0633}\u{0649}"),
+    ('\u{fcfc}', "\u{0633}\u{064a}"),
+    ('\u{fcfd}', "\u{0634}\u{0649}"),
+    ('\u{fcfe}', "\u{0634}\u{064a}"),
+    ('\u{fcff}', "\u{062d}\u{0649}"),
+    ('\u{fd00}', "\u{062d}\u{064a}"),
+    ('\u{fd01}', "\u{062c}\u{0649}"),
+    ('\u{fd02}', "\u{062c}\u{064a}"),
+    ('\u{fd03}', "\u{062e}\u{0649}"),
+    ('\u{fd04}', "\u{062e}\u{064a}"),
+    ('\u{fd05}', "\u{0635}\u{0649}"),
+    ('\u{fd06}', "\u{0635}\u{064a}"),
+    ('\u{fd07}', "\u{0636}\u{0649}"),
+    ('\u{fd08}', "\u{0636}\u{064a}"),
+    ('\u{fd09}', "\u{0634}\u{062c}"),
+    ('\u{fd0a}', "\u{0634}\u{062d}"),
+    ('\u{fd0b}', "\u{0634}\u{062e}"),
+    ('\u{fd0c}', "\u{0634}\u{0645}"),
+    ('\u{fd0d}', "\u{0634}\u{0631}"),
+    ('\u{fd0e}', "\u{0633}\u{0631}"),
+    ('\u{fd0f}', "\u{0635}\u{0631}"),
+    ('\u{fd10}', "\u{0636}\u{0631}"),
+    ('\u{fd11}', "\u{0637}\u{0649}"),
+    ('\u{fd12}', "\u{0637}\u{064a}"),
+    ('\u{fd13}', "\u{0639}\u{0649}"),
+    ('\u{fd14}', "\u{0639}\u{064a}"),
+    ('\u{fd15}', "\u{063a}\u{0649}"),
+    ('\u{fd16}', "\u{063a}\u{064a}"),
+    ('\u{fd17}', "\u{0633}\u{0649}"),
+    ('\u{fd18}', "\u{0633}\u{064a}"),
+    ('\u{fd19}', "\u{0634}\u{0649}"),
+    ('\u{fd1a}', "\u{0634}\u{064a}"),
+    ('\u{fd1b}', "\u{062d}\u{0649}"),
+    ('\u{fd1c}', "\u{062d}\u{064a}"),
+    ('\u{fd1d}', "\u{062c}\u{0649}"),
+    ('\u{fd1e}', "\u{062c}\u{064a}"),
+    ('\u{fd1f}', "\u{062e}\u{0649}"),
+    ('\u{fd20}', "\u{062e}\u{064a}"),
+    ('\u{fd21}', "\u{0635}\u{0649}"),
+    ('\u{fd22}', "\u{0635}\u{064a}"),
+    ('\u{fd23}', "\u{0636}\u{0649}"),
+    ('\u{fd24}', "\u{0636}\u{064a}"),
+    ('\u{fd25}', "\u{0634}\u{062c}"),
+    ('\u{fd26}', "\u{0634}\u{062d}"),
+    ('\u{fd27}', "\u{0634}\u{062e}"),
+    ('\u{fd28}', "\u{0634}\u{0645}"),
+    ('\u{fd29}', "\u{0634}\u{0631}"),
+    ('\u{fd2a}', "\u{0633}\u{0631}"),
+    ('\u{fd2b}', "\u{0635}\u{0631}"),
+    ('\u{fd2c}', "\u{0636}\u{0631}"),
+    ('\u{fd2d}', "\u{0634}\u{062c}"),
+    ('\u{fd2e}', "\u{0634}\u{062d}"),
+    ('\u{fd2f}', "\u{0634}\u{062e}"),
+    ('\u{fd30}', "\u{0634}\u{0645}"),
+    ('\u{fd31}', "\u{0633}\u{0647}"),
+    ('\u{fd32}', "\u{0634}\u{0647}"),
+    ('\u{fd33}', "\u{0637}\u{0645}"),
+    ('\u{fd34}', "\u{0633}\u{062c}"),
+    ('\u{fd35}', "\u{0633}\u{062d}"),
+    ('\u{fd36}', "\u{0633}\u{062e}"),
+    ('\u{fd37}', "\u{0634}\u{062c}"),
+    ('\u{fd38}', "\u{0634}\u{062d}"),
+    ('\u{fd39}', "\u{0634}\u{062e}"),
+    ('\u{fd3a}', "\u{0637}\u{0645}"),
+    ('\u{fd3b}', "\u{0638}\u{0645}"),
+    ('\u{fd3c}', "\u{0627}"),
+    ('\u{fd3d}', "\u{0627}"),
+    ('\u{fd50}', "\u{062a}\u{062c}\u{0645}"),
+    ('\u{fd51}', "\u{062a}\u{062d}\u{062c}"),
+    ('\u{fd52}', "\u{062a}\u{062d}\u{062c}"),
+    ('\u{fd53}', "\u{062a}\u{062d}\u{0645}"),
+    ('\u{fd54}', "\u{062a}\u{062e}\u{0645}"),
+    ('\u{fd55}', "\u{062a}\u{0645}\u{062c}"),
+    ('\u{fd56}', "\u{062a}\u{0645}\u{062d}"),
+    ('\u{fd57}', "\u{062a}\u{0645}\u{062e}"),
+    ('\u{fd58}', "\u{062c}\u{0645}\u{062d}"),
+    ('\u{fd59}', "\u{062c}\u{0645}\u{062d}"),
+    ('\u{fd5a}', "\u{062d}\u{0645}\u{064a}"),
+    ('\u{fd5b}', "\u{062d}\u{0645}\u{0649}"),
+    ('\u{fd5c}', "\u{0633}\u{062d}\u{062c}"),
+    ('\u{fd5d}', "\u{0633}\u{062c}\u{062d}"),
+    ('\u{fd5e}', "\u{0633}\u{062c}\u{0649}"),
+    ('\u{fd5f}', "\u{0633}\u{0645}\u{062d}"),
+    ('\u{fd60}', "\u{0633}\u{0645}\u{062d}"),
+    ('\u{fd61}', "\u{0633}\u{0645}\u{062c}"),
+    ('\u{fd62}', "\u{0633}\u{0645}\u{0645}"),
+    ('\u{fd63}', "\u{0633}\u{0645}\u{0645}"),
+    ('\u{fd64}', "\u{0635}\u{062d}\u{062d}"),
+    ('\u{fd65}', "\u{0635}\u{062d}\u{062d}"),
+    ('\u{fd66}', "\u{0635}\u{0645}\u{0645}"),
+    ('\u{fd67}', "\u{0634}\u{062d}\u{0645}"),
+    ('\u{fd68}', "\u{0634}\u{062d}\u{0645}"),
+    ('\u{fd69}', "\u{0634}\u{062c}\u{064a}"),
+    ('\u{fd6a}', "\u{0634}\u{0645}\u{062e}"),
+    ('\u{fd6b}', "\u{0634}\u{0645}\u{062e}"),
+    ('\u{fd6c}', "\u{0634}\u{0645}\u{0645}"),
+    ('\u{fd6d}', "\u{0634}\u{0645}\u{0645}"),
+    ('\u{fd6e}', "\u{0636}\u{062d}\u{0649}"),
+    ('\u{fd6f}', "\u{0636}\u{062e}\u{0645}"),
+    ('\u{fd70}', "\u{0636}\u{062e}\u{0645}"),
+    ('\u{fd71}', "\u{0637}\u{0645}\u{062d}"),
+    ('\u{fd72}', "\u{0637}\u{0645}\u{062d}"),
+    ('\u{fd73}', "\u{0637}\u{0645}\u{0645}"),
+    ('\u{fd74}', "\u{0637}\u{0645}\u{064a}"),
+    ('\u{fd75}', "\u{0639}\u{062c}\u{0645}"),
+    ('\u{fd76}', "\u{0639}\u{0645}\u{0645}"),
+    ('\u{fd77}', "\u{0639}\u{0645}\u{0645}"),
+    ('\u{fd78}', "\u{0639}\u{0645}\u{0649}"),
+    ('\u{fd79}', "\u{063a}\u{0645}\u{0645}"),
+    ('\u{fd7a}', "\u{063a}\u{0645}\u{064a}"),
+    ('\u{fd7b}', "\u{063a}\u{0645}\u{0649}"),
+    ('\u{fd7c}', "\u{0641}\u{062e}\u{0645}"),
+    ('\u{fd7d}', "\u{0641}\u{062e}\u{0645}"),
+    ('\u{fd7e}', "\u{0642}\u{0645}\u{062d}"),
+    ('\u{fd7f}', "\u{0642}\u{0645}\u{0645}"),
+    ('\u{fd80}', "\u{0644}\u{062d}\u{0645}"),
+    ('\u{fd81}', "\u{0644}\u{062d}\u{064a}"),
+    ('\u{fd82}', "\u{0644}\u{062d}\u{0649}"),
+    ('\u{fd83}', "\u{0644}\u{062c}\u{062c}"),
+    ('\u{fd84}', "\u{0644}\u{062c}\u{062c}"),
+    ('\u{fd85}', "\u{0644}\u{062e}\u{0645}"),
+    ('\u{fd86}', "\u{0644}\u{062e}\u{0645}"),
+    ('\u{fd87}', "\u{0644}\u{0645}\u{062d}"),
+    ('\u{fd88}', "\u{0644}\u{0645}\u{062d}"),
+    ('\u{fd89}', "\u{0645}\u{062d}\u{062c}"),
+    ('\u{fd8a}', "\u{0645}\u{062d}\u{0645}"),
+    ('\u{fd8b}', "\u{0645}\u{062d}\u{064a}"),
+    ('\u{fd8c}', "\u{0645}\u{062c}\u{062d}"),
+    ('\u{fd8d}', "\u{0645}\u{062c}\u{0645}"),
+    ('\u{fd8e}', "\u{0645}\u{062e}\u{062c}"),
+    ('\u{fd8f}', "\u{0645}\u{062e}\u{0645}"),
+    ('\u{fd92}', "\u{0645}\u{062c}\u{062e}"),
+    ('\u{fd93}', "\u{0647}\u{0645}\u{062c}"),
+    ('\u{fd94}', "\u{0647}\u{0645}\u{0645}"),
+    ('\u{fd95}', "\u{0646}\u{062d}\u{0645}"),
+    ('\u{fd96}', "\u{0646}\u{062d}\u{0649}"),
+    ('\u{fd97}', "\u{0646}\u{062c}\u{0645}"),
+    ('\u{fd98}', "\u{0646}\u{062c}\u{0645}"),
+    ('\u{fd99}', "\u{0646}\u{062c}\u{0649}"),
+    ('\u{fd9a}', "\u{0646}\u{0645}\u{064a}"),
+    ('\u{fd9b}', "\u{0646}\u{0645}\u{0649}"),
+    ('\u{fd9c}', "\u{064a}\u{0645}\u{0645}"),
+    ('\u{fd9d}', "\u{064a}\u{0645}\u{0645}"),
+    ('\u{fd9e}', "\u{0628}\u{062e}\u{064a}"),
+    ('\u{fd9f}', "\u{062a}\u{062c}\u{064a}"),
+    ('\u{fda0}', "\u{062a}\u{062c}\u{0649}"),
+    ('\u{fda1}', "\u{062a}\u{062e}\u{064a}"),
+    ('\u{fda2}', "\u{062a}\u{062e}\u{0649}"),
+    ('\u{fda3}', "\u{062a}\u{0645}\u{064a}"),
+    ('\u{fda4}', "\u{062a}\u{0645}\u{0649}"),
+    ('\u{fda5}', "\u{062c}\u{0645}\u{064a}"),
+    ('\u{fda6}', "\u{062c}\u{062d}\u{0649}"),
+    ('\u{fda7}', "\u{062c}\u{0645}\u{0649}"),
+    ('\u{fda8}', "\u{0633}\u{062e}\u{0649}"),
+    ('\u{fda9}', "\u{0635}\u{062d}\u{064a}"),
+    ('\u{fdaa}', "\u{0634}\u{062d}\u{064a}"),
+    ('\u{fdab}', "\u{0636}\u{062d}\u{064a}"),
+    ('\u{fdac}', "\u{0644}\u{062c}\u{064a}"),
+    ('\u{fdad}', "\u{0644}\u{0645}\u{064a}"),
+    ('\u{fdae}', "\u{064a}\u{062d}\u{064a}"),
+    ('\u{fdaf}', "\u{064a}\u{062c}\u{064a}"),
+    ('\u{fdb0}', "\u{064a}\u{0645}\u{064a}"),
+    ('\u{fdb1}', "\u{0645}\u{0645}\u{064a}"),
+    ('\u{fdb2}', "\u{0642}\u{0645}\u{064a}"),
+    ('\u{fdb3}', "\u{0646}\u{062d}\u{064a}"),
+    ('\u{fdb4}', "\u{0642}\u{0645}\u{062d}"),
+    ('\u{fdb5}', "\u{0644}\u{062d}\u{0645}"),
+    ('\u{fdb6}', "\u{0639}\u{0645}\u{064a}"),
+    ('\u{fdb7}', "\u{0643}\u{0645}\u{064a}"),
+    ('\u{fdb8}', "\u{0646}\u{062c}\u{062d}"),
+    ('\u{fdb9}', "\u{0645}\u{062e}\u{064a}"),
+    ('\u{fdba}', "\u{0644}\u{062c}\u{0645}"),
+    ('\u{fdbb}', "\u{0643}\u{0645}\u{0645}"),
+    ('\u{fdbc}', "\u{0644}\u{062c}\u{0645}"),
+    ('\u{fdbd}', "\u{0646}\u{062c}\u{062d}"),
+    ('\u{fdbe}', "\u{062c}\u{062d}\u{064a}"),
+    ('\u{fdbf}', "\u{062d}\u{062c}\u{064a}"),
+    ('\u{fdc0}', "\u{0645}\u{062c}\u{064a}"),
+    ('\u{fdc1}', "\u{0641}\u{0645}\u{064a}"),
+    ('\u{fdc2}', "\u{0628}\u{062d}\u{064a}"),
+    ('\u{fdc3}', "\u{0643}\u{0645}\u{0645}"),
+    ('\u{fdc4}', "\u{0639}\u{062c}\u{0645}"),
+    ('\u{fdc5}', "\u{0635}\u{0645}\u{0645}"),
+    ('\u{fdc6}', "\u{0633}\u{062e}\u{064a}"),
+    ('\u{fdc7}', "\u{0646}\u{062c}\u{064a}"),
+    ('\u{fdf0}', "\u{0635}\u{0644}\u{06d2}"),
+    ('\u{fdf1}', "\u{0642}\u{0644}\u{06d2}"),
+    ('\u{fdf2}', "\u{0627}\u{0644}\u{0644}\u{0647}"),
+    ('\u{fdf3}', "\u{0627}\u{0643}\u{0628}\u{0631}"),
+    ('\u{fdf4}', "\u{0645}\u{062d}\u{0645}\u{062f}"),
+    ('\u{fdf5}', "\u{0635}\u{0644}\u{0639}\u{0645}"),
+    ('\u{fdf6}', "\u{0631}\u{0633}\u{0648}\u{0644}"),
+    ('\u{fdf7}', "\u{0639}\u{0644}\u{064a}\u{0647}"),
+    ('\u{fdf8}', "\u{0648}\u{0633}\u{0644}\u{0645}"),
+    ('\u{fdf9}', "\u{0635}\u{0644}\u{0649}"),
+    ('\u{fdfa}', "\u{0635}\u{0644}\u{0649}\u{0020}\u{0627}\u{0644}\u{0644}\u{0647}\u{0020}\u{0639}\u{0644}\u{064a}\u{0647}\u{0020}\u{0648}\u{0633}\u{0644}\u{0645}"),
+    ('\u{fdfb}', "\u{062c}\u{0644}\u{0020}\u{062c}\u{0644}\u{0627}\u{0644}\u{0647}"),
+    ('\u{fdfc}', "\u{0631}\u{06cc}\u{0627}\u{0644}"),
+    ('\u{fe10}', "\u{002c}"),
+    ('\u{fe11}', "\u{3001}"),
+    ('\u{fe12}', "\u{3002}"),
+    ('\u{fe13}', "\u{003a}"),
+    ('\u{fe14}', "\u{003b}"),
+    ('\u{fe15}', "\u{0021}"),
+    ('\u{fe16}', "\u{003f}"),
+    ('\u{fe17}', "\u{3016}"),
+    ('\u{fe18}', "\u{3017}"),
+    ('\u{fe19}', "\u{002e}\u{002e}\u{002e}"),
+    ('\u{fe30}', "\u{002e}\u{002e}"),
+    ('\u{fe31}', "\u{2014}"),
+    ('\u{fe32}', "\u{2013}"),
+    ('\u{fe33}', "\u{005f}"),
+    ('\u{fe34}', "\u{005f}"),
+    ('\u{fe35}', "\u{0028}"),
+    ('\u{fe36}', "\u{0029}"),
+    ('\u{fe37}', "\u{007b}"),
+    ('\u{fe38}', "\u{007d}"),
+    ('\u{fe39}', "\u{3014}"),
+    ('\u{fe3a}', "\u{3015}"),
+    ('\u{fe3b}', "\u{3010}"),
+    ('\u{fe3c}', "\u{3011}"),
+    ('\u{fe3d}', "\u{300a}"),
+    ('\u{fe3e}', "\u{300b}"),
+    ('\u{fe3f}', "\u{3008}"),
+    ('\u{fe40}', "\u{3009}"),
+    ('\u{fe41}', "\u{300c}"),
+    ('\u{fe42}', "\u{300d}"),
+    ('\u{fe43}', "\u{300e}"),
+    ('\u{fe44}', "\u{300f}"),
+    ('\u{fe47}', "\u{005b}"),
+    ('\u{fe48}', "\u{005d}"),
+    ('\u{fe49}', "\u{0020}"),
+    ('\u{fe4a}', "\u{0020}"),
+    ('\u{fe4b}', "\u{0020}"),
+    ('\u{fe4c}', "\u{0020}"),
+    ('\u{fe4d}', "\u{005f}"),
+    ('\u{fe4e}', "\u{005f}"),
+    ('\u{fe4f}', "\u{005f}"),
+    ('\u{fe50}', "\u{002c}"),
+    ('\u{fe51}', "\u{3001}"),
+    ('\u{fe52}', "\u{002e}"),
+    ('\u{fe54}', "\u{003b}"),
+    ('\u{fe55}', "\u{003a}"),
+    ('\u{fe56}', "\u{003f}"),
+    ('\u{fe57}', "\u{0021}"),
+    ('\u{fe58}', "\u{2014}"),
+    ('\u{fe59}', "\u{0028}"),
+    ('\u{fe5a}', "\u{0029}"),
+    ('\u{fe5b}', "\u{007b}"),
+    ('\u{fe5c}', "\u{007d}"),
+    ('\u{fe5d}', "\u{3014}"),
+    ('\u{fe5e}', "\u{3015}"),
+    ('\u{fe5f}', "\u{0023}"),
+    ('\u{fe60}', "\u{0026}"),
+    ('\u{fe61}', "\u{002a}"),
+    ('\u{fe62}', "\u{002b}"),
+    ('\u{fe63}', "\u{002d}"),
+    ('\u{fe64}', "\u{003c}"),
+    ('\u{fe65}', "\u{003e}"),
+    ('\u{fe66}', "\u{003d}"),
+    ('\u{fe68}', "\u{005c}"),
+    ('\u{fe69}', "\u{0024}"),
+    ('\u{fe6a}', "\u{0025}"),
+    ('\u{fe6b}', "\u{0040}"),
+    ('\u{fe70}', "\u{0020}"),
+    ('\u{fe71}', "\u{0640}"),
+    ('\u{fe72}', "\u{0020}"),
+    ('\u{fe74}', "\u{0020}"),
+    ('\u{fe76}', "\u{0020}"),
+    ('\u{fe77}', "\u{0640}"),
+    ('\u{fe78}', "\u{0020}"),
+    ('\u{fe79}', "\u{0640}"),
+    ('\u{fe7a}', "\u{0020}"),
+    ('\u{fe7b}', "\u{0640}"),
+    ('\u{fe7c}', "\u{0020}"),
+    ('\u{fe7d}', "\u{0640}"),
+    ('\u{fe7e}', "\u{0020}"),
+    ('\u{fe7f}', "\u{0640}"),
+    ('\u{fe80}', "\u{0621}"),
+    ('\u{fe81}', "\u{0627}"),
+    ('\u{fe82}', "\u{0627}"),
+    ('\u{fe83}', "\u{0627}"),
+    ('\u{fe84}', "\u{0627}"),
+    ('\u{fe85}', "\u{0648}"),
+    ('\u{fe86}', "\u{0648}"),
+    ('\u{fe87}', "\u{0627}"),
+    ('\u{fe88}', "\u{0627}"),
+    ('\u{fe89}', "\u{064a}"),
+    ('\u{fe8a}', "\u{064a}"),
+    ('\u{fe8b}', "\u{064a}"),
+    ('\u{fe8c}', "\u{064a}"),
+    ('\u{fe8d}', "\u{0627}"),
+    ('\u{fe8e}', "\u{0627}"),
+    ('\u{fe8f}', "\u{0628}"),
+    ('\u{fe90}', "\u{0628}"),
+    ('\u{fe91}', "\u{0628}"),
+    ('\u{fe92}', "\u{0628}"),
+    ('\u{fe93}', "\u{0629}"),
+    ('\u{fe94}', "\u{0629}"),
+    ('\u{fe95}', "\u{062a}"),
+    ('\u{fe96}', "\u{062a}"),
+    ('\u{fe97}', "\u{062a}"),
+    ('\u{fe98}', "\u{062a}"),
+    ('\u{fe99}', "\u{062b}"),
+    ('\u{fe9a}', "\u{062b}"),
+    ('\u{fe9b}', "\u{062b}"),
+    ('\u{fe9c}', "\u{062b}"),
+    ('\u{fe9d}', "\u{062c}"),
+    ('\u{fe9e}', "\u{062c}"),
+    ('\u{fe9f}', "\u{062c}"),
+    ('\u{fea0}', "\u{062c}"),
+    ('\u{fea1}', "\u{062d}"),
+    ('\u{fea2}', "\u{062d}"),
+    ('\u{fea3}', "\u{062d}"),
+    ('\u{fea4}', "\u{062d}"),
+    ('\u{fea5}', "\u{062e}"),
+    ('\u{fea6}', "\u{062e}"),
+    ('\u{fea7}', "\u{062e}"),
+    ('\u{fea8}', "\u{062e}"),
+    ('\u{fea9}', "\u{062f}"),
+    ('\u{feaa}', "\u{062f}"),
+    ('\u{feab}', "\u{0630}"),
+    ('\u{feac}', "\u{0630}"),
+    ('\u{fead}', "\u{0631}"),
+    ('\u{feae}', "\u{0631}"),
+    ('\u{feaf}', "\u{0632}"),
+    ('\u{feb0}', "\u{0632}"),
+    ('\u{feb1}', "\u{0633}"),
+    ('\u{feb2}', "\u{0633}"),
+    ('\u{feb3}', "\u{0633}"),
+    ('\u{feb4}', "\u{0633}"),
+    ('\u{feb5}', "\u{0634}"),
+    ('\u{feb6}', "\u{0634}"),
+    ('\u{feb7}', "\u{0634}"),
+    ('\u{feb8}', "\u{0634}"),
+    ('\u{feb9}', "\u{0635}"),
+    ('\u{feba}', "\u{0635}"),
+    ('\u{febb}', "\u{0635}"),
+    ('\u{febc}', "\u{0635}"),
+    ('\u{febd}', "\u{0636}"),
+    ('\u{febe}', "\u{0636}"),
+    ('\u{febf}', "\u{0636}"),
+    ('\u{fec0}', "\u{0636}"),
+    ('\u{fec1}', "\u{0637}"),
+    ('\u{fec2}', "\u{0637}"),
+    ('\u{fec3}', "\u{0637}"),
+    ('\u{fec4}', "\u{0637}"),
+    ('\u{fec5}', "\u{0638}"),
+    ('\u{fec6}', "\u{0638}"),
+    ('\u{fec7}', "\u{0638}"),
+    ('\u{fec8}', "\u{0638}"),
+    ('\u{fec9}', "\u{0639}"),
+    ('\u{feca}', "\u{0639}"),
+    ('\u{fecb}', "\u{0639}"),
+    ('\u{fecc}', "\u{0639}"),
+    ('\u{fecd}', "\u{063a}"),
+    ('\u{fece}', "\u{063a}"),
+    ('\u{fecf}', "\u{063a}"),
+    ('\u{fed0}', "\u{063a}"),
+    ('\u{fed1}', "\u{0641}"),
+    ('\u{fed2}', "\u{0641}"),
+    ('\u{fed3}', "\u{0641}"),
+    ('\u{fed4}', "\u{0641}"),
+    ('\u{fed5}', "\u{0642}"),
+    ('\u{fed6}', "\u{0642}"),
+    ('\u{fed7}', "\u{0642}"),
+    ('\u{fed8}', "\u{0642}"),
+    ('\u{fed9}', "\u{0643}"),
+    ('\u{feda}', "\u{0643}"),
+    ('\u{fedb}', "\u{0643}"),
+    ('\u{fedc}', "\u{0643}"),
+    ('\u{fedd}', "\u{0644}"),
+    ('\u{fede}', "\u{0644}"),
+    ('\u{fedf}', "\u{0644}"),
+    ('\u{fee0}', "\u{0644}"),
+    ('\u{fee1}', "\u{0645}"),
+    ('\u{fee2}', "\u{0645}"),
+    ('\u{fee3}', "\u{0645}"),
+    ('\u{fee4}', "\u{0645}"),
+    ('\u{fee5}', "\u{0646}"),
+    ('\u{fee6}', "\u{0646}"),
+    ('\u{fee7}', "\u{0646}"),
+    ('\u{fee8}', "\u{0646}"),
+    ('\u{fee9}', "\u{0647}"),
+    ('\u{feea}', "\u{0647}"),
+    ('\u{feeb}', "\u{0647}"),
+    ('\u{feec}', "\u{0647}"),
+    ('\u{feed}', "\u{0648}"),
+    ('\u{feee}', "\u{0648}"),
+    ('\u{feef}', "\u{0649}"),
+    ('\u{fef0}', "\u{0649}"),
+    ('\u{fef1}', "\u{064a}"),
+    ('\u{fef2}', "\u{064a}"),
+    ('\u{fef3}', "\u{064a}"),
+    ('\u{fef4}', "\u{064a}"),
+    ('\u{fef5}', "\u{0644}\u{0627}"),
+    ('\u{fef6}', "\u{0644}\u{0627}"),
+    ('\u{fef7}', "\u{0644}\u{0627}"),
+    ('\u{fef8}', "\u{0644}\u{0627}"),
+    ('\u{fef9}', "\u{0644}\u{0627}"),
+    ('\u{fefa}', "\u{0644}\u{0627}"),
+    ('\u{fefb}', "\u{0644}\u{0627}"),
+    ('\u{fefc}', "\u{0644}\u{0627}"),
+    ('\u{ff01}', "\u{0021}"),
+    ('\u{ff02}', "\u{0022}"),
+    ('\u{ff03}', "\u{0023}"),
+    ('\u{ff04}', "\u{0024}"),
+    ('\u{ff05}', "\u{0025}"),
+    ('\u{ff06}', "\u{0026}"),
+    ('\u{ff07}', "\u{0027}"),
+    ('\u{ff08}', "\u{0028}"),
+    ('\u{ff09}', "\u{0029}"),
+    ('\u{ff0a}', "\u{002a}"),
+    ('\u{ff0b}', "\u{002b}"),
+    ('\u{ff0c}', "\u{002c}"),
+    ('\u{ff0d}', "\u{002d}"),
+    ('\u{ff0e}', "\u{002e}"),
+    ('\u{ff0f}', "\u{002f}"),
+    ('\u{ff10}', "0"),
+    ('\u{ff11}', "1"),
+    ('\u{ff12}', "2"),
+    ('\u{ff13}', "3"),
+    ('\u{ff14}', "4"),
+    ('\u{ff15}', "5"),
+    ('\u{ff16}', "6"),
+    ('\u{ff17}', "7"),
+    ('\u{ff18}', "8"),
+    ('\u{ff19}', "9"),
+    ('\u{ff1a}', "\u{003a}"),
+    ('\u{ff1b}', "\u{003b}"),
+    ('\u{ff1c}', "\u{003c}"),
+    ('\u{ff1d}', "\u{003d}"),
+    ('\u{ff1e}', "\u{003e}"),
+    ('\u{ff1f}', "\u{003f}"),
+    ('\u{ff20}', "\u{0040}"),
+    ('\u{ff21}', "a"),
+    ('\u{ff22}', "b"),
+    ('\u{ff23}', "c"),
+    ('\u{ff24}', "d"),
+    ('\u{ff25}', "e"),
+    ('\u{ff26}', "f"),
+    ('\u{ff27}', "g"),
+    ('\u{ff28}', "h"),
+    ('\u{ff29}', "i"),
+    ('\u{ff2a}', "j"),
+    ('\u{ff2b}', "k"),
+    ('\u{ff2c}', "l"),
+    ('\u{ff2d}', "m"),
+    ('\u{ff2e}', "n"),
+    ('\u{ff2f}', "o"),
+    ('\u{ff30}', "p"),
+    ('\u{ff31}', "q"),
+    ('\u{ff32}', "r"),
+    ('\u{ff33}', "s"),
+    ('\u{ff34}', "t"),
+    ('\u{ff35}', "u"),
+    ('\u{ff36}', "v"),
+    ('\u{ff37}', "w"),
+    ('\u{ff38}', "x"),
+    ('\u{ff39}', "y"),
+    ('\u{ff3a}', "z"),
+    ('\u{ff3b}', "\u{005b}"),
+    ('\u{ff3c}', "\u{005c}"),
+    ('\u{ff3d}', "\u{005d}"),
+    ('\u{ff3e}', "\u{005e}"),
+    ('\u{ff3f}', "\u{005f}"),
+    ('\u{ff40}', "\u{0060}"),
+    ('\u{ff41}', "a"),
+    ('\u{ff42}', "b"),
+    ('\u{ff43}', "c"),
+    ('\u{ff44}', "d"),
+    ('\u{ff45}', "e"),
+    ('\u{ff46}', "f"),
+    ('\u{ff47}', "g"),
+    ('\u{ff48}', "h"),
+    ('\u{ff49}', "i"),
+    ('\u{ff4a}', "j"),
+    ('\u{ff4b}', "k"),
+    ('\u{ff4c}', "l"),
+    ('\u{ff4d}', "m"),
+    ('\u{ff4e}', "n"),
+    ('\u{ff4f}', "o"),
+    ('\u{ff50}', "p"),
+    ('\u{ff51}', "q"),
+    ('\u{ff52}', "r"),
+    ('\u{ff53}', "s"),
+    ('\u{ff54}', "t"),
+    ('\u{ff55}', "u"),
+    ('\u{ff56}', "v"),
+    ('\u{ff57}', "w"),
+    ('\u{ff58}', "x"),
+    ('\u{ff59}', "y"),
+    ('\u{ff5a}', "z"),
+    ('\u{ff5b}', "\u{007b}"),
+    ('\u{ff5c}', "\u{007c}"),
+    ('\u{ff5d}', "\u{007d}"),
+    ('\u{ff5e}', "\u{007e}"),
+    ('\u{ff5f}', "\u{2985}"),
+    ('\u{ff60}', "\u{2986}"),
+    ('\u{ff61}', "\u{3002}"),
+    ('\u{ff62}', "\u{300c}"),
+    ('\u{ff63}', "\u{300d}"),
+    ('\u{ff64}', "\u{3001}"),
+    ('\u{ff65}', "\u{30fb}"),
+    ('\u{ff66}', "\u{30f2}"),
+    ('\u{ff67}', "\u{30a1}"),
+    ('\u{ff68}', "\u{30a3}"),
+    ('\u{ff69}', "\u{30a5}"),
+    ('\u{ff6a}', "\u{30a7}"),
+    ('\u{ff6b}', "\u{30a9}"),
+    ('\u{ff6c}', "\u{30e3}"),
+    ('\u{ff6d}', "\u{30e5}"),
+    ('\u{ff6e}', "\u{30e7}"),
+    ('\u{ff6f}', "\u{30c3}"),
+    ('\u{ff70}', "\u{30fc}"),
+    ('\u{ff71}', "\u{30a2}"),
+    ('\u{ff72}', "\u{30a4}"),
+    ('\u{ff73}', "\u{30a6}"),
+    ('\u{ff74}', "\u{30a8}"),
+    ('\u{ff75}', "\u{30aa}"),
+    ('\u{ff76}', "\u{30ab}"),
+    ('\u{ff77}', "\u{30ad}"),
+    ('\u{ff78}', "\u{30af}"),
+    ('\u{ff79}', "\u{30b1}"),
+    ('\u{ff7a}', "\u{30b3}"),
+    ('\u{ff7b}', "\u{30b5}"),
+    ('\u{ff7c}', "\u{30b7}"),
+    ('\u{ff7d}', "\u{30b9}"),
+    ('\u{ff7e}', "\u{30bb}"),
+    ('\u{ff7f}', "\u{30bd}"),
+    ('\u{ff80}', "\u{30bf}"),
+    ('\u{ff81}', "\u{30c1}"),
+    ('\u{ff82}', "\u{30c4}"),
+    ('\u{ff83}', "\u{30c6}"),
+    ('\u{ff84}', "\u{30c8}"),
+    ('\u{ff85}', "\u{30ca}"),
+    ('\u{ff86}', "\u{30cb}"),
+    ('\u{ff87}', "\u{30cc}"),
+    ('\u{ff88}', "\u{30cd}"),
+    ('\u{ff89}', "\u{30ce}"),
+    ('\u{ff8a}', "\u{30cf}"),
+    ('\u{ff8b}', "\u{30d2}"),
+    ('\u{ff8c}', "\u{30d5}"),
+    ('\u{ff8d}', "\u{30d8}"),
+    ('\u{ff8e}', "\u{30db}"),
+    ('\u{ff8f}', "\u{30de}"),
+    ('\u{ff90}', "\u{30df}"),
+    ('\u{ff91}', "\u{30e0}"),
+    ('\u{ff92}', "\u{30e1}"),
+    ('\u{ff93}', "\u{30e2}"),
+    ('\u{ff94}', "\u{30e4}"),
+    ('\u{ff95}', "\u{30e6}"),
+    ('\u{ff96}', "\u{30e8}"),
+    ('\u{ff97}', "\u{30e9}"),
+    ('\u{ff98}', "\u{30ea}"),
+    ('\u{ff99}', "\u{30eb}"),
+    ('\u{ff9a}', "\u{30ec}"),
+    ('\u{ff9b}', "\u{30ed}"),
+    ('\u{ff9c}', "\u{30ef}"),
+    ('\u{ff9d}', "\u{30f3}"),
+    ('\u{ff9e}', ""),
+    ('\u{ff9f}', ""),
+    ('\u{ffa0}', "\u{1160}"),
+    ('\u{ffa1}', "\u{1100}"),
+    ('\u{ffa2}', "\u{1101}"),
+    ('\u{ffa3}', "\u{11aa}"),
+    ('\u{ffa4}', "\u{1102}"),
+    ('\u{ffa5}', "\u{11ac}"),
+    ('\u{ffa6}', "\u{11ad}"),
+    ('\u{ffa7}', "\u{1103}"),
+    ('\u{ffa8}', "\u{1104}"),
+    ('\u{ffa9}', "\u{1105}"),
+    ('\u{ffaa}', "\u{11b0}"),
+    ('\u{ffab}', "\u{11b1}"),
+    ('\u{ffac}', "\u{11b2}"),
+    ('\u{ffad}', "\u{11b3}"),
+    ('\u{ffae}', "\u{11b4}"),
+    ('\u{ffaf}', "\u{11b5}"),
+    ('\u{ffb0}', "\u{111a}"),
+    ('\u{ffb1}', "\u{1106}"),
+    ('\u{ffb2}', "\u{1107}"),
+    ('\u{ffb3}', "\u{1108}"),
+    ('\u{ffb4}', "\u{1121}"),
+    ('\u{ffb5}', "\u{1109}"),
+    ('\u{ffb6}', "\u{110a}"),
+    ('\u{ffb7}', "\u{110b}"),
+    ('\u{ffb8}', "\u{110c}"),
+    ('\u{ffb9}', "\u{110d}"),
+    ('\u{ffba}', "\u{110e}"),
+    ('\u{ffbb}', "\u{110f}"),
+    ('\u{ffbc}', "\u{1110}"),
+    ('\u{ffbd}', "\u{1111}"),
+    ('\u{ffbe}', "\u{1112}"),
+    ('\u{ffc2}', "\u{1161}"),
+    ('\u{ffc3}', "\u{1162}"),
+    ('\u{ffc4}', "\u{1163}"),
+    ('\u{ffc5}', "\u{1164}"),
+    ('\u{ffc6}', "\u{1165}"),
+    ('\u{ffc7}', "\u{1166}"),
+    ('\u{ffca}', "\u{1167}"),
+    ('\u{ffcb}', "\u{1168}"),
+    ('\u{ffcc}', "\u{1169}"),
+    ('\u{ffcd}', "\u{116a}"),
+    ('\u{ffce}', "\u{116b}"),
+    ('\u{ffcf}', "\u{116c}"),
+    ('\u{ffd2}', "\u{116d}"),
+    ('\u{ffd3}', "\u{116e}"),
+    ('\u{ffd4}', "\u{116f}"),
+    ('\u{ffd5}', "\u{1170}"),
+    ('\u{ffd6}', "\u{1171}"),
+    ('\u{ffd7}', "\u{1172}"),
+    ('\u{ffda}', "\u{1173}"),
+    ('\u{ffdb}', "\u{1174}"),
+    ('\u{ffdc}', "\u{1175}"),
+    ('\u{ffe0}', "\u{00a2}"),
+    ('\u{ffe1}', "\u{00a3}"),
+    ('\u{ffe2}', "\u{00ac}"),
+    ('\u{ffe3}', "\u{0020}"),
+    ('\u{ffe4}', "\u{00a6}"),
+    ('\u{ffe5}', "\u{00a5}"),
+    ('\u{ffe6}', "\u{20a9}"),
+    ('\u{ffe8}', "\u{2502}"),
+    ('\u{ffe9}', "\u{2190}"),
+    ('\u{ffea}', "\u{2191}"),
+    ('\u{ffeb}', "\u{2192}"),
+    ('\u{ffec}', "\u{2193}"),
+    ('\u{ffed}', "\u{25a0}"),
+    ('\u{ffee}', "\u{25cb}"),
+    ('\u{10400}', "\u{10428}"),
+    ('\u{10401}', "\u{10429}"),
+    ('\u{10402}', "\u{1042a}"),
+    ('\u{10403}', "\u{1042b}"),
+    ('\u{10404}', "\u{1042c}"),
+    ('\u{10405}', "\u{1042d}"),
+    ('\u{10406}', "\u{1042e}"),
+    ('\u{10407}', "\u{1042f}"),
+    ('\u{10408}', "\u{10430}"),
+    ('\u{10409}', "\u{10431}"),
+    ('\u{1040a}', "\u{10432}"),
+    ('\u{1040b}', "\u{10433}"),
+    ('\u{1040c}', "\u{10434}"),
+    ('\u{1040d}', "\u{10435}"),
+    ('\u{1040e}', "\u{10436}"),
+    ('\u{1040f}', "\u{10437}"),
+    ('\u{10410}', "\u{10438}"),
+    ('\u{10411}', "\u{10439}"),
+    ('\u{10412}', "\u{1043a}"),
+    ('\u{10413}', "\u{1043b}"),
+    ('\u{10414}', "\u{1043c}"),
+    ('\u{10415}', "\u{1043d}"),
+    ('\u{10416}', "\u{1043e}"),
+    ('\u{10417}', "\u{1043f}"),
+    ('\u{10418}', "\u{10440}"),
+    ('\u{10419}', "\u{10441}"),
+    ('\u{1041a}', "\u{10442}"),
+    ('\u{1041b}', "\u{10443}"),
+    ('\u{1041c}', "\u{10444}"),
+    ('\u{1041d}', "\u{10445}"),
+    ('\u{1041e}', "\u{10446}"),
+    ('\u{1041f}', "\u{10447}"),
+    ('\u{10420}', "\u{10448}"),
+    ('\u{10421}', "\u{10449}"),
+    ('\u{10422}', "\u{1044a}"),
+    ('\u{10423}', "\u{1044b}"),
+    ('\u{10424}', "\u{1044c}"),
+    ('\u{10425}', "\u{1044d}"),
+    ('\u{10426}', "\u{1044e}"),
+    ('\u{10427}', "\u{1044f}"),
+    ('\u{104b0}', "\u{104d8}"),
+    ('\u{104b1}', "\u{104d9}"),
+    ('\u{104b2}', "\u{104da}"),
+    ('\u{104b3}', "\u{104db}"),
+    ('\u{104b4}', "\u{104dc}"),
+    ('\u{104b5}', "\u{104dd}"),
+    ('\u{104b6}', "\u{104de}"),
+    ('\u{104b7}', "\u{104df}"),
+    ('\u{104b8}', "\u{104e0}"),
+    ('\u{104b9}', "\u{104e1}"),
+    ('\u{104ba}', "\u{104e2}"),
+    ('\u{104bb}', "\u{104e3}"),
+    ('\u{104bc}', "\u{104e4}"),
+    ('\u{104bd}', "\u{104e5}"),
+    ('\u{104be}', "\u{104e6}"),
+    ('\u{104bf}', "\u{104e7}"),
+    ('\u{104c0}', "\u{104e8}"),
+    ('\u{104c1}', "\u{104e9}"),
+    ('\u{104c2}', "\u{104ea}"),
+    ('\u{104c3}', "\u{104eb}"),
+    ('\u{104c4}', "\u{104ec}"),
+    ('\u{104c5}', "\u{104ed}"),
+    ('\u{104c6}', "\u{104ee}"),
+    ('\u{104c7}', "\u{104ef}"),
+    ('\u{104c8}', "\u{104f0}"),
+    ('\u{104c9}', "\u{104f1}"),
+    ('\u{104ca}', "\u{104f2}"),
+    ('\u{104cb}', "\u{104f3}"),
+    ('\u{104cc}', "\u{104f4}"),
+    ('\u{104cd}', "\u{104f5}"),
+    ('\u{104ce}', "\u{104f6}"),
+    ('\u{104cf}', "\u{104f7}"),
+    ('\u{104d0}', "\u{104f8}"),
+    ('\u{104d1}', "\u{104f9}"),
+    ('\u{104d2}', "\u{104fa}"),
+    ('\u{104d3}', "\u{104fb}"),
+    ('\u{10570}', "\u{10597}"),
+    ('\u{10571}', "\u{10598}"),
+    ('\u{10572}', "\u{10599}"),
+    ('\u{10573}', "\u{1059a}"),
+    ('\u{10574}', "\u{1059b}"),
+    ('\u{10575}', "\u{1059c}"),
+    ('\u{10576}', "\u{1059d}"),
+    ('\u{10577}', "\u{1059e}"),
+    ('\u{10578}', "\u{1059f}"),
+    ('\u{10579}', "\u{105a0}"),
+    ('\u{1057a}', "\u{105a1}"),
+    ('\u{1057c}', "\u{105a3}"),
+    ('\u{1057d}', "\u{105a4}"),
+    ('\u{1057e}', "\u{105a5}"),
+    ('\u{1057f}', "\u{105a6}"),
+    ('\u{10580}', "\u{105a7}"),
+    ('\u{10581}', "\u{105a8}"),
+    ('\u{10582}', "\u{105a9}"),
+    ('\u{10583}', "\u{105aa}"),
+    ('\u{10584}', "\u{105ab}"),
+    ('\u{10585}', "\u{105ac}"),
+    ('\u{10586}', "\u{105ad}"),
+    ('\u{10587}', "\u{105ae}"),
+    ('\u{10588}', "\u{105af}"),
+    ('\u{10589}', "\u{105b0}"),
+    ('\u{1058a}', "\u{105b1}"),
+    ('\u{1058c}', "\u{105b3}"),
+    ('\u{1058d}', "\u{105b4}"),
+    ('\u{1058e}', "\u{105b5}"),
+    ('\u{1058f}', "\u{105b6}"),
+    ('\u{10590}', "\u{105b7}"),
+    ('\u{10591}', "\u{105b8}"),
+    ('\u{10592}', "\u{105b9}"),
+    ('\u{10594}', "\u{105bb}"),
+    ('\u{10595}', "\u{105bc}"),
+    ('\u{10781}', "\u{02d0}"),
+    ('\u{10782}', "\u{02d1}"),
+    ('\u{10783}', "ae"),
+    ('\u{10784}', "\u{0299}"),
+    ('\u{10785}', "\u{0253}"),
+    ('\u{10787}', "\u{02a3}"),
+    ('\u{10788}', "\u{ab66}"),
+    ('\u{10789}', "\u{02a5}"),
+    ('\u{1078a}', "\u{02a4}"),
+    ('\u{1078b}', "\u{0256}"),
+    ('\u{1078c}', "\u{0257}"),
+    ('\u{1078d}', "\u{1d91}"),
+    ('\u{1078e}', "\u{0258}"),
+    ('\u{1078f}', "\u{025e}"),
+    ('\u{10790}', "\u{02a9}"),
+    ('\u{10791}', "\u{0264}"),
+    ('\u{10792}', "\u{0262}"),
+    ('\u{10793}', "\u{0260}"),
+    ('\u{10794}', "\u{029b}"),
+    ('\u{10795}', "h"),
+    ('\u{10796}', "\u{029c}"),
+    ('\u{10797}', "\u{0267}"),
+    ('\u{10798}', "\u{0284}"),
+    ('\u{10799}', "\u{02aa}"),
+    ('\u{1079a}', "\u{02ab}"),
+    ('\u{1079b}', "\u{026c}"),
+    ('\u{1079c}', "\u{1df04}"),
+    ('\u{1079d}', "\u{a78e}"),
+    ('\u{1079e}', "\u{026e}"),
+    ('\u{1079f}', "\u{1df05}"),
+    ('\u{107a0}', "\u{028e}"),
+    ('\u{107a1}', "\u{1df06}"),
+    ('\u{107a2}', "o"),
+    ('\u{107a3}', "\u{0276}"),
+    ('\u{107a4}', "\u{0277}"),
+    ('\u{107a5}', "q"),
+    ('\u{107a6}', "\u{027a}"),
+    ('\u{107a7}', "\u{1df08}"),
+    ('\u{107a8}', "\u{027d}"),
+    ('\u{107a9}', "\u{027e}"),
+    ('\u{107aa}', "\u{0280}"),
+    ('\u{107ab}', "\u{02a8}"),
+    ('\u{107ac}', "\u{02a6}"),
+    ('\u{107ad}', "\u{ab67}"),
+    ('\u{107ae}', "\u{02a7}"),
+    ('\u{107af}', "\u{0288}"),
+    ('\u{107b0}', "\u{2c71}"),
+    ('\u{107b2}', "\u{028f}"),
+    ('\u{107b3}', "\u{02a1}"),
+    ('\u{107b4}', "\u{02a2}"),
+    ('\u{107b5}', "\u{0298}"),
+    ('\u{107b6}', "\u{01c0}"),
+    ('\u{107b7}', "\u{01c1}"),
+    ('\u{107b8}', "\u{01c2}"),
+    ('\u{107b9}', "\u{1df0a}"),
+    ('\u{107ba}', "\u{1df1e}"),
+    ('\u{10c80}', "\u{10cc0}"),
+    ('\u{10c81}', "\u{10cc1}"),
+    ('\u{10c82}', "\u{10cc2}"),
+    ('\u{10c83}', "\u{10cc3}"),
+    ('\u{10c84}', "\u{10cc4}"),
+    ('\u{10c85}', "\u{10cc5}"),
+    ('\u{10c86}', "\u{10cc6}"),
+    ('\u{10c87}', "\u{10cc7}"),
+    ('\u{10c88}', "\u{10cc8}"),
+    ('\u{10c89}', "\u{10cc9}"),
+    ('\u{10c8a}', "\u{10cca}"),
+    ('\u{10c8b}', "\u{10ccb}"),
+    ('\u{10c8c}', "\u{10ccc}"),
+    ('\u{10c8d}', "\u{10ccd}"),
+    ('\u{10c8e}', "\u{10cce}"),
+    ('\u{10c8f}', "\u{10ccf}"),
+    ('\u{10c90}', "\u{10cd0}"),
+    ('\u{10c91}', "\u{10cd1}"),
+    ('\u{10c92}', "\u{10cd2}"),
+    ('\u{10c93}', "\u{10cd3}"),
+    ('\u{10c94}', "\u{10cd4}"),
+    ('\u{10c95}', "\u{10cd5}"),
+    ('\u{10c96}', "\u{10cd6}"),
+    ('\u{10c97}', "\u{10cd7}"),
+    ('\u{10c98}', "\u{10cd8}"),
+    ('\u{10c99}', "\u{10cd9}"),
+    ('\u{10c9a}', "\u{10cda}"),
+    ('\u{10c9b}', "\u{10cdb}"),
+    ('\u{10c9c}', "\u{10cdc}"),
+    ('\u{10c9d}', "\u{10cdd}"),
+    ('\u{10c9e}', "\u{10cde}"),
+    ('\u{10c9f}', "\u{10cdf}"),
+    ('\u{10ca0}', "\u{10ce0}"),
+    ('\u{10ca1}', "\u{10ce1}"),
+    ('\u{10ca2}', "\u{10ce2}"),
+    ('\u{10ca3}', "\u{10ce3}"),
+    ('\u{10ca4}', "\u{10ce4}"),
+    ('\u{10ca5}', "\u{10ce5}"),
+    ('\u{10ca6}', "\u{10ce6}"),
+    ('\u{10ca7}', "\u{10ce7}"),
+    ('\u{10ca8}', "\u{10ce8}"),
+    ('\u{10ca9}', "\u{10ce9}"),
+    ('\u{10caa}', "\u{10cea}"),
+    ('\u{10cab}', "\u{10ceb}"),
+    ('\u{10cac}', "\u{10cec}"),
+    ('\u{10cad}', "\u{10ced}"),
+    ('\u{10cae}', "\u{10cee}"),
+    ('\u{10caf}', "\u{10cef}"),
+    ('\u{10cb0}', "\u{10cf0}"),
+    ('\u{10cb1}', "\u{10cf1}"),
+    ('\u{10cb2}', "\u{10cf2}"),
+    ('\u{1109a}', "\u{11099}"),
+    ('\u{1109c}', "\u{1109b}"),
+    ('\u{110ab}', "\u{110a5}"),
+    ('\u{1134b}', "\u{11347}\u{1133e}"),
+    ('\u{1134c}', "\u{11347}\u{11357}"),
+    ('\u{114bb}', "\u{114b9}"),
+    ('\u{114bc}', "\u{114b9}\u{114b0}"),
+    ('\u{114be}', "\u{114b9}\u{114bd}"),
+    ('\u{115ba}', "\u{115b8}\u{115af}"),
+    ('\u{115bb}', "\u{115b9}\u{115af}"),
+    ('\u{118a0}', "\u{118c0}"),
+    ('\u{118a1}', "\u{118c1}"),
+    ('\u{118a2}', "\u{118c2}"),
+    ('\u{118a3}', "\u{118c3}"),
+    ('\u{118a4}', "\u{118c4}"),
+    ('\u{118a5}', "\u{118c5}"),
+    ('\u{118a6}', "\u{118c6}"),
+    ('\u{118a7}', "\u{118c7}"),
+    ('\u{118a8}', "\u{118c8}"),
+    ('\u{118a9}', "\u{118c9}"),
+    ('\u{118aa}', "\u{118ca}"),
+    ('\u{118ab}', "\u{118cb}"),
+    ('\u{118ac}', "\u{118cc}"),
+    ('\u{118ad}', "\u{118cd}"),
+    ('\u{118ae}', "\u{118ce}"),
+    ('\u{118af}', "\u{118cf}"),
+    ('\u{118b0}', "\u{118d0}"),
+    ('\u{118b1}', "\u{118d1}"),
+    ('\u{118b2}', "\u{118d2}"),
+    ('\u{118b3}', "\u{118d3}"),
+    ('\u{118b4}', "\u{118d4}"),
+    ('\u{118b5}', "\u{118d5}"),
+    ('\u{118b6}', "\u{118d6}"),
+    ('\u{118b7}', "\u{118d7}"),
+    ('\u{118b8}', "\u{118d8}"),
+    ('\u{118b9}', "\u{118d9}"),
+    ('\u{118ba}', "\u{118da}"),
+    ('\u{118bb}', "\u{118db}"),
+    ('\u{118bc}', "\u{118dc}"),
+    ('\u{118bd}', "\u{118dd}"),
+    ('\u{118be}', "\u{118de}"),
+    ('\u{118bf}', "\u{118df}"),
+    ('\u{11938}', "\u{11935}\u{11930}"),
+    ('\u{16e40}', "\u{16e60}"),
+    ('\u{16e41}', "\u{16e61}"),
+    ('\u{16e42}', "\u{16e62}"),
+    ('\u{16e43}', "\u{16e63}"),
+    ('\u{16e44}', "\u{16e64}"),
+    ('\u{16e45}', "\u{16e65}"),
+    ('\u{16e46}', "\u{16e66}"),
+    ('\u{16e47}', "\u{16e67}"),
+    ('\u{16e48}', "\u{16e68}"),
+    ('\u{16e49}', "\u{16e69}"),
+    ('\u{16e4a}', "\u{16e6a}"),
+    ('\u{16e4b}', "\u{16e6b}"),
+    ('\u{16e4c}', "\u{16e6c}"),
+    ('\u{16e4d}', "\u{16e6d}"),
+    ('\u{16e4e}', "\u{16e6e}"),
+    ('\u{16e4f}', "\u{16e6f}"),
+    ('\u{16e50}', "\u{16e70}"),
+    ('\u{16e51}', "\u{16e71}"),
+    ('\u{16e52}', "\u{16e72}"),
+    ('\u{16e53}', "\u{16e73}"),
+    ('\u{16e54}', "\u{16e74}"),
+    ('\u{16e55}', "\u{16e75}"),
+    ('\u{16e56}', "\u{16e76}"),
+    ('\u{16e57}', "\u{16e77}"),
+    ('\u{16e58}', "\u{16e78}"),
+    ('\u{16e59}', "\u{16e79}"),
+    ('\u{16e5a}', "\u{16e7a}"),
+    ('\u{16e5b}', "\u{16e7b}"),
+    ('\u{16e5c}', "\u{16e7c}"),
+    ('\u{16e5d}', "\u{16e7d}"),
+    ('\u{16e5e}', "\u{16e7e}"),
+    ('\u{16e5f}', "\u{16e7f}"),
+    ('\u{1d15e}', "\u{1d157}"),
+    ('\u{1d15f}', "\u{1d158}"),
+    ('\u{1d160}', "\u{1d158}"),
+    ('\u{1d161}', "\u{1d158}"),
+    ('\u{1d162}', "\u{1d158}"),
+    ('\u{1d163}', "\u{1d158}"),
+    ('\u{1d164}', "\u{1d158}"),
+    ('\u{1d1bb}', "\u{1d1b9}"),
+    ('\u{1d1bc}', "\u{1d1ba}"),
+    ('\u{1d1bd}', "\u{1d1b9}"),
+    ('\u{1d1be}', "\u{1d1ba}"),
+    ('\u{1d1bf}', "\u{1d1b9}"),
+    ('\u{1d1c0}', "\u{1d1ba}"),
+    ('\u{1d400}', "a"),
+    ('\u{1d401}', "b"),
+    ('\u{1d402}', "c"),
+    ('\u{1d403}', "d"),
+    ('\u{1d404}', "e"),
+    ('\u{1d405}', "f"),
+    ('\u{1d406}', "g"),
+    ('\u{1d407}', "h"),
+    ('\u{1d408}', "i"),
+    ('\u{1d409}', "j"),
+    ('\u{1d40a}', "k"),
+    ('\u{1d40b}', "l"),
+    ('\u{1d40c}', "m"),
+    ('\u{1d40d}', "n"),
+    ('\u{1d40e}', "o"),
+    ('\u{1d40f}', "p"),
+    ('\u{1d410}', "q"),
+    ('\u{1d411}', "r"),
+    ('\u{1d412}', "s"),
+    ('\u{1d413}', "t"),
+    ('\u{1d414}', "u"),
+    ('\u{1d415}', "v"),
+    ('\u{1d416}', "w"),
+    ('\u{1d417}', "x"),
+    ('\u{1d418}', "y"),
+    ('\u{1d419}', "z"),
+    ('\u{1d41a}', "a"),
+    ('\u{1d41b}', "b"),
+    ('\u{1d41c}', "c"),
+    ('\u{1d41d}', "d"),
+    ('\u{1d41e}', "e"),
+    ('\u{1d41f}', "f"),
+    ('\u{1d420}', "g"),
+    ('\u{1d421}', "h"),
+    ('\u{1d422}', "i"),
+    ('\u{1d423}', "j"),
+    ('\u{1d424}', "k"),
+    ('\u{1d425}', "l"),
+    ('\u{1d426}', "m"),
+    ('\u{1d427}', "n"),
+    ('\u{1d428}', "o"),
+    ('\u{1d429}', "p"),
+    ('\u{1d42a}', "q"),
+    ('\u{1d42b}', "r"),
+    ('\u{1d42c}', "s"),
+    ('\u{1d42d}', "t"),
+    ('\u{1d42e}', "u"),
+    ('\u{1d42f}', "v"),
+    ('\u{1d430}', "w"),
+    ('\u{1d431}', "x"),
+    ('\u{1d432}', "y"),
+    ('\u{1d433}', "z"),
+    ('\u{1d434}', "a"),
+    ('\u{1d435}', "b"),
+    ('\u{1d436}', "c"),
+    ('\u{1d437}', "d"),
+    ('\u{1d438}', "e"),
+    ('\u{1d439}', "f"),
+    ('\u{1d43a}', "g"),
+    ('\u{1d43b}', "h"),
+    ('\u{1d43c}', "i"),
+    ('\u{1d43d}', "j"),
+    ('\u{1d43e}', "k"),
+    ('\u{1d43f}', "l"),
+    ('\u{1d440}', "m"),
+    ('\u{1d441}', "n"),
+    ('\u{1d442}', "o"),
+    ('\u{1d443}', "p"),
+    ('\u{1d444}', "q"),
+    ('\u{1d445}', "r"),
+    ('\u{1d446}', "s"),
+    ('\u{1d447}', "t"),
+    ('\u{1d448}', "u"),
+    ('\u{1d449}', "v"),
+    ('\u{1d44a}', "w"),
+    ('\u{1d44b}', "x"),
+    ('\u{1d44c}', "y"),
+    ('\u{1d44d}', "z"),
+    ('\u{1d44e}', "a"),
+    ('\u{1d44f}', "b"),
+    ('\u{1d450}', "c"),
+    ('\u{1d451}', "d"),
+    ('\u{1d452}', "e"),
+    ('\u{1d453}', "f"),
+    ('\u{1d454}', "g"),
+    ('\u{1d456}', "i"),
+    ('\u{1d457}', "j"),
+    ('\u{1d458}', "k"),
+    ('\u{1d459}', "l"),
+    ('\u{1d45a}', "m"),
+    ('\u{1d45b}', "n"),
+    ('\u{1d45c}', "o"),
+    ('\u{1d45d}', "p"),
+    ('\u{1d45e}', "q"),
+    ('\u{1d45f}', "r"),
+    ('\u{1d460}', "s"),
+    ('\u{1d461}', "t"),
+    ('\u{1d462}', "u"),
+    ('\u{1d463}', "v"),
+    ('\u{1d464}', "w"),
+    ('\u{1d465}', "x"),
+    ('\u{1d466}', "y"),
+    ('\u{1d467}', "z"),
+    ('\u{1d468}', "a"),
+    ('\u{1d469}', "b"),
+    ('\u{1d46a}', "c"),
+    ('\u{1d46b}', "d"),
+    ('\u{1d46c}', "e"),
+    ('\u{1d46d}', "f"),
+    ('\u{1d46e}', "g"),
+    ('\u{1d46f}', "h"),
+    ('\u{1d470}', "i"),
+    ('\u{1d471}', "j"),
+    ('\u{1d472}', "k"),
+    ('\u{1d473}', "l"),
+    ('\u{1d474}', "m"),
+    ('\u{1d475}', "n"),
+    ('\u{1d476}', "o"),
+    ('\u{1d477}', "p"),
+    ('\u{1d478}', "q"),
+    ('\u{1d479}', "r"),
+    ('\u{1d47a}', "s"),
+    ('\u{1d47b}', "t"),
+    ('\u{1d47c}', "u"),
+    ('\u{1d47d}', "v"),
+    ('\u{1d47e}', "w"),
+    ('\u{1d47f}', "x"),
+    ('\u{1d480}', "y"),
+    ('\u{1d481}', "z"),
+    ('\u{1d482}', "a"),
+    ('\u{1d483}', "b"),
+    ('\u{1d484}', "c"),
+    ('\u{1d485}', "d"),
+    ('\u{1d486}', "e"),
+    ('\u{1d487}', "f"),
+    ('\u{1d488}', "g"),
+    ('\u{1d489}', "h"),
+    ('\u{1d48a}', "i"),
+    ('\u{1d48b}', "j"),
+    ('\u{1d48c}', "k"),
+    ('\u{1d48d}', "l"),
+    ('\u{1d48e}', "m"),
+    ('\u{1d48f}', "n"),
+    ('\u{1d490}', "o"),
+    ('\u{1d491}', "p"),
+    ('\u{1d492}', "q"),
+    ('\u{1d493}', "r"),
+    ('\u{1d494}', "s"),
+    ('\u{1d495}', "t"),
+    ('\u{1d496}', "u"),
+    ('\u{1d497}', "v"),
+    ('\u{1d498}', "w"),
+    ('\u{1d499}', "x"),
+    ('\u{1d49a}', "y"),
+    ('\u{1d49b}', "z"),
+    ('\u{1d49c}', "a"),
+    ('\u{1d49e}', "c"),
+    ('\u{1d49f}', "d"),
+    ('\u{1d4a2}', "g"),
+    ('\u{1d4a5}', "j"),
+    ('\u{1d4a6}', "k"),
+    ('\u{1d4a9}', "n"),
+    ('\u{1d4aa}', "o"),
+    ('\u{1d4ab}', "p"),
+    ('\u{1d4ac}', "q"),
+    ('\u{1d4ae}', "s"),
+    ('\u{1d4af}', "t"),
+    ('\u{1d4b0}', "u"),
+    ('\u{1d4b1}', "v"),
+    ('\u{1d4b2}', "w"),
+    ('\u{1d4b3}', "x"),
+    ('\u{1d4b4}', "y"),
+    ('\u{1d4b5}', "z"),
+    ('\u{1d4b6}', "a"),
+    ('\u{1d4b7}', "b"),
+    ('\u{1d4b8}', "c"),
+    ('\u{1d4b9}', "d"),
+    ('\u{1d4bb}', "f"),
+    ('\u{1d4bd}', "h"),
+    ('\u{1d4be}', "i"),
+    ('\u{1d4bf}', "j"),
+    ('\u{1d4c0}', "k"),
+    ('\u{1d4c1}', "l"),
+    ('\u{1d4c2}', "m"),
+    ('\u{1d4c3}', "n"),
+    ('\u{1d4c5}', "p"),
+    ('\u{1d4c6}', "q"),
+    ('\u{1d4c7}', "r"),
+    ('\u{1d4c8}', "s"),
+    ('\u{1d4c9}', "t"),
+    ('\u{1d4ca}', "u"),
+    ('\u{1d4cb}', "v"),
+    ('\u{1d4cc}', "w"),
+    ('\u{1d4cd}', "x"),
+    ('\u{1d4ce}', "y"),
+    ('\u{1d4cf}', "z"),
+    ('\u{1d4d0}', "a"),
+    ('\u{1d4d1}', "b"),
+    ('\u{1d4d2}', "c"),
+    ('\u{1d4d3}', "d"),
+    ('\u{1d4d4}', "e"),
+    ('\u{1d4d5}', "f"),
+    ('\u{1d4d6}', "g"),
+    ('\u{1d4d7}', "h"),
+    ('\u{1d4d8}', "i"),
+    ('\u{1d4d9}', "j"),
+    ('\u{1d4da}', "k"),
+    ('\u{1d4db}', "l"),
+    ('\u{1d4dc}', "m"),
+    ('\u{1d4dd}', "n"),
+    ('\u{1d4de}', "o"),
+    ('\u{1d4df}', "p"),
+    ('\u{1d4e0}', "q"),
+    ('\u{1d4e1}', "r"),
+    ('\u{1d4e2}', "s"),
+    ('\u{1d4e3}', "t"),
+    ('\u{1d4e4}', "u"),
+    ('\u{1d4e5}', "v"),
+    ('\u{1d4e6}', "w"),
+    ('\u{1d4e7}', "x"),
+    ('\u{1d4e8}', "y"),
+    ('\u{1d4e9}', "z"),
+    ('\u{1d4ea}', "a"),
+    ('\u{1d4eb}', "b"),
+    ('\u{1d4ec}', "c"),
+    ('\u{1d4ed}', "d"),
+    ('\u{1d4ee}', "e"),
+    ('\u{1d4ef}', "f"),
+    ('\u{1d4f0}', "g"),
+    ('\u{1d4f1}', "h"),
+    ('\u{1d4f2}', "i"),
+    ('\u{1d4f3}', "j"),
+    ('\u{1d4f4}', "k"),
+    ('\u{1d4f5}', "l"),
+    ('\u{1d4f6}', "m"),
+    ('\u{1d4f7}', "n"),
+    ('\u{1d4f8}', "o"),
+    ('\u{1d4f9}', "p"),
+    ('\u{1d4fa}', "q"),
+    ('\u{1d4fb}', "r"),
+    ('\u{1d4fc}', "s"),
+    ('\u{1d4fd}', "t"),
+    ('\u{1d4fe}', "u"),
+    ('\u{1d4ff}', "v"),
+    ('\u{1d500}', "w"),
+    ('\u{1d501}', "x"),
+    ('\u{1d502}', "y"),
+    ('\u{1d503}', "z"),
+    ('\u{1d504}', "a"),
+    ('\u{1d505}', "b"),
+    ('\u{1d507}', "d"),
+    ('\u{1d508}', "e"),
+    ('\u{1d509}', "f"),
+    ('\u{1d50a}', "g"),
+    ('\u{1d50d}', "j"),
+    ('\u{1d50e}', "k"),
+    ('\u{1d50f}', "l"),
+    ('\u{1d510}', "m"),
+    ('\u{1d511}', "n"),
+    ('\u{1d512}', "o"),
+    ('\u{1d513}', "p"),
+    ('\u{1d514}', "q"),
+    ('\u{1d516}', "s"),
+    ('\u{1d517}', "t"),
+    ('\u{1d518}', "u"),
+    ('\u{1d519}', "v"),
+    ('\u{1d51a}', "w"),
+    ('\u{1d51b}', "x"),
+    ('\u{1d51c}', "y"),
+    ('\u{1d51e}', "a"),
+    ('\u{1d51f}', "b"),
+    ('\u{1d520}', "c"),
+    ('\u{1d521}', "d"),
+    ('\u{1d522}', "e"),
+    ('\u{1d523}', "f"),
+    ('\u{1d524}', "g"),
+    ('\u{1d525}', "h"),
+    ('\u{1d526}', "i"),
+    ('\u{1d527}', "j"),
+    ('\u{1d528}', "k"),
+    ('\u{1d529}', "l"),
+    ('\u{1d52a}', "m"),
+    ('\u{1d52b}', "n"),
+    ('\u{1d52c}', "o"),
+    ('\u{1d52d}', "p"),
+    ('\u{1d52e}', "q"),
+    ('\u{1d52f}', "r"),
+    ('\u{1d530}', "s"),
+    ('\u{1d531}', "t"),
+    ('\u{1d532}', "u"),
+    ('\u{1d533}', "v"),
+    ('\u{1d534}', "w"),
+    ('\u{1d535}', "x"),
+    ('\u{1d536}', "y"),
+    ('\u{1d537}', "z"),
+    ('\u{1d538}', "a"),
+    ('\u{1d539}', "b"),
+    ('\u{1d53b}', "d"),
+    ('\u{1d53c}', "e"),
+    ('\u{1d53d}', "f"),
+    ('\u{1d53e}', "g"),
+    ('\u{1d540}', "i"),
+    ('\u{1d541}', "j"),
+    ('\u{1d542}', "k"),
+    ('\u{1d543}', "l"),
+    ('\u{1d544}', "m"),
+    ('\u{1d546}', "o"),
+    ('\u{1d54a}', "s"),
+    ('\u{1d54b}', "t"),
+    ('\u{1d54c}', "u"),
+    ('\u{1d54d}', "v"),
+    ('\u{1d54e}', "w"),
+    ('\u{1d54f}', "x"),
+    ('\u{1d550}', "y"),
+    ('\u{1d552}', "a"),
+    ('\u{1d553}', "b"),
+    ('\u{1d554}', "c"),
+    ('\u{1d555}', "d"),
+    ('\u{1d556}', "e"),
+    ('\u{1d557}', "f"),
+    ('\u{1d558}', "g"),
+    ('\u{1d559}', "h"),
+    ('\u{1d55a}', "i"),
+    ('\u{1d55b}', "j"),
+    ('\u{1d55c}', "k"),
+    ('\u{1d55d}', "l"),
+    ('\u{1d55e}', "m"),
+    ('\u{1d55f}', "n"),
+    ('\u{1d560}', "o"),
+    ('\u{1d561}', "p"),
+    ('\u{1d562}', "q"),
+    ('\u{1d563}', "r"),
+    ('\u{1d564}', "s"),
+    ('\u{1d565}', "t"),
+    ('\u{1d566}', "u"),
+    ('\u{1d567}', "v"),
+    ('\u{1d568}', "w"),
+    ('\u{1d569}', "x"),
+    ('\u{1d56a}', "y"),
+    ('\u{1d56b}', "z"),
+    ('\u{1d56c}', "a"),
+    ('\u{1d56d}', "b"),
+    ('\u{1d56e}', "c"),
+    ('\u{1d56f}', "d"),
+    ('\u{1d570}', "e"),
+    ('\u{1d571}', "f"),
+    ('\u{1d572}', "g"),
+    ('\u{1d573}', "h"),
+    ('\u{1d574}', "i"),
+    ('\u{1d575}', "j"),
+    ('\u{1d576}', "k"),
+    ('\u{1d577}', "l"),
+    ('\u{1d578}', "m"),
+    ('\u{1d579}', "n"),
+    ('\u{1d57a}', "o"),
+    ('\u{1d57b}', "p"),
+    ('\u{1d57c}', "q"),
+    ('\u{1d57d}', "r"),
+    ('\u{1d57e}', "s"),
+    ('\u{1d57f}', "t"),
+    ('\u{1d580}', "u"),
+    ('\u{1d581}', "v"),
+    ('\u{1d582}', "w"),
+    ('\u{1d583}', "x"),
+    ('\u{1d584}', "y"),
+    ('\u{1d585}', "z"),
+    ('\u{1d586}', "a"),
+    ('\u{1d587}', "b"),
+    ('\u{1d588}', "c"),
+    ('\u{1d589}', "d"),
+    ('\u{1d58a}', "e"),
+    ('\u{1d58b}', "f"),
+    ('\u{1d58c}', "g"),
+    ('\u{1d58d}', "h"),
+    ('\u{1d58e}', "i"),
+    ('\u{1d58f}', "j"),
+    ('\u{1d590}', "k"),
+    ('\u{1d591}', "l"),
+    ('\u{1d592}', "m"),
+    ('\u{1d593}', "n"),
+    ('\u{1d594}', "o"),
+    ('\u{1d595}', "p"),
+    ('\u{1d596}', "q"),
+    ('\u{1d597}', "r"),
+    ('\u{1d598}', "s"),
+    ('\u{1d599}', "t"),
+    ('\u{1d59a}', "u"),
+    ('\u{1d59b}', "v"),
+    ('\u{1d59c}', "w"),
+    ('\u{1d59d}', "x"),
+    ('\u{1d59e}', "y"),
+    ('\u{1d59f}', "z"),
+    ('\u{1d5a0}', "a"),
+    ('\u{1d5a1}', "b"),
+    ('\u{1d5a2}', "c"),
+    ('\u{1d5a3}', "d"),
+    ('\u{1d5a4}', "e"),
+    ('\u{1d5a5}', "f"),
+    ('\u{1d5a6}', "g"),
+    ('\u{1d5a7}', "h"),
+    ('\u{1d5a8}', "i"),
+    ('\u{1d5a9}', "j"),
+    ('\u{1d5aa}', "k"),
+    ('\u{1d5ab}', "l"),
+    ('\u{1d5ac}', "m"),
+    ('\u{1d5ad}', "n"),
+    ('\u{1d5ae}', "o"),
+    ('\u{1d5af}', "p"),
+    ('\u{1d5b0}', "q"),
+    ('\u{1d5b1}', "r"),
+    ('\u{1d5b2}', "s"),
+    ('\u{1d5b3}', "t"),
+    ('\u{1d5b4}', "u"),
+    ('\u{1d5b5}', "v"),
+    ('\u{1d5b6}', "w"),
+    ('\u{1d5b7}', "x"),
+    ('\u{1d5b8}', "y"),
+    ('\u{1d5b9}', "z"),
+    ('\u{1d5ba}', "a"),
+    ('\u{1d5bb}', "b"),
+    ('\u{1d5bc}', "c"),
+    ('\u{1d5bd}', "d"),
+    ('\u{1d5be}', "e"),
+    ('\u{1d5bf}', "f"),
+    ('\u{1d5c0}', "g"),
+    ('\u{1d5c1}', "h"),
+    ('\u{1d5c2}', "i"),
+    ('\u{1d5c3}', "j"),
+    ('\u{1d5c4}', "k"),
+    ('\u{1d5c5}', "l"),
+    ('\u{1d5c6}', "m"),
+    ('\u{1d5c7}', "n"),
+    ('\u{1d5c8}', "o"),
+    ('\u{1d5c9}', "p"),
+    ('\u{1d5ca}', "q"),
+    ('\u{1d5cb}', "r"),
+    ('\u{1d5cc}', "s"),
+    ('\u{1d5cd}', "t"),
+    ('\u{1d5ce}', "u"),
+    ('\u{1d5cf}', "v"),
+    ('\u{1d5d0}', "w"),
+    ('\u{1d5d1}', "x"),
+    ('\u{1d5d2}', "y"),
+    ('\u{1d5d3}', "z"),
+    ('\u{1d5d4}', "a"),
+    ('\u{1d5d5}', "b"),
+    ('\u{1d5d6}', "c"),
+    ('\u{1d5d7}', "d"),
+    ('\u{1d5d8}', "e"),
+    ('\u{1d5d9}', "f"),
+    ('\u{1d5da}', "g"),
+    ('\u{1d5db}', "h"),
+    ('\u{1d5dc}', "i"),
+    ('\u{1d5dd}', "j"),
+    ('\u{1d5de}', "k"),
+    ('\u{1d5df}', "l"),
+    ('\u{1d5e0}', "m"),
+    ('\u{1d5e1}', "n"),
+    ('\u{1d5e2}', "o"),
+    ('\u{1d5e3}', "p"),
+    ('\u{1d5e4}', "q"),
+    ('\u{1d5e5}', "r"),
+    ('\u{1d5e6}', "s"),
+    ('\u{1d5e7}', "t"),
+    ('\u{1d5e8}', "u"),
+    ('\u{1d5e9}', "v"),
+    ('\u{1d5ea}', "w"),
+    ('\u{1d5eb}', "x"),
+    ('\u{1d5ec}', "y"),
+    ('\u{1d5ed}', "z"),
+    ('\u{1d5ee}', "a"),
+    ('\u{1d5ef}', "b"),
+    ('\u{1d5f0}', "c"),
+    ('\u{1d5f1}', "d"),
+    ('\u{1d5f2}', "e"),
+    ('\u{1d5f3}', "f"),
+    ('\u{1d5f4}', "g"),
+    ('\u{1d5f5}', "h"),
+    ('\u{1d5f6}', "i"),
+    ('\u{1d5f7}', "j"),
+    ('\u{1d5f8}', "k"),
+    ('\u{1d5f9}', "l"),
+    ('\u{1d5fa}', "m"),
+    ('\u{1d5fb}', "n"),
+    ('\u{1d5fc}', "o"),
+    ('\u{1d5fd}', "p"),
+    ('\u{1d5fe}', "q"),
+    ('\u{1d5ff}', "r"),
+    ('\u{1d600}', "s"),
+    ('\u{1d601}', "t"),
+    ('\u{1d602}', "u"),
+    ('\u{1d603}', "v"),
+    ('\u{1d604}', "w"),
+    ('\u{1d605}', "x"),
+    ('\u{1d606}', "y"),
+    ('\u{1d607}', "z"),
+    ('\u{1d608}', "a"),
+    ('\u{1d609}', "b"),
+    ('\u{1d60a}', "c"),
+    ('\u{1d60b}', "d"),
+    ('\u{1d60c}', "e"),
+    ('\u{1d60d}', "f"),
+    ('\u{1d60e}', "g"),
+    ('\u{1d60f}', "h"),
+    ('\u{1d610}', "i"),
+    ('\u{1d611}', "j"),
+    ('\u{1d612}', "k"),
+    ('\u{1d613}', "l"),
+    ('\u{1d614}', "m"),
+    ('\u{1d615}', "n"),
+    ('\u{1d616}', "o"),
+    ('\u{1d617}', "p"),
+    ('\u{1d618}', "q"),
+    ('\u{1d619}', "r"),
+    ('\u{1d61a}', "s"),
+    ('\u{1d61b}', "t"),
+    ('\u{1d61c}', "u"),
+    ('\u{1d61d}', "v"),
+    ('\u{1d61e}', "w"),
+    ('\u{1d61f}', "x"),
+    ('\u{1d620}', "y"),
+    ('\u{1d621}', "z"),
+    ('\u{1d622}', "a"),
+    ('\u{1d623}', "b"),
+    ('\u{1d624}', "c"),
+    ('\u{1d625}', "d"),
+    ('\u{1d626}', "e"),
+    ('\u{1d627}', "f"),
+    ('\u{1d628}', "g"),
+    ('\u{1d629}', "h"),
+    ('\u{1d62a}', "i"),
+    ('\u{1d62b}', "j"),
+    ('\u{1d62c}', "k"),
+    ('\u{1d62d}', "l"),
+    ('\u{1d62e}', "m"),
+    ('\u{1d62f}', "n"),
+    ('\u{1d630}', "o"),
+    ('\u{1d631}', "p"),
+    ('\u{1d632}', "q"),
+    ('\u{1d633}', "r"),
+    ('\u{1d634}', "s"),
+    ('\u{1d635}', "t"),
+    ('\u{1d636}', "u"),
+    ('\u{1d637}', "v"),
+    ('\u{1d638}', "w"),
+    ('\u{1d639}', "x"),
+    ('\u{1d63a}', "y"),
+    ('\u{1d63b}', "z"),
+    ('\u{1d63c}', "a"),
+    ('\u{1d63d}', "b"),
+    ('\u{1d63e}', "c"),
+    ('\u{1d63f}', "d"),
+    ('\u{1d640}', "e"),
+    ('\u{1d641}', "f"),
+    ('\u{1d642}', "g"),
+    ('\u{1d643}', "h"),
+    ('\u{1d644}', "i"),
+    ('\u{1d645}', "j"),
+    ('\u{1d646}', "k"),
+    ('\u{1d647}', "l"),
+    ('\u{1d648}', "m"),
+    ('\u{1d649}', "n"),
+    ('\u{1d64a}', "o"),
+    ('\u{1d64b}', "p"),
+    ('\u{1d64c}', "q"),
+    ('\u{1d64d}', "r"),
+    ('\u{1d64e}', "s"),
+    ('\u{1d64f}', "t"),
+    ('\u{1d650}', "u"),
+    ('\u{1d651}', "v"),
+    ('\u{1d652}', "w"),
+    ('\u{1d653}', "x"),
+    ('\u{1d654}', "y"),
+    ('\u{1d655}', "z"),
+    ('\u{1d656}', "a"),
+    ('\u{1d657}', "b"),
+    ('\u{1d658}', "c"),
+    ('\u{1d659}', "d"),
+    ('\u{1d65a}', "e"),
+    ('\u{1d65b}', "f"),
+    ('\u{1d65c}', "g"),
+    ('\u{1d65d}', "h"),
+    ('\u{1d65e}', "i"),
+    ('\u{1d65f}', "j"),
+    ('\u{1d660}', "k"),
+    ('\u{1d661}', "l"),
+    ('\u{1d662}', "m"),
+    ('\u{1d663}', "n"),
+    ('\u{1d664}', "o"),
+    ('\u{1d665}', "p"),
+    ('\u{1d666}', "q"),
+    ('\u{1d667}', "r"),
+    ('\u{1d668}', "s"),
+    ('\u{1d669}', "t"),
+    ('\u{1d66a}', "u"),
+    ('\u{1d66b}', "v"),
+    ('\u{1d66c}', "w"),
+    ('\u{1d66d}', "x"),
+    ('\u{1d66e}', "y"),
+    ('\u{1d66f}', "z"),
+    ('\u{1d670}', "a"),
+    ('\u{1d671}', "b"),
+    ('\u{1d672}', "c"),
+    ('\u{1d673}', "d"),
+    ('\u{1d674}', "e"),
+    ('\u{1d675}', "f"),
+    ('\u{1d676}', "g"),
+    ('\u{1d677}', "h"),
+    ('\u{1d678}', "i"),
+    ('\u{1d679}', "j"),
+    ('\u{1d67a}', "k"),
+    ('\u{1d67b}', "l"),
+    ('\u{1d67c}', "m"),
+    ('\u{1d67d}', "n"),
+    ('\u{1d67e}', "o"),
+    ('\u{1d67f}', "p"),
+    ('\u{1d680}', "q"),
+    ('\u{1d681}', "r"),
+    ('\u{1d682}', "s"),
+    ('\u{1d683}', "t"),
+    ('\u{1d684}', "u"),
+    ('\u{1d685}', "v"),
+    ('\u{1d686}', "w"),
+    ('\u{1d687}', "x"),
+    ('\u{1d688}', "y"),
+    ('\u{1d689}', "z"),
+    ('\u{1d68a}', "a"),
+    ('\u{1d68b}', "b"),
+    ('\u{1d68c}', "c"),
+    ('\u{1d68d}', "d"),
+    ('\u{1d68e}', "e"),
+    ('\u{1d68f}', "f"),
+    ('\u{1d690}', "g"),
+    ('\u{1d691}', "h"),
+    ('\u{1d692}', "i"),
+    ('\u{1d693}', "j"),
+    ('\u{1d694}', "k"),
+    ('\u{1d695}', "l"),
+    ('\u{1d696}', "m"),
+    ('\u{1d697}', "n"),
+    ('\u{1d698}', "o"),
+    ('\u{1d699}', "p"),
+    ('\u{1d69a}', "q"),
+    ('\u{1d69b}', "r"),
+    ('\u{1d69c}', "s"),
+    ('\u{1d69d}', "t"),
+    ('\u{1d69e}', "u"),
+    ('\u{1d69f}', "v"),
+    ('\u{1d6a0}', "w"),
+    ('\u{1d6a1}', "x"),
+    ('\u{1d6a2}', "y"),
+    ('\u{1d6a3}', "z"),
+    ('\u{1d6a4}', "\u{0131}"),
+    ('\u{1d6a5}', "\u{0237}"),
+    ('\u{1d6a8}', "\u{03b1}"),
+    ('\u{1d6a9}', "\u{03b2}"),
+    ('\u{1d6aa}', "\u{03b3}"),
+    ('\u{1d6ab}', "\u{03b4}"),
+    ('\u{1d6ac}', "\u{03b5}"),
+    ('\u{1d6ad}', "\u{03b6}"),
+    ('\u{1d6ae}', "\u{03b7}"),
+    ('\u{1d6af}', "\u{03b8}"),
+    ('\u{1d6b0}', "\u{03b9}"),
+    ('\u{1d6b1}', "\u{03ba}"),
+    ('\u{1d6b2}', "\u{03bb}"),
+    ('\u{1d6b3}', "\u{03bc}"),
+    ('\u{1d6b4}', "\u{03bd}"),
+    ('\u{1d6b5}', "\u{03be}"),
+    ('\u{1d6b6}', "\u{03bf}"),
+    ('\u{1d6b7}', "\u{03c0}"),
+    ('\u{1d6b8}', "\u{03c1}"),
+    ('\u{1d6b9}', "\u{03b8}"),
+    ('\u{1d6ba}', "\u{03c3}"),
+    ('\u{1d6bb}', "\u{03c4}"),
+    ('\u{1d6bc}', "\u{03c5}"),
+    ('\u{1d6bd}', "\u{03c6}"),
+    ('\u{1d6be}', "\u{03c7}"),
+    ('\u{1d6bf}', "\u{03c8}"),
+    ('\u{1d6c0}', "\u{03c9}"),
+    ('\u{1d6c1}', "\u{2207}"),
+    ('\u{1d6c2}', "\u{03b1}"),
+    ('\u{1d6c3}', "\u{03b2}"),
+    ('\u{1d6c4}', "\u{03b3}"),
+    ('\u{1d6c5}', "\u{03b4}"),
+    ('\u{1d6c6}', "\u{03b5}"),
+    ('\u{1d6c7}', "\u{03b6}"),
+    ('\u{1d6c8}', "\u{03b7}"),
+    ('\u{1d6c9}', "\u{03b8}"),
+    ('\u{1d6ca}', "\u{03b9}"),
+    ('\u{1d6cb}', "\u{03ba}"),
+    ('\u{1d6cc}', "\u{03bb}"),
+    ('\u{1d6cd}', "\u{03bc}"),
+    ('\u{1d6ce}', "\u{03bd}"),
+    ('\u{1d6cf}', "\u{03be}"),
+    ('\u{1d6d0}', "\u{03bf}"),
+    ('\u{1d6d1}', "\u{03c0}"),
+    ('\u{1d6d2}', "\u{03c1}"),
+    ('\u{1d6d3}', "\u{03c3}"),
+    ('\u{1d6d4}', "\u{03c3}"),
+    ('\u{1d6d5}', "\u{03c4}"),
+    ('\u{1d6d6}', "\u{03c5}"),
+    ('\u{1d6d7}', "\u{03c6}"),
+    ('\u{1d6d8}', "\u{03c7}"),
+    ('\u{1d6d9}', "\u{03c8}"),
+    ('\u{1d6da}', "\u{03c9}"),
+    ('\u{1d6db}', "\u{2202}"),
+    ('\u{1d6dc}', "\u{03b5}"),
+    ('\u{1d6dd}', "\u{03b8}"),
+    ('\u{1d6de}', "\u{03ba}"),
+    ('\u{1d6df}', "\u{03c6}"),
+    ('\u{1d6e0}', "\u{03c1}"),
+    ('\u{1d6e1}', "\u{03c0}"),
+    ('\u{1d6e2}', "\u{03b1}"),
+    ('\u{1d6e3}', "\u{03b2}"),
+    ('\u{1d6e4}', "\u{03b3}"),
+    ('\u{1d6e5}', "\u{03b4}"),
+    ('\u{1d6e6}', "\u{03b5}"),
+    ('\u{1d6e7}', "\u{03b6}"),
+    ('\u{1d6e8}', "\u{03b7}"),
+    ('\u{1d6e9}', "\u{03b8}"),
+    ('\u{1d6ea}', "\u{03b9}"),
+    ('\u{1d6eb}', "\u{03ba}"),
+    ('\u{1d6ec}', "\u{03bb}"),
+    ('\u{1d6ed}', "\u{03bc}"),
+    ('\u{1d6ee}', "\u{03bd}"),
+    ('\u{1d6ef}', "\u{03be}"),
+    ('\u{1d6f0}', "\u{03bf}"),
+    ('\u{1d6f1}', "\u{03c0}"),
+    ('\u{1d6f2}', "\u{03c1}"),
+    ('\u{1d6f3}', "\u{03b8}"),
+    ('\u{1d6f4}', "\u{03c3}"),
+    ('\u{1d6f5}', "\u{03c4}"),
+    ('\u{1d6f6}', "\u{03c5}"),
+    ('\u{1d6f7}', "\u{03c6}"),
+    ('\u{1d6f8}', "\u{03c7}"),
+    ('\u{1d6f9}', "\u{03c8}"),
+    ('\u{1d6fa}', "\u{03c9}"),
+    ('\u{1d6fb}', "\u{2207}"),
+    ('\u{1d6fc}', "\u{03b1}"),
+    ('\u{1d6fd}', "\u{03b2}"),
+    ('\u{1d6fe}', "\u{03b3}"),
+    ('\u{1d6ff}', "\u{03b4}"),
+    ('\u{1d700}', "\u{03b5}"),
+    ('\u{1d701}', "\u{03b6}"),
+    ('\u{1d702}', "\u{03b7}"),
+    ('\u{1d703}', "\u{03b8}"),
+    ('\u{1d704}', "\u{03b9}"),
+    ('\u{1d705}', "\u{03ba}"),
+    ('\u{1d706}', "\u{03bb}"),
+    ('\u{1d707}', "\u{03bc}"),
+    ('\u{1d708}', "\u{03bd}"),
+    ('\u{1d709}', "\u{03be}"),
+    ('\u{1d70a}', "\u{03bf}"),
+    ('\u{1d70b}', "\u{03c0}"),
+    ('\u{1d70c}', "\u{03c1}"),
+    ('\u{1d70d}', "\u{03c3}"),
+    ('\u{1d70e}', "\u{03c3}"),
+    ('\u{1d70f}', "\u{03c4}"),
+    ('\u{1d710}', "\u{03c5}"),
+    ('\u{1d711}', "\u{03c6}"),
+    ('\u{1d712}', "\u{03c7}"),
+    ('\u{1d713}', "\u{03c8}"),
+    ('\u{1d714}', "\u{03c9}"),
+    ('\u{1d715}', "\u{2202}"),
+    ('\u{1d716}', "\u{03b5}"),
+    ('\u{1d717}', "\u{03b8}"),
+    ('\u{1d718}', "\u{03ba}"),
+    ('\u{1d719}', "\u{03c6}"),
+    ('\u{1d71a}', "\u{03c1}"),
+    ('\u{1d71b}', "\u{03c0}"),
+    ('\u{1d71c}', "\u{03b1}"),
+    ('\u{1d71d}', "\u{03b2}"),
+    ('\u{1d71e}', "\u{03b3}"),
+    ('\u{1d71f}', "\u{03b4}"),
+    ('\u{1d720}', "\u{03b5}"),
+    ('\u{1d721}', "\u{03b6}"),
+    ('\u{1d722}', "\u{03b7}"),
+    ('\u{1d723}', "\u{03b8}"),
+    ('\u{1d724}', "\u{03b9}"),
+    ('\u{1d725}', "\u{03ba}"),
+    ('\u{1d726}', "\u{03bb}"),
+    ('\u{1d727}', "\u{03bc}"),
+    ('\u{1d728}', "\u{03bd}"),
+    ('\u{1d729}', "\u{03be}"),
+    ('\u{1d72a}', "\u{03bf}"),
+    ('\u{1d72b}', "\u{03c0}"),
+    ('\u{1d72c}', "\u{03c1}"),
+    ('\u{1d72d}', "\u{03b8}"),
+    ('\u{1d72e}', "\u{03c3}"),
+    ('\u{1d72f}', "\u{03c4}"),
+    ('\u{1d730}', "\u{03c5}"),
+    ('\u{1d731}', "\u{03c6}"),
+    ('\u{1d732}', "\u{03c7}"),
+    ('\u{1d733}', "\u{03c8}"),
+    ('\u{1d734}', "\u{03c9}"),
+    ('\u{1d735}', "\u{2207}"),
+    ('\u{1d736}', "\u{03b1}"),
+    ('\u{1d737}', "\u{03b2}"),
+    ('\u{1d738}', "\u{03b3}"),
+    ('\u{1d739}', "\u{03b4}"),
+    ('\u{1d73a}', "\u{03b5}"),
+    ('\u{1d73b}', "\u{03b6}"),
+    ('\u{1d73c}', "\u{03b7}"),
+    ('\u{1d73d}', "\u{03b8}"),
+    ('\u{1d73e}', "\u{03b9}"),
+    ('\u{1d73f}', "\u{03ba}"),
+    ('\u{1d740}', "\u{03bb}"),
+    ('\u{1d741}', "\u{03bc}"),
+    ('\u{1d742}', "\u{03bd}"),
+    ('\u{1d743}', "\u{03be}"),
+    ('\u{1d744}', "\u{03bf}"),
+    ('\u{1d745}', "\u{03c0}"),
+    ('\u{1d746}', "\u{03c1}"),
+    ('\u{1d747}', "\u{03c3}"),
+    ('\u{1d748}', "\u{03c3}"),
+    ('\u{1d749}', "\u{03c4}"),
+    ('\u{1d74a}', "\u{03c5}"),
+    ('\u{1d74b}', "\u{03c6}"),
+    ('\u{1d74c}', "\u{03c7}"),
+    ('\u{1d74d}', "\u{03c8}"),
+    ('\u{1d74e}', "\u{03c9}"),
+    ('\u{1d74f}', "\u{2202}"),
+    ('\u{1d750}', "\u{03b5}"),
+    ('\u{1d751}', "\u{03b8}"),
+    ('\u{1d752}', "\u{03ba}"),
+    ('\u{1d753}', "\u{03c6}"),
+    ('\u{1d754}', "\u{03c1}"),
+    ('\u{1d755}', "\u{03c0}"),
+    ('\u{1d756}', "\u{03b1}"),
+    ('\u{1d757}', "\u{03b2}"),
+    ('\u{1d758}', "\u{03b3}"),
+    ('\u{1d759}', "\u{03b4}"),
+    ('\u{1d75a}', "\u{03b5}"),
+    ('\u{1d75b}', "\u{03b6}"),
+    ('\u{1d75c}', "\u{03b7}"),
+    ('\u{1d75d}', "\u{03b8}"),
+    ('\u{1d75e}', "\u{03b9}"),
+    ('\u{1d75f}', "\u{03ba}"),
+    ('\u{1d760}', "\u{03bb}"),
+    ('\u{1d761}', "\u{03bc}"),
+    ('\u{1d762}', "\u{03bd}"),
+    ('\u{1d763}', "\u{03be}"),
+    ('\u{1d764}', "\u{03bf}"),
+    ('\u{1d765}', "\u{03c0}"),
+    ('\u{1d766}', "\u{03c1}"),
+    ('\u{1d767}', "\u{03b8}"),
+    ('\u{1d768}', "\u{03c3}"),
+    ('\u{1d769}', "\u{03c4}"),
+    ('\u{1d76a}', "\u{03c5}"),
+    ('\u{1d76b}', "\u{03c6}"),
+    ('\u{1d76c}', "\u{03c7}"),
+    ('\u{1d76d}', "\u{03c8}"),
+    ('\u{1d76e}', "\u{03c9}"),
+    ('\u{1d76f}', "\u{2207}"),
+    ('\u{1d770}', "\u{03b1}"),
+    ('\u{1d771}', "\u{03b2}"),
+    ('\u{1d772}', "\u{03b3}"),
+    ('\u{1d773}', "\u{03b4}"),
+    ('\u{1d774}', "\u{03b5}"),
+    ('\u{1d775}', "\u{03b6}"),
+    ('\u{1d776}', "\u{03b7}"),
+    ('\u{1d777}', "\u{03b8}"),
+    ('\u{1d778}', "\u{03b9}"),
+    ('\u{1d779}', "\u{03ba}"),
+    ('\u{1d77a}', "\u{03bb}"),
+    ('\u{1d77b}', "\u{03bc}"),
+    ('\u{1d77c}', "\u{03bd}"),
+    ('\u{1d77d}', "\u{03be}"),
+    ('\u{1d77e}', "\u{03bf}"),
+    ('\u{1d77f}', "\u{03c0}"),
+    ('\u{1d780}', "\u{03c1}"),
+    ('\u{1d781}', "\u{03c3}"),
+    ('\u{1d782}', "\u{03c3}"),
+    ('\u{1d783}', "\u{03c4}"),
+    ('\u{1d784}', "\u{03c5}"),
+    ('\u{1d785}', "\u{03c6}"),
+    ('\u{1d786}', "\u{03c7}"),
+    ('\u{1d787}', "\u{03c8}"),
+    ('\u{1d788}', "\u{03c9}"),
+    ('\u{1d789}', "\u{2202}"),
+    ('\u{1d78a}', "\u{03b5}"),
+    ('\u{1d78b}', "\u{03b8}"),
+    ('\u{1d78c}', "\u{03ba}"),
+    ('\u{1d78d}', "\u{03c6}"),
+    ('\u{1d78e}', "\u{03c1}"),
+    ('\u{1d78f}', "\u{03c0}"),
+    ('\u{1d790}', "\u{03b1}"),
+    ('\u{1d791}', "\u{03b2}"),
+    ('\u{1d792}', "\u{03b3}"),
+    ('\u{1d793}', "\u{03b4}"),
+    ('\u{1d794}', "\u{03b5}"),
+    ('\u{1d795}', "\u{03b6}"),
+    ('\u{1d796}', "\u{03b7}"),
+    ('\u{1d797}', "\u{03b8}"),
+    ('\u{1d798}', "\u{03b9}"),
+    ('\u{1d799}', "\u{03ba}"),
+    ('\u{1d79a}', "\u{03bb}"),
+    ('\u{1d79b}', "\u{03bc}"),
+    ('\u{1d79c}', "\u{03bd}"),
+    ('\u{1d79d}', "\u{03be}"),
+    ('\u{1d79e}', "\u{03bf}"),
+    ('\u{1d79f}', "\u{03c0}"),
+    ('\u{1d7a0}', "\u{03c1}"),
+    ('\u{1d7a1}', "\u{03b8}"),
+    ('\u{1d7a2}', "\u{03c3}"),
+    ('\u{1d7a3}', "\u{03c4}"),
+    ('\u{1d7a4}', "\u{03c5}"),
+    ('\u{1d7a5}', "\u{03c6}"),
+    ('\u{1d7a6}', "\u{03c7}"),
+    ('\u{1d7a7}', "\u{03c8}"),
+    ('\u{1d7a8}', "\u{03c9}"),
+    ('\u{1d7a9}', "\u{2207}"),
+    ('\u{1d7aa}', "\u{03b1}"),
+    ('\u{1d7ab}', "\u{03b2}"),
+    ('\u{1d7ac}', "\u{03b3}"),
+    ('\u{1d7ad}', "\u{03b4}"),
+    ('\u{1d7ae}', "\u{03b5}"),
+    ('\u{1d7af}', "\u{03b6}"),
+    ('\u{1d7b0}', "\u{03b7}"),
+    ('\u{1d7b1}', "\u{03b8}"),
+    ('\u{1d7b2}', "\u{03b9}"),
+    ('\u{1d7b3}', "\u{03ba}"),
+    ('\u{1d7b4}', "\u{03bb}"),
+    ('\u{1d7b5}', "\u{03bc}"),
+    ('\u{1d7b6}', "\u{03bd}"),
+    ('\u{1d7b7}', "\u{03be}"),
+    ('\u{1d7b8}', "\u{03bf}"),
+    ('\u{1d7b9}', "\u{03c0}"),
+    ('\u{1d7ba}', "\u{03c1}"),
+    ('\u{1d7bb}', "\u{03c3}"),
+    ('\u{1d7bc}', "\u{03c3}"),
+    ('\u{1d7bd}', "\u{03c4}"),
+    ('\u{1d7be}', "\u{03c5}"),
+    ('\u{1d7bf}', "\u{03c6}"),
+    ('\u{1d7c0}', "\u{03c7}"),
+    ('\u{1d7c1}', "\u{03c8}"),
+    ('\u{1d7c2}', "\u{03c9}"),
+    ('\u{1d7c3}', "\u{2202}"),
+    ('\u{1d7c4}', "\u{03b5}"),
+    ('\u{1d7c5}', "\u{03b8}"),
+    ('\u{1d7c6}', "\u{03ba}"),
+    ('\u{1d7c7}', "\u{03c6}"),
+    ('\u{1d7c8}', "\u{03c1}"),
+    ('\u{1d7c9}', "\u{03c0}"),
+    ('\u{1d7ca}', "\u{03dd}"),
+    ('\u{1d7cb}', "\u{03dd}"),
+    ('\u{1d7ce}', "0"),
+    ('\u{1d7cf}', "1"),
+    ('\u{1d7d0}', "2"),
+    ('\u{1d7d1}', "3"),
+    ('\u{1d7d2}', "4"),
+    ('\u{1d7d3}', "5"),
+    ('\u{1d7d4}', "6"),
+    ('\u{1d7d5}', "7"),
+    ('\u{1d7d6}', "8"),
+    ('\u{1d7d7}', "9"),
+    ('\u{1d7d8}', "0"),
+    ('\u{1d7d9}', "1"),
+    ('\u{1d7da}', "2"),
+    ('\u{1d7db}', "3"),
+    ('\u{1d7dc}', "4"),
+    ('\u{1d7dd}', "5"),
+    ('\u{1d7de}', "6"),
+    ('\u{1d7df}', "7"),
+    ('\u{1d7e0}', "8"),
+    ('\u{1d7e1}', "9"),
+    ('\u{1d7e2}', "0"),
+    ('\u{1d7e3}', "1"),
+    ('\u{1d7e4}', "2"),
+    ('\u{1d7e5}', "3"),
+    ('\u{1d7e6}', "4"),
+    ('\u{1d7e7}', "5"),
+    ('\u{1d7e8}', "6"),
+    ('\u{1d7e9}', "7"),
+    ('\u{1d7ea}', "8"),
+    ('\u{1d7eb}', "9"),
+    ('\u{1d7ec}', "0"),
+    ('\u{1d7ed}', "1"),
+    ('\u{1d7ee}', "2"),
+    ('\u{1d7ef}', "3"),
+    ('\u{1d7f0}', "4"),
+    ('\u{1d7f1}', "5"),
+    ('\u{1d7f2}', "6"),
+    ('\u{1d7f3}', "7"),
+    ('\u{1d7f4}', "8"),
+    ('\u{1d7f5}', "9"),
+    ('\u{1d7f6}', "0"),
+    ('\u{1d7f7}', "1"),
+    ('\u{1d7f8}', "2"),
+    ('\u{1d7f9}', "3"),
+    ('\u{1d7fa}', "4"),
+    ('\u{1d7fb}', "5"),
+    ('\u{1d7fc}', "6"),
+    ('\u{1d7fd}', "7"),
+    ('\u{1d7fe}', "8"),
+    ('\u{1d7ff}', "9"),
+    ('\u{1e900}', "\u{1e922}"),
+    ('\u{1e901}', "\u{1e923}"),
+    ('\u{1e902}', "\u{1e924}"),
+    ('\u{1e903}', "\u{1e925}"),
+    ('\u{1e904}', "\u{1e926}"),
+    ('\u{1e905}', "\u{1e927}"),
+    ('\u{1e906}', "\u{1e928}"),
+    ('\u{1e907}', "\u{1e929}"),
+    ('\u{1e908}', "\u{1e92a}"),
+    ('\u{1e909}', "\u{1e92b}"),
+    ('\u{1e90a}', "\u{1e92c}"),
+    ('\u{1e90b}', "\u{1e92d}"),
+    ('\u{1e90c}', "\u{1e92e}"),
+    ('\u{1e90d}', "\u{1e92f}"),
+    ('\u{1e90e}', "\u{1e930}"),
+    ('\u{1e90f}', "\u{1e931}"),
+    ('\u{1e910}', "\u{1e932}"),
+    ('\u{1e911}', "\u{1e933}"),
+    ('\u{1e912}', "\u{1e934}"),
+    ('\u{1e913}', "\u{1e935}"),
+    ('\u{1e914}', "\u{1e936}"),
+    ('\u{1e915}', "\u{1e937}"),
+    ('\u{1e916}', "\u{1e938}"),
+    ('\u{1e917}', "\u{1e939}"),
+    ('\u{1e918}', "\u{1e93a}"),
+    ('\u{1e919}', "\u{1e93b}"),
+    ('\u{1e91a}', "\u{1e93c}"),
+    ('\u{1e91b}', "\u{1e93d}"),
+    ('\u{1e91c}', "\u{1e93e}"),
+    ('\u{1e91d}', "\u{1e93f}"),
+    ('\u{1e91e}', "\u{1e940}"),
+    ('\u{1e91f}', "\u{1e941}"),
+    ('\u{1e920}', "\u{1e942}"),
+    ('\u{1e921}', "\u{1e943}"),
+    ('\u{1ee00}', "\u{0627}"),
+    ('\u{1ee01}', "\u{0628}"),
+    ('\u{1ee02}', "\u{062c}"),
+    ('\u{1ee03}', "\u{062f}"),
+    ('\u{1ee05}', "\u{0648}"),
+    ('\u{1ee06}', "\u{0632}"),
+    ('\u{1ee07}', "\u{062d}"),
+    ('\u{1ee08}', "\u{0637}"),
+    ('\u{1ee09}', "\u{064a}"),
+    ('\u{1ee0a}', "\u{0643}"),
+    ('\u{1ee0b}', "\u{0644}"),
+    ('\u{1ee0c}', "\u{0645}"),
+    ('\u{1ee0d}', "\u{0646}"),
+    ('\u{1ee0e}', "\u{0633}"),
+    ('\u{1ee0f}', "\u{0639}"),
+    ('\u{1ee10}', "\u{0641}"),
+    ('\u{1ee11}', "\u{0635}"),
+    ('\u{1ee12}', "\u{0642}"),
+    ('\u{1ee13}', "\u{0631}"),
+    ('\u{1ee14}', "\u{0634}"),
+    ('\u{1ee15}', "\u{062a}"),
+    ('\u{1ee16}', "\u{062b}"),
+    ('\u{1ee17}', "\u{062e}"),
+    ('\u{1ee18}', "\u{0630}"),
+    ('\u{1ee19}', "\u{0636}"),
+    ('\u{1ee1a}', "\u{0638}"),
+    ('\u{1ee1b}', "\u{063a}"),
+    ('\u{1ee1c}', "\u{066e}"),
+    ('\u{1ee1d}', "\u{06ba}"),
+    ('\u{1ee1e}', "\u{06a1}"),
+    ('\u{1ee1f}', "\u{066f}"),
+    ('\u{1ee21}', "\u{0628}"),
+    ('\u{1ee22}', "\u{062c}"),
+    ('\u{1ee24}', "\u{0647}"),
+    ('\u{1ee27}', "\u{062d}"),
+    ('\u{1ee29}', "\u{064a}"),
+    ('\u{1ee2a}', "\u{0643}"),
+    ('\u{1ee2b}', "\u{0644}"),
+    ('\u{1ee2c}', "\u{0645}"),
+    ('\u{1ee2d}', "\u{0646}"),
+    ('\u{1ee2e}', "\u{0633}"),
+    ('\u{1ee2f}', "\u{0639}"),
+    ('\u{1ee30}', "\u{0641}"),
+    ('\u{1ee31}', "\u{0635}"),
+    ('\u{1ee32}', "\u{0642}"),
+    ('\u{1ee34}', "\u{0634}"),
+    ('\u{1ee35}', "\u{062a}"),
+    ('\u{1ee36}', "\u{062b}"),
+    ('\u{1ee37}', "\u{062e}"),
+    ('\u{1ee39}', "\u{0636}"),
+    ('\u{1ee3b}', "\u{063a}"),
+    ('\u{1ee42}', "\u{062c}"),
+    ('\u{1ee47}', "\u{062d}"),
+    ('\u{1ee49}', "\u{064a}"),
+    ('\u{1ee4b}', "\u{0644}"),
+    ('\u{1ee4d}', "\u{0646}"),
+    ('\u{1ee4e}', "\u{0633}"),
+    ('\u{1ee4f}', "\u{0639}"),
+    ('\u{1ee51}', "\u{0635}"),
+    ('\u{1ee52}', "\u{0642}"),
+    ('\u{1ee54}', "\u{0634}"),
+    ('\u{1ee57}', "\u{062e}"),
+    ('\u{1ee59}', "\u{0636}"),
+    ('\u{1ee5b}', "\u{063a}"),
+    ('\u{1ee5d}', "\u{06ba}"),
+    ('\u{1ee5f}', "\u{066f}"),
+    ('\u{1ee61}', "\u{0628}"),
+    ('\u{1ee62}', "\u{062c}"),
+    ('\u{1ee64}', "\u{0647}"),
+    ('\u{1ee67}', "\u{062d}"),
+    ('\u{1ee68}', "\u{0637}"),
+    ('\u{1ee69}', "\u{064a}"),
+    ('\u{1ee6a}', "\u{0643}"),
+    ('\u{1ee6c}', "\u{0645}"),
+    ('\u{1ee6d}', "\u{0646}"),
+    ('\u{1ee6e}', "\u{0633}"),
+    ('\u{1ee6f}', "\u{0639}"),
+    ('\u{1ee70}', "\u{0641}"),
+    ('\u{1ee71}', "\u{0635}"),
+    ('\u{1ee72}', "\u{0642}"),
+    ('\u{1ee74}', "\u{0634}"),
+    ('\u{1ee75}', "\u{062a}"),
+    ('\u{1ee76}', "\u{062b}"),
+    ('\u{1ee77}', "\u{062e}"),
+    ('\u{1ee79}', "\u{0636}"),
+    ('\u{1ee7a}', "\u{0638}"),
+    ('\u{1ee7b}', "\u{063a}"),
+    ('\u{1ee7c}', "\u{066e}"),
+    ('\u{1ee7e}', "\u{06a1}"),
+    ('\u{1ee80}', "\u{0627}"),
+    ('\u{1ee81}', "\u{0628}"),
+    ('\u{1ee82}', "\u{062c}"),
+    ('\u{1ee83}', "\u{062f}"),
+    ('\u{1ee84}', "\u{0647}"),
+    ('\u{1ee85}', "\u{0648}"),
+    ('\u{1ee86}', "\u{0632}"),
+    ('\u{1ee87}', "\u{062d}"),
+    ('\u{1ee88}', "\u{0637}"),
+    ('\u{1ee89}', "\u{064a}"),
+    ('\u{1ee8b}', "\u{0644}"),
+    ('\u{1ee8c}', "\u{0645}"),
+    ('\u{1ee8d}', "\u{0646}"),
+    ('\u{1ee8e}', "\u{0633}"),
+    ('\u{1ee8f}', "\u{0639}"),
+    ('\u{1ee90}', "\u{0641}"),
+    ('\u{1ee91}', "\u{0635}"),
+    ('\u{1ee92}', "\u{0642}"),
+    ('\u{1ee93}', "\u{0631}"),
+    ('\u{1ee94}', "\u{0634}"),
+    ('\u{1ee95}', "\u{062a}"),
+    ('\u{1ee96}', "\u{062b}"),
+    ('\u{1ee97}', "\u{062e}"),
+    ('\u{1ee98}', "\u{0630}"),
+    ('\u{1ee99}', "\u{0636}"),
+    ('\u{1ee9a}', "\u{0638}"),
+    ('\u{1ee9b}', "\u{063a}"),
+    ('\u{1eea1}', "\u{0628}"),
+    ('\u{1eea2}', "\u{062c}"),
+    ('\u{1eea3}', "\u{062f}"),
+    ('\u{1eea5}', "\u{0648}"),
+    ('\u{1eea6}', "\u{0632}"),
+    ('\u{1eea7}', "\u{062d}"),
+    ('\u{1eea8}', "\u{0637}"),
+    ('\u{1eea9}', "\u{064a}"),
+    ('\u{1eeab}', "\u{0644}"),
+    ('\u{1eeac}', "\u{0645}"),
+    ('\u{1eead}', "\u{0646}"),
+    ('\u{1eeae}', "\u{0633}"),
+    ('\u{1eeaf}', "\u{0639}"),
+    ('\u{1eeb0}', "\u{0641}"),
+    ('\u{1eeb1}', "\u{0635}"),
+    ('\u{1eeb2}', "\u{0642}"),
+    ('\u{1eeb3}', "\u{0631}"),
+    ('\u{1eeb4}', "\u{0634}"),
+    ('\u{1eeb5}', "\u{062a}"),
+    ('\u{1eeb6}', "\u{062b}"),
+    ('\u{1eeb7}', "\u{062e}"),
+    ('\u{1eeb8}', "\u{0630}"),
+    ('\u{1eeb9}', "\u{0636}"),
+    ('\u{1eeba}', "\u{0638}"),
+    ('\u{1eebb}', "\u{063a}"),
+    ('\u{1f100}', "\u{0030}\u{002e}"),
+    ('\u{1f101}', "\u{0030}\u{002c}"),
+    ('\u{1f102}', "\u{0031}\u{002c}"),
+    ('\u{1f103}', "\u{0032}\u{002c}"),
+    ('\u{1f104}', "\u{0033}\u{002c}"),
+    ('\u{1f105}', "\u{0034}\u{002c}"),
+    ('\u{1f106}', "\u{0035}\u{002c}"),
+    ('\u{1f107}', "\u{0036}\u{002c}"),
+    ('\u{1f108}', "\u{0037}\u{002c}"),
+    ('\u{1f109}', "\u{0038}\u{002c}"),
+    ('\u{1f10a}', "\u{0039}\u{002c}"),
+    ('\u{1f110}', "\u{0028}\u{0061}\u{0029}"),
+    ('\u{1f111}', "\u{0028}\u{0062}\u{0029}"),
+    ('\u{1f112}', "\u{0028}\u{0063}\u{0029}"),
+    ('\u{1f113}', "\u{0028}\u{0064}\u{0029}"),
+    ('\u{1f114}', "\u{0028}\u{0065}\u{0029}"),
+    ('\u{1f115}', "\u{0028}\u{0066}\u{0029}"),
+    ('\u{1f116}', "\u{0028}\u{0067}\u{0029}"),
+    ('\u{1f117}', "\u{0028}\u{0068}\u{0029}"),
+    ('\u{1f118}', "\u{0028}\u{0069}\u{0029}"),
+    ('\u{1f119}', "\u{0028}\u{006a}\u{0029}"),
+    ('\u{1f11a}', "\u{0028}\u{006b}\u{0029}"),
+    ('\u{1f11b}', "\u{0028}\u{006c}\u{0029}"),
+    ('\u{1f11c}', "\u{0028}\u{006d}\u{0029}"),
+    ('\u{1f11d}', "\u{0028}\u{006e}\u{0029}"),
+    ('\u{1f11e}', "\u{0028}\u{006f}\u{0029}"),
+    ('\u{1f11f}', "\u{0028}\u{0070}\u{0029}"),
+    ('\u{1f120}', "\u{0028}\u{0071}\u{0029}"),
+    ('\u{1f121}', "\u{0028}\u{0072}\u{0029}"),
+    ('\u{1f122}', "\u{0028}\u{0073}\u{0029}"),
+    ('\u{1f123}', "\u{0028}\u{0074}\u{0029}"),
+    ('\u{1f124}', "\u{0028}\u{0075}\u{0029}"),
+    ('\u{1f125}', "\u{0028}\u{0076}\u{0029}"),
+    ('\u{1f126}', "\u{0028}\u{0077}\u{0029}"),
+    ('\u{1f127}', "\u{0028}\u{0078}\u{0029}"),
+    ('\u{1f128}', "\u{0028}\u{0079}\u{0029}"),
+    ('\u{1f129}', "\u{0028}\u{007a}\u{0029}"),
+    ('\u{1f12a}', "\u{3014}\u{0073}\u{3015}"),
+    ('\u{1f12b}', "c"),
+    ('\u{1f12c}', "r"),
+    ('\u{1f12d}', "cd"),
+    ('\u{1f12e}', "wz"),
+    ('\u{1f130}', "a"),
+    ('\u{1f131}', "b"),
+    ('\u{1f132}', "c"),
+    ('\u{1f133}', "d"),
+    ('\u{1f134}', "e"),
+    ('\u{1f135}', "f"),
+    ('\u{1f136}', "g"),
+    ('\u{1f137}', "h"),
+    ('\u{1f138}', "i"),
+    ('\u{1f139}', "j"),
+    ('\u{1f13a}', "k"),
+    ('\u{1f13b}', "l"),
+    ('\u{1f13c}', "m"),
+    ('\u{1f13d}', "n"),
+    ('\u{1f13e}', "o"),
+    ('\u{1f13f}', "p"),
+    ('\u{1f140}', "q"),
+    ('\u{1f141}', "r"),
+    ('\u{1f142}', "s"),
+    ('\u{1f143}', "t"),
+    ('\u{1f144}', "u"),
+    ('\u{1f145}', "v"),
+    ('\u{1f146}', "w"),
+    ('\u{1f147}', "x"),
+    ('\u{1f148}', "y"),
+    ('\u{1f149}', "z"),
+    ('\u{1f14a}', "hv"),
+    ('\u{1f14b}', "mv"),
+    ('\u{1f14c}', "sd"),
+    ('\u{1f14d}', "ss"),
+    ('\u{1f14e}', "ppv"),
+    ('\u{1f14f}', "wc"),
+    ('\u{1f16a}', "mc"),
+    ('\u{1f16b}', "md"),
+    ('\u{1f16c}', "mr"),
+    ('\u{1f190}', "dj"),
+    ('\u{1f200}', "\u{307b}\u{304b}"),
+    ('\u{1f201}', "\u{30b3}\u{30b3}"),
+    ('\u{1f202}', "\u{30b5}"),
+    ('\u{1f210}', "\u{624b}"),
+    ('\u{1f211}', "\u{5b57}"),
+    ('\u{1f212}', "\u{53cc}"),
+    ('\u{1f213}', "\u{30c6}"),
+    ('\u{1f214}', "\u{4e8c}"),
+    ('\u{1f215}', "\u{591a}"),
+    ('\u{1f216}', "\u{89e3}"),
+    ('\u{1f217}', "\u{5929}"),
+    ('\u{1f218}', "\u{4ea4}"),
+    ('\u{1f219}', "\u{6620}"),
+    ('\u{1f21a}', "\u{7121}"),
+    ('\u{1f21b}', "\u{6599}"),
+    ('\u{1f21c}', "\u{524d}"),
+    ('\u{1f21d}', "\u{5f8c}"),
+    ('\u{1f21e}', "\u{518d}"),
+    ('\u{1f21f}', "\u{65b0}"),
+    ('\u{1f220}', "\u{521d}"),
+    ('\u{1f221}', "\u{7d42}"),
+    ('\u{1f222}', "\u{751f}"),
+    ('\u{1f223}', "\u{8ca9}"),
+    ('\u{1f224}', "\u{58f0}"),
+    ('\u{1f225}', "\u{5439}"),
+    ('\u{1f226}', "\u{6f14}"),
+    ('\u{1f227}', "\u{6295}"),
+    ('\u{1f228}', "\u{6355}"),
+    ('\u{1f229}', "\u{4e00}"),
+    ('\u{1f22a}', "\u{4e09}"),
+    ('\u{1f22b}', "\u{904a}"),
+    ('\u{1f22c}', "\u{5de6}"),
+    ('\u{1f22d}', "\u{4e2d}"),
+    ('\u{1f22e}', "\u{53f3}"),
+    ('\u{1f22f}', "\u{6307}"),
+    ('\u{1f230}', "\u{8d70}"),
+    ('\u{1f231}', "\u{6253}"),
+    ('\u{1f232}', "\u{7981}"),
+    ('\u{1f233}', "\u{7a7a}"),
+    ('\u{1f234}', "\u{5408}"),
+    ('\u{1f235}', "\u{6e80}"),
+    ('\u{1f236}', "\u{6709}"),
+    ('\u{1f237}', "\u{6708}"),
+    ('\u{1f238}', "\u{7533}"),
+    ('\u{1f239}', "\u{5272}"),
+    ('\u{1f23a}', "\u{55b6}"),
+    ('\u{1f23b}', "\u{914d}"),
+    ('\u{1f240}', "\u{3014}\u{672c}\u{3015}"),
+    ('\u{1f241}', "\u{3014}\u{4e09}\u{3015}"),
+    ('\u{1f242}', "\u{3014}\u{4e8c}\u{3015}"),
+    ('\u{1f243}', "\u{3014}\u{5b89}\u{3015}"),
+    ('\u{1f244}', "\u{3014}\u{70b9}\u{3015}"),
+    ('\u{1f245}', "\u{3014}\u{6253}\u{3015}"),
+    ('\u{1f246}', "\u{3014}\u{76d7}\u{3015}"),
+    ('\u{1f247}', "\u{3014}\u{52dd}\u{3015}"),
+    ('\u{1f248}', "\u{3014}\u{6557}\u{3015}"),
+    ('\u{1f250}', "\u{5f97}"),
+    ('\u{1f251}', "\u{53ef}"),
+    ('\u{1fbf0}', "0"),
+    ('\u{1fbf1}', "1"),
+    ('\u{1fbf2}', "2"),
+    ('\u{1fbf3}', "3"),
+    ('\u{1fbf4}', "4"),
+    ('\u{1fbf5}', "5"),
+    ('\u{1fbf6}', "6"),
+    ('\u{1fbf7}', "7"),
+    ('\u{1fbf8}', "8"),
+    ('\u{1fbf9}', "9"),
+    ('\u{2f800}', "\u{4e3d}"),
+    ('\u{2f801}', "\u{4e38}"),
+    ('\u{2f802}', "\u{4e41}"),
+    ('\u{2f803}', "\u{20122}"),
+    ('\u{2f804}', "\u{4f60}"),
+    ('\u{2f805}', "\u{4fae}"),
+    ('\u{2f806}', "\u{4fbb}"),
+    ('\u{2f807}', "\u{5002}"),
+    ('\u{2f808}', "\u{507a}"),
+    ('\u{2f809}', "\u{5099}"),
+    ('\u{2f80a}', "\u{50e7}"),
+    ('\u{2f80b}', "\u{50cf}"),
+    ('\u{2f80c}', "\u{349e}"),
+    ('\u{2f80d}', "\u{2063a}"),
+    ('\u{2f80e}', "\u{514d}"),
+    ('\u{2f80f}', "\u{5154}"),
+    ('\u{2f810}', "\u{5164}"),
+    ('\u{2f811}', "\u{5177}"),
+    ('\u{2f812}', "\u{2051c}"),
+    ('\u{2f813}', "\u{34b9}"),
+    ('\u{2f814}', "\u{5167}"),
+    ('\u{2f815}', "\u{518d}"),
+    ('\u{2f816}', "\u{2054b}"),
+    ('\u{2f817}', "\u{5197}"),
+    ('\u{2f818}', "\u{51a4}"),
+    ('\u{2f819}', "\u{4ecc}"),
+    ('\u{2f81a}', "\u{51ac}"),
+    ('\u{2f81b}', "\u{51b5}"),
+    ('\u{2f81c}', "\u{291df}"),
+    ('\u{2f81d}', "\u{51f5}"),
+    ('\u{2f81e}', "\u{5203}"),
+    ('\u{2f81f}', "\u{34df}"),
+    ('\u{2f820}', "\u{523b}"),
+    ('\u{2f821}', "\u{5246}"),
+    ('\u{2f822}', "\u{5272}"),
+    ('\u{2f823}', "\u{5277}"),
+    ('\u{2f824}', "\u{3515}"),
+    ('\u{2f825}', "\u{52c7}"),
+    ('\u{2f826}', "\u{52c9}"),
+    ('\u{2f827}', "\u{52e4}"),
+    ('\u{2f828}', "\u{52fa}"),
+    ('\u{2f829}', "\u{5305}"),
+    ('\u{2f82a}', "\u{5306}"),
+    ('\u{2f82b}', "\u{5317}"),
+    ('\u{2f82c}', "\u{5349}"),
+    ('\u{2f82d}', "\u{5351}"),
+    ('\u{2f82e}', "\u{535a}"),
+    ('\u{2f82f}', "\u{5373}"),
+    ('\u{2f830}', "\u{537d}"),
+    ('\u{2f831}', "\u{537f}"),
+    ('\u{2f832}', "\u{537f}"),
+    ('\u{2f833}', "\u{537f}"),
+    ('\u{2f834}', "\u{20a2c}"),
+    ('\u{2f835}', "\u{7070}"),
+    ('\u{2f836}', "\u{53ca}"),
+    ('\u{2f837}', "\u{53df}"),
+    ('\u{2f838}', "\u{20b63}"),
+    ('\u{2f839}', "\u{53eb}"),
+    ('\u{2f83a}', "\u{53f1}"),
+    ('\u{2f83b}', "\u{5406}"),
+    ('\u{2f83c}', "\u{549e}"),
+    ('\u{2f83d}', "\u{5438}"),
+    ('\u{2f83e}', "\u{5448}"),
+    ('\u{2f83f}', "\u{5468}"),
+    ('\u{2f840}', "\u{54a2}"),
+    ('\u{2f841}', "\u{54f6}"),
+    ('\u{2f842}', "\u{5510}"),
+    ('\u{2f843}', "\u{5553}"),
+    ('\u{2f844}', "\u{5563}"),
+    ('\u{2f845}', "\u{5584}"),
+    ('\u{2f846}', "\u{5584}"),
+    ('\u{2f847}', "\u{5599}"),
+    ('\u{2f848}', "\u{55ab}"),
+    ('\u{2f849}', "\u{55b3}"),
+    ('\u{2f84a}', "\u{55c2}"),
+    ('\u{2f84b}', "\u{5716}"),
+    ('\u{2f84c}', "\u{5606}"),
+    ('\u{2f84d}', "\u{5717}"),
+    ('\u{2f84e}', "\u{5651}"),
+    ('\u{2f84f}', "\u{5674}"),
+    ('\u{2f850}', "\u{5207}"),
+    ('\u{2f851}', "\u{58ee}"),
+    ('\u{2f852}', "\u{57ce}"),
+    ('\u{2f853}', "\u{57f4}"),
+    ('\u{2f854}', "\u{580d}"),
+    ('\u{2f855}', "\u{578b}"),
+    ('\u{2f856}', "\u{5832}"),
+    ('\u{2f857}', "\u{5831}"),
+    ('\u{2f858}', "\u{58ac}"),
+    ('\u{2f859}', "\u{214e4}"),
+    ('\u{2f85a}', "\u{58f2}"),
+    ('\u{2f85b}', "\u{58f7}"),
+    ('\u{2f85c}', "\u{5906}"),
+    ('\u{2f85d}', "\u{591a}"),
+    ('\u{2f85e}', "\u{5922}"),
+    ('\u{2f85f}', "\u{5962}"),
+    ('\u{2f860}', "\u{216a8}"),
+    ('\u{2f861}', "\u{216ea}"),
+    ('\u{2f862}', "\u{59ec}"),
+    ('\u{2f863}', "\u{5a1b}"),
+    ('\u{2f864}', "\u{5a27}"),
+    ('\u{2f865}', "\u{59d8}"),
+    ('\u{2f866}', "\u{5a66}"),
+    ('\u{2f867}', "\u{36ee}"),
+    ('\u{2f868}', "\u{36fc}"),
+    ('\u{2f869}', "\u{5b08}"),
+    ('\u{2f86a}', "\u{5b3e}"),
+    ('\u{2f86b}', "\u{5b3e}"),
+    ('\u{2f86c}', "\u{219c8}"),
+    ('\u{2f86d}', "\u{5bc3}"),
+    ('\u{2f86e}', "\u{5bd8}"),
+    ('\u{2f86f}', "\u{5be7}"),
+    ('\u{2f870}', "\u{5bf3}"),
+    ('\u{2f871}', "\u{21b18}"),
+    ('\u{2f872}', "\u{5bff}"),
+    ('\u{2f873}', "\u{5c06}"),
+    ('\u{2f874}', "\u{5f53}"),
+    ('\u{2f875}', "\u{5c22}"),
+    ('\u{2f876}', "\u{3781}"),
+    ('\u{2f877}', "\u{5c60}"),
+    ('\u{2f878}', "\u{5c6e}"),
+    ('\u{2f879}', "\u{5cc0}"),
+    ('\u{2f87a}', "\u{5c8d}"),
+    ('\u{2f87b}', "\u{21de4}"),
+    ('\u{2f87c}', "\u{5d43}"),
+    ('\u{2f87d}', "\u{21de6}"),
+    ('\u{2f87e}', "\u{5d6e}"),
+    ('\u{2f87f}', "\u{5d6b}"),
+    ('\u{2f880}', "\u{5d7c}"),
+    ('\u{2f881}', "\u{5de1}"),
+    ('\u{2f882}', "\u{5de2}"),
+    ('\u{2f883}', "\u{382f}"),
+    ('\u{2f884}', "\u{5dfd}"),
+    ('\u{2f885}', "\u{5e28}"),
+    ('\u{2f886}', "\u{5e3d}"),
+    ('\u{2f887}', "\u{5e69}"),
+    ('\u{2f888}', "\u{3862}"),
+    ('\u{2f889}', "\u{22183}"),
+    ('\u{2f88a}', "\u{387c}"),
+    ('\u{2f88b}', "\u{5eb0}"),
+    ('\u{2f88c}', "\u{5eb3}"),
+    ('\u{2f88d}', "\u{5eb6}"),
+    ('\u{2f88e}', "\u{5eca}"),
+    ('\u{2f88f}', "\u{2a392}"),
+    ('\u{2f890}', "\u{5efe}"),
+    ('\u{2f891}', "\u{22331}"),
+    ('\u{2f892}', "\u{22331}"),
+    ('\u{2f893}', "\u{8201}"),
+    ('\u{2f894}', "\u{5f22}"),
+    ('\u{2f895}', "\u{5f22}"),
+    ('\u{2f896}', "\u{38c7}"),
+    ('\u{2f897}', "\u{232b8}"),
+    ('\u{2f898}', "\u{261da}"),
+    ('\u{2f899}', "\u{5f62}"),
+    ('\u{2f89a}', "\u{5f6b}"),
+    ('\u{2f89b}', "\u{38e3}"),
+    ('\u{2f89c}', "\u{5f9a}"),
+    ('\u{2f89d}', "\u{5fcd}"),
+    ('\u{2f89e}', "\u{5fd7}"),
+    ('\u{2f89f}', "\u{5ff9}"),
+    ('\u{2f8a0}', "\u{6081}"),
+    ('\u{2f8a1}', "\u{393a}"),
+    ('\u{2f8a2}', "\u{391c}"),
+    ('\u{2f8a3}', "\u{6094}"),
+    ('\u{2f8a4}', "\u{226d4}"),
+    ('\u{2f8a5}', "\u{60c7}"),
+    ('\u{2f8a6}', "\u{6148}"),
+    ('\u{2f8a7}', "\u{614c}"),
+    ('\u{2f8a8}', "\u{614e}"),
+    ('\u{2f8a9}', "\u{614c}"),
+    ('\u{2f8aa}', "\u{617a}"),
+    ('\u{2f8ab}', "\u{618e}"),
+    ('\u{2f8ac}', "\u{61b2}"),
+    ('\u{2f8ad}', "\u{61a4}"),
+    ('\u{2f8ae}', "\u{61af}"),
+    ('\u{2f8af}', "\u{61de}"),
+    ('\u{2f8b0}', "\u{61f2}"),
+    ('\u{2f8b1}', "\u{61f6}"),
+    ('\u{2f8b2}', "\u{6210}"),
+    ('\u{2f8b3}', "\u{621b}"),
+    ('\u{2f8b4}', "\u{625d}"),
+    ('\u{2f8b5}', "\u{62b1}"),
+    ('\u{2f8b6}', "\u{62d4}"),
+    ('\u{2f8b7}', "\u{6350}"),
+    ('\u{2f8b8}', "\u{22b0c}"),
+    ('\u{2f8b9}', "\u{633d}"),
+    ('\u{2f8ba}', "\u{62fc}"),
+    ('\u{2f8bb}', "\u{6368}"),
+    ('\u{2f8bc}', "\u{6383}"),
+    ('\u{2f8bd}', "\u{63e4}"),
+    ('\u{2f8be}', "\u{22bf1}"),
+    ('\u{2f8bf}', "\u{6422}"),
+    ('\u{2f8c0}', "\u{63c5}"),
+    ('\u{2f8c1}', "\u{63a9}"),
+    ('\u{2f8c2}', "\u{3a2e}"),
+    ('\u{2f8c3}', "\u{6469}"),
+    ('\u{2f8c4}', "\u{647e}"),
+    ('\u{2f8c5}', "\u{649d}"),
+    ('\u{2f8c6}', "\u{6477}"),
+    ('\u{2f8c7}', "\u{3a6c}"),
+    ('\u{2f8c8}', "\u{654f}"),
+    ('\u{2f8c9}', "\u{656c}"),
+    ('\u{2f8ca}', "\u{2300a}"),
+    ('\u{2f8cb}', "\u{65e3}"),
+    ('\u{2f8cc}', "\u{66f8}"),
+    ('\u{2f8cd}', "\u{6649}"),
+    ('\u{2f8ce}', "\u{3b19}"),
+    ('\u{2f8cf}', "\u{6691}"),
+    ('\u{2f8d0}', "\u{3b08}"),
+    ('\u{2f8d1}', "\u{3ae4}"),
+    ('\u{2f8d2}', "\u{5192}"),
+    ('\u{2f8d3}', "\u{5195}"),
+    ('\u{2f8d4}', "\u{6700}"),
+    ('\u{2f8d5}', "\u{669c}"),
+    ('\u{2f8d6}', "\u{80ad}"),
+    ('\u{2f8d7}', "\u{43d9}"),
+    ('\u{2f8d8}', "\u{6717}"),
+    ('\u{2f8d9}', "\u{671b}"),
+    ('\u{2f8da}', "\u{6721}"),
+    ('\u{2f8db}', "\u{675e}"),
+    ('\u{2f8dc}', "\u{6753}"),
+    ('\u{2f8dd}', "\u{233c3}"),
+    ('\u{2f8de}', "\u{3b49}"),
+    ('\u{2f8df}', "\u{67fa}"),
+    ('\u{2f8e0}', "\u{6785}"),
+    ('\u{2f8e1}', "\u{6852}"),
+    ('\u{2f8e2}', "\u{6885}"),
+    ('\u{2f8e3}', "\u{2346d}"),
+    ('\u{2f8e4}', "\u{688e}"),
+    ('\u{2f8e5}', "\u{681f}"),
+    ('\u{2f8e6}', "\u{6914}"),
+    ('\u{2f8e7}', "\u{3b9d}"),
+    ('\u{2f8e8}', "\u{6942}"),
+    ('\u{2f8e9}', "\u{69a3}"),
+    ('\u{2f8ea}', "\u{69ea}"),
+    ('\u{2f8eb}', "\u{6aa8}"),
+    ('\u{2f8ec}', "\u{236a3}"),
+    ('\u{2f8ed}', "\u{6adb}"),
+    ('\u{2f8ee}', "\u{3c18}"),
+    ('\u{2f8ef}', "\u{6b21}"),
+    ('\u{2f8f0}', "\u{238a7}"),
+    ('\u{2f8f1}', "\u{6b54}"),
+    ('\u{2f8f2}', "\u{3c4e}"),
+    ('\u{2f8f3}', "\u{6b72}"),
+    ('\u{2f8f4}', "\u{6b9f}"),
+    ('\u{2f8f5}', "\u{6bba}"),
+    ('\u{2f8f6}', "\u{6bbb}"),
+    ('\u{2f8f7}', "\u{23a8d}"),
+    ('\u{2f8f8}', "\u{21d0b}"),
+    ('\u{2f8f9}', "\u{23afa}"),
+    ('\u{2f8fa}', "\u{6c4e}"),
+    ('\u{2f8fb}', "\u{23cbc}"),
+    ('\u{2f8fc}', "\u{6cbf}"),
+    ('\u{2f8fd}', "\u{6ccd}"),
+    ('\u{2f8fe}', "\u{6c67}"),
+    ('\u{2f8ff}', "\u{6d16}"),
+    ('\u{2f900}', "\u{6d3e}"),
+    ('\u{2f901}', "\u{6d77}"),
+    ('\u{2f902}', "\u{6d41}"),
+    ('\u{2f903}', "\u{6d69}"),
+    ('\u{2f904}', "\u{6d78}"),
+    ('\u{2f905}', "\u{6d85}"),
+    ('\u{2f906}', "\u{23d1e}"),
+    ('\u{2f907}', "\u{6d34}"),
+    ('\u{2f908}', "\u{6e2f}"),
+    ('\u{2f909}', "\u{6e6e}"),
+    ('\u{2f90a}', "\u{3d33}"),
+    ('\u{2f90b}', "\u{6ecb}"),
+    ('\u{2f90c}', "\u{6ec7}"),
+    ('\u{2f90d}', "\u{23ed1}"),
+    ('\u{2f90e}', "\u{6df9}"),
+    ('\u{2f90f}', "\u{6f6e}"),
+    ('\u{2f910}', "\u{23f5e}"),
+    ('\u{2f911}', "\u{23f8e}"),
+    ('\u{2f912}', "\u{6fc6}"),
+    ('\u{2f913}', "\u{7039}"),
+    ('\u{2f914}', "\u{701e}"),
+    ('\u{2f915}', "\u{701b}"),
+    ('\u{2f916}', "\u{3d96}"),
+    ('\u{2f917}', "\u{704a}"),
+    ('\u{2f918}', "\u{707d}"),
+    ('\u{2f919}', "\u{7077}"),
+    ('\u{2f91a}', "\u{70ad}"),
+    ('\u{2f91b}', "\u{20525}"),
+    ('\u{2f91c}', "\u{7145}"),
+    ('\u{2f91d}', "\u{24263}"),
+    ('\u{2f91e}', "\u{719c}"),
+    ('\u{2f91f}', "\u{243ab}"),
+    ('\u{2f920}', "\u{7228}"),
+    ('\u{2f921}', "\u{7235}"),
+    ('\u{2f922}', "\u{7250}"),
+    ('\u{2f923}', "\u{24608}"),
+    ('\u{2f924}', "\u{7280}"),
+    ('\u{2f925}', "\u{7295}"),
+    ('\u{2f926}', "\u{24735}"),
+    ('\u{2f927}', "\u{24814}"),
+    ('\u{2f928}', "\u{737a}"),
+    ('\u{2f929}', "\u{738b}"),
+    ('\u{2f92a}', "\u{3eac}"),
+    ('\u{2f92b}', "\u{73a5}"),
+    ('\u{2f92c}', "\u{3eb8}"),
+    ('\u{2f92d}', "\u{3eb8}"),
+    ('\u{2f92e}', "\u{7447}"),
+    ('\u{2f92f}', "\u{745c}"),
+    ('\u{2f930}', "\u{7471}"),
+    ('\u{2f931}', "\u{7485}"),
+    ('\u{2f932}', "\u{74ca}"),
+    ('\u{2f933}', "\u{3f1b}"),
+    ('\u{2f934}', "\u{7524}"),
+    ('\u{2f935}', "\u{24c36}"),
+    ('\u{2f936}', "\u{753e}"),
+    ('\u{2f937}', "\u{24c92}"),
+    ('\u{2f938}', "\u{7570}"),
+    ('\u{2f939}', "\u{2219f}"),
+    ('\u{2f93a}', "\u{7610}"),
+    ('\u{2f93b}', "\u{24fa1}"),
+    ('\u{2f93c}', "\u{24fb8}"),
+    ('\u{2f93d}', "\u{25044}"),
+    ('\u{2f93e}', "\u{3ffc}"),
+    ('\u{2f93f}', "\u{4008}"),
+    ('\u{2f940}', "\u{76f4}"),
+    ('\u{2f941}', "\u{250f3}"),
+    ('\u{2f942}', "\u{250f2}"),
+    ('\u{2f943}', "\u{25119}"),
+    ('\u{2f944}', "\u{25133}"),
+    ('\u{2f945}', "\u{771e}"),
+    ('\u{2f946}', "\u{771f}"),
+    ('\u{2f947}', "\u{771f}"),
+    ('\u{2f948}', "\u{774a}"),
+    ('\u{2f949}', "\u{4039}"),
+    ('\u{2f94a}', "\u{778b}"),
+    ('\u{2f94b}', "\u{4046}"),
+    ('\u{2f94c}', "\u{4096}"),
+    ('\u{2f94d}', "\u{2541d}"),
+    ('\u{2f94e}', "\u{784e}"),
+    ('\u{2f94f}', "\u{788c}"),
+    ('\u{2f950}', "\u{78cc}"),
+    ('\u{2f951}', "\u{40e3}"),
+    ('\u{2f952}', "\u{25626}"),
+    ('\u{2f953}', "\u{7956}"),
+    ('\u{2f954}', "\u{2569a}"),
+    ('\u{2f955}', "\u{256c5}"),
+    ('\u{2f956}', "\u{798f}"),
+    ('\u{2f957}', "\u{79eb}"),
+    ('\u{2f958}', "\u{412f}"),
+    ('\u{2f959}', "\u{7a40}"),
+    ('\u{2f95a}', "\u{7a4a}"),
+    ('\u{2f95b}', "\u{7a4f}"),
+    ('\u{2f95c}', "\u{2597c}"),
+    ('\u{2f95d}', "\u{25aa7}"),
+    ('\u{2f95e}', "\u{25aa7}"),
+    ('\u{2f95f}', "\u{7aee}"),
+    ('\u{2f960}', "\u{4202}"),
+    ('\u{2f961}', "\u{25bab}"),
+    ('\u{2f962}', "\u{7bc6}"),
+    ('\u{2f963}', "\u{7bc9}"),
+    ('\u{2f964}', "\u{4227}"),
+    ('\u{2f965}', "\u{25c80}"),
+    ('\u{2f966}', "\u{7cd2}"),
+    ('\u{2f967}', "\u{42a0}"),
+    ('\u{2f968}', "\u{7ce8}"),
+    ('\u{2f969}', "\u{7ce3}"),
+    ('\u{2f96a}', "\u{7d00}"),
+    ('\u{2f96b}', "\u{25f86}"),
+    ('\u{2f96c}', "\u{7d63}"),
+    ('\u{2f96d}', "\u{4301}"),
+    ('\u{2f96e}', "\u{7dc7}"),
+    ('\u{2f96f}', "\u{7e02}"),
+    ('\u{2f970}', "\u{7e45}"),
+    ('\u{2f971}', "\u{4334}"),
+    ('\u{2f972}', "\u{26228}"),
+    ('\u{2f973}', "\u{26247}"),
+    ('\u{2f974}', "\u{4359}"),
+    ('\u{2f975}', "\u{262d9}"),
+    ('\u{2f976}', "\u{7f7a}"),
+    ('\u{2f977}', "\u{2633e}"),
+    ('\u{2f978}', "\u{7f95}"),
+    ('\u{2f979}', "\u{7ffa}"),
+    ('\u{2f97a}', "\u{8005}"),
+    ('\u{2f97b}', "\u{264da}"),
+    ('\u{2f97c}', "\u{26523}"),
+    ('\u{2f97d}', "\u{8060}"),
+    ('\u{2f97e}', "\u{265a8}"),
+    ('\u{2f97f}', "\u{8070}"),
+    ('\u{2f980}', "\u{2335f}"),
+    ('\u{2f981}', "\u{43d5}"),
+    ('\u{2f982}', "\u{80b2}"),
+    ('\u{2f983}', "\u{8103}"),
+    ('\u{2f984}', "\u{440b}"),
+    ('\u{2f985}', "\u{813e}"),
+    ('\u{2f986}', "\u{5ab5}"),
+    ('\u{2f987}', "\u{267a7}"),
+    ('\u{2f988}', "\u{267b5}"),
+    ('\u{2f989}', "\u{23393}"),
+    ('\u{2f98a}', "\u{2339c}"),
+    ('\u{2f98b}', "\u{8201}"),
+    ('\u{2f98c}', "\u{8204}"),
+    ('\u{2f98d}', "\u{8f9e}"),
+    ('\u{2f98e}', "\u{446b}"),
+    ('\u{2f98f}', "\u{8291}"),
+    ('\u{2f990}', "\u{828b}"),
+    ('\u{2f991}', "\u{829d}"),
+    ('\u{2f992}', "\u{52b3}"),
+    ('\u{2f993}', "\u{82b1}"),
+    ('\u{2f994}', "\u{82b3}"),
+    ('\u{2f995}', "\u{82bd}"),
+    ('\u{2f996}', "\u{82e6}"),
+    ('\u{2f997}', "\u{26b3c}"),
+    ('\u{2f998}', "\u{82e5}"),
+    ('\u{2f999}', "\u{831d}"),
+    ('\u{2f99a}', "\u{8363}"),
+    ('\u{2f99b}', "\u{83ad}"),
+    ('\u{2f99c}', "\u{8323}"),
+    ('\u{2f99d}', "\u{83bd}"),
+    ('\u{2f99e}', "\u{83e7}"),
+    ('\u{2f99f}', "\u{8457}"),
+    ('\u{2f9a0}', "\u{8353}"),
+    ('\u{2f9a1}', "\u{83ca}"),
+    ('\u{2f9a2}', "\u{83cc}"),
+    ('\u{2f9a3}', "\u{83dc}"),
+    ('\u{2f9a4}', "\u{26c36}"),
+    ('\u{2f9a5}', "\u{26d6b}"),
+    ('\u{2f9a6}', "\u{26cd5}"),
+    ('\u{2f9a7}', "\u{452b}"),
+    ('\u{2f9a8}', "\u{84f1}"),
+    ('\u{2f9a9}', "\u{84f3}"),
+    ('\u{2f9aa}', "\u{8516}"),
+    ('\u{2f9ab}', "\u{273ca}"),
+    ('\u{2f9ac}', "\u{8564}"),
+    ('\u{2f9ad}', "\u{26f2c}"),
+    ('\u{2f9ae}', "\u{455d}"),
+    ('\u{2f9af}', "\u{4561}"),
+    ('\u{2f9b0}', "\u{26fb1}"),
+    ('\u{2f9b1}', "\u{270d2}"),
+    ('\u{2f9b2}', "\u{456b}"),
+    ('\u{2f9b3}', "\u{8650}"),
+    ('\u{2f9b4}', "\u{865c}"),
+    ('\u{2f9b5}', "\u{8667}"),
+    ('\u{2f9b6}', "\u{8669}"),
+    ('\u{2f9b7}', "\u{86a9}"),
+    ('\u{2f9b8}', "\u{8688}"),
+    ('\u{2f9b9}', "\u{870e}"),
+    ('\u{2f9ba}', "\u{86e2}"),
+    ('\u{2f9bb}', "\u{8779}"),
+    ('\u{2f9bc}', "\u{8728}"),
+    ('\u{2f9bd}', "\u{876b}"),
+    ('\u{2f9be}', "\u{8786}"),
+    ('\u{2f9bf}', "\u{45d7}"),
+    ('\u{2f9c0}', "\u{87e1}"),
+    ('\u{2f9c1}', "\u{8801}"),
+    ('\u{2f9c2}', "\u{45f9}"),
+    ('\u{2f9c3}', "\u{8860}"),
+    ('\u{2f9c4}', "\u{8863}"),
+    ('\u{2f9c5}', "\u{27667}"),
+    ('\u{2f9c6}', "\u{88d7}"),
+    ('\u{2f9c7}', "\u{88de}"),
+    ('\u{2f9c8}', "\u{4635}"),
+    ('\u{2f9c9}', "\u{88fa}"),
+    ('\u{2f9ca}', "\u{34bb}"),
+    ('\u{2f9cb}', "\u{278ae}"),
+    ('\u{2f9cc}', "\u{27966}"),
+    ('\u{2f9cd}', "\u{46be}"),
+    ('\u{2f9ce}', "\u{46c7}"),
+    ('\u{2f9cf}', "\u{8aa0}"),
+    ('\u{2f9d0}', "\u{8aed}"),
+    ('\u{2f9d1}', "\u{8b8a}"),
+    ('\u{2f9d2}', "\u{8c55}"),
+    ('\u{2f9d3}', "\u{27ca8}"),
+    ('\u{2f9d4}', "\u{8cab}"),
+    ('\u{2f9d5}', "\u{8cc1}"),
+    ('\u{2f9d6}', "\u{8d1b}"),
+    ('\u{2f9d7}', "\u{8d77}"),
+    ('\u{2f9d8}', "\u{27f2f}"),
+    ('\u{2f9d9}', "\u{20804}"),
+    ('\u{2f9da}', "\u{8dcb}"),
+    ('\u{2f9db}', "\u{8dbc}"),
+    ('\u{2f9dc}', "\u{8df0}"),
+    ('\u{2f9dd}', "\u{208de}"),
+    ('\u{2f9de}', "\u{8ed4}"),
+    ('\u{2f9df}', "\u{8f38}"),
+    ('\u{2f9e0}', "\u{285d2}"),
+    ('\u{2f9e1}', "\u{285ed}"),
+    ('\u{2f9e2}', "\u{9094}"),
+    ('\u{2f9e3}', "\u{90f1}"),
+    ('\u{2f9e4}', "\u{9111}"),
+    ('\u{2f9e5}', "\u{2872e}"),
+    ('\u{2f9e6}', "\u{911b}"),
+    ('\u{2f9e7}', "\u{9238}"),
+    ('\u{2f9e8}', "\u{92d7}"),
+    ('\u{2f9e9}', "\u{92d8}"),
+    ('\u{2f9ea}', "\u{927c}"),
+    ('\u{2f9eb}', "\u{93f9}"),
+    ('\u{2f9ec}', "\u{9415}"),
+    ('\u{2f9ed}', "\u{28bfa}"),
+    ('\u{2f9ee}', "\u{958b}"),
+    ('\u{2f9ef}', "\u{4995}"),
+    ('\u{2f9f0}', "\u{95b7}"),
+    ('\u{2f9f1}', "\u{28d77}"),
+    ('\u{2f9f2}', "\u{49e6}"),
+    ('\u{2f9f3}', "\u{96c3}"),
+    ('\u{2f9f4}', "\u{5db2}"),
+    ('\u{2f9f5}', "\u{9723}"),
+    ('\u{2f9f6}', "\u{29145}"),
+    ('\u{2f9f7}', "\u{2921a}"),
+    ('\u{2f9f8}', "\u{4a6e}"),
+    ('\u{2f9f9}', "\u{4a76}"),
+    ('\u{2f9fa}', "\u{97e0}"),
+    ('\u{2f9fb}', "\u{2940a}"),
+    ('\u{2f9fc}', "\u{4ab2}"),
+    ('\u{2f9fd}', "\u{29496}"),
+    ('\u{2f9fe}', "\u{980b}"),
+    ('\u{2f9ff}', "\u{980b}"),
+    ('\u{2fa00}', "\u{9829}"),
+    ('\u{2fa01}', "\u{295b6}"),
+    ('\u{2fa02}', "\u{98e2}"),
+    ('\u{2fa03}', "\u{4b33}"),
+    ('\u{2fa04}', "\u{9929}"),
+    ('\u{2fa05}', "\u{99a7}"),
+    ('\u{2fa06}', "\u{99c2}"),
+    ('\u{2fa07}', "\u{99fe}"),
+    ('\u{2fa08}', "\u{4bce}"),
+    ('\u{2fa09}', "\u{29b30}"),
+    ('\u{2fa0a}', "\u{9b12}"),
+    ('\u{2fa0b}', "\u{9c40}"),
+    ('\u{2fa0c}', "\u{9cfd}"),
+    ('\u{2fa0d}', "\u{4cce}"),
+    ('\u{2fa0e}', "\u{4ced}"),
+    ('\u{2fa0f}', "\u{9d67}"),
+    ('\u{2fa10}', "\u{2a0ce}"),
+    ('\u{2fa11}', "\u{4cf8}"),
+    ('\u{2fa12}', "\u{2a105}"),
+    ('\u{2fa13}', "\u{2a20e}"),
+    ('\u{2fa14}', "\u{2a291}"),
+    ('\u{2fa15}', "\u{9ebb}"),
+    ('\u{2fa16}', "\u{4d56}"),
+    ('\u{2fa17}', "\u{9ef9}"),
+    ('\u{2fa18}', "\u{9efe}"),
+    ('\u{2fa19}', "\u{9f05}"),
+    ('\u{2fa1a}', "\u{9f0f}"),
+    ('\u{2fa1b}', "\u{9f16}"),
+    ('\u{2fa1c}', "\u{9f3b}"),
+    ('\u{2fa1d}', "\u{2a600}"),
+];
@@ -1,13 +1,280 @@
 #[macro_use]
 extern crate serde;
-use candid::{Decode, Encode};
+use candid::{Decode, Encode, Principal};
+use ic_cdk::api::management_canister::http_request::{
+    http_request, CanisterHttpRequestArgument, HttpHeader, HttpMethod, HttpResponse, TransformArgs,
+    TransformContext,
+};
 use ic_cdk::api::time;
+use ic_cdk_timers::TimerId;
 use ic_stable_structures::memory_manager::{MemoryId, MemoryManager, VirtualMemory};
+use ic_stable_structures::storable::Blob;
 use ic_stable_structures::{BoundedStorable, Cell, DefaultMemoryImpl, StableBTreeMap, Storable};
-use std::{borrow::Cow, cell::RefCell};
+use sha2::{Digest as _, Sha256};
+use std::{borrow::Cow, cell::RefCell, ops::Bound, thread::LocalKey, time::Duration};
+
+mod fold_table;
 
 type Memory = VirtualMemory<DefaultMemoryImpl>;
 type IdCell = Cell<u64, Memory>;
+// Raw bytes of a principal, which are at most 29 bytes long
+type PrincipalKey = Blob<29>;
+// An owner together with a due date
+type DueKey = (PrincipalKey, u64);
+// An owner together with a status
+type StatusKey = (PrincipalKey, TaskStatus);
+// SHA-256 of an owner and a normalized title
+type TitleKey = Blob<32>;
+// A lowercase title word, cut to its first 32 bytes
+type TokenKey = Blob<32>;
+// An owner together with a title word
+type OwnerTokenKey = (PrincipalKey, TokenKey);
+// Secondary index of todo ids by key
+type TodoIndex<K> = StableBTreeMap<(K, u64), (), Memory>;
+
+// Upper bound for the number of todos returned by recent activity queries
+const MAX_RECENT_LIMIT: u64 = 100;
+// Upper bound for the page size of paginated list queries
+const MAX_PAGE_LIMIT: u64 = 100;
+const MAX_AGENDA_DAYS: u64 = 31;
+const DIGEST_SECTION_LIMIT: usize = 25;
+const MAX_FOCUS_ITEMS: u8 = 10;
+// Todos due within this window count as due soon in the focus list
+const DUE_SOON_WINDOW: u64 = 3 * NANOS_PER_DAY;
+// Maximum length of a todo title, in characters
+const MAX_TITLE_LENGTH: usize = 200;
+// Maximum length of a todo description, in characters
+const MAX_DESCRIPTION_LENGTH: usize = 1000;
+// Effort estimates are capped at one week
+const MAX_ESTIMATED_MINUTES: u32 = 7 * 24 * 60;
+// Label limits
+const MAX_LABEL_NAME_LENGTH: usize = 50;
+const MAX_LABELS_PER_USER: usize = 100;
+const MAX_LABELS_PER_TODO: usize = 10;
+// Saved filter limits
+const MAX_FILTER_NAME_LENGTH: usize = 50;
+const MAX_SAVED_FILTERS_PER_USER: usize = 20;
+// Size of share tokens in bytes (128 bits)
+const SHARE_TOKEN_BYTES: usize = 16;
+// Pending ownership transfers expire after 7 days
+const TRANSFER_OFFER_TTL: u64 = 7 * NANOS_PER_DAY;
+// Idempotency keys are remembered for 24 hours and limited to 64 bytes
+const IDEMPOTENCY_WINDOW: u64 = NANOS_PER_DAY;
+const MAX_IDEMPOTENCY_KEY_LENGTH: usize = 64;
+// Notifications are pruned when older than 90 days or beyond 500 per recipient
+const NOTIFICATION_RETENTION: u64 = 90 * NANOS_PER_DAY;
+const MAX_NOTIFICATIONS_PER_USER: usize = 500;
+// Messages are cut so a notification always fits its stable memory bound
+const MAX_NOTIFICATION_MESSAGE_BYTES: usize = 256;
+// Event deliveries to subscribers are retried with exponential backoff and then dropped
+const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+const DELIVERY_RETRY_DELAY: u64 = 30 * NANOS_PER_SECOND;
+const MAX_DELIVERIES_PER_RUN: usize = 10;
+const MAX_QUEUED_DELIVERIES: u64 = 1000;
+// Event titles are cut so a queued delivery always fits its stable memory bound
+const MAX_EVENT_TITLE_BYTES: usize = 512;
+// Completion webhooks, delivered through HTTPS outcalls
+const MAX_WEBHOOK_URL_LENGTH: usize = 512;
+const MAX_WEBHOOK_SECRET_LENGTH: usize = 128;
+const MAX_WEBHOOK_LOG_ENTRIES: usize = 50;
+const MAX_WEBHOOK_ERROR_LENGTH: usize = 200;
+const WEBHOOK_MAX_RESPONSE_BYTES: u64 = 2048;
+const WEBHOOK_CYCLES: u128 = 2_000_000_000;
+// Completion webhooks cost an outcall each: a todo is posted at most once per debounce period
+// and an owner at most MAX_WEBHOOKS_PER_WINDOW times per window
+const WEBHOOK_TODO_DEBOUNCE: u64 = 10 * 60 * NANOS_PER_SECOND;
+const WEBHOOK_OWNER_WINDOW: u64 = 60 * 60 * NANOS_PER_SECOND;
+const MAX_WEBHOOKS_PER_WINDOW: u32 = 30;
+// Ranges accepted for per-user settings, timezone offsets share the UTC offset range below
+const MAX_DEFAULT_DUE_OFFSET_DAYS: u32 = 365;
+const MIN_TRASH_RETENTION_DAYS: u32 = 1;
+const MAX_TRASH_RETENTION_DAYS: u32 = 365;
+const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+const MAX_DISPLAY_NAME_LENGTH: usize = 50;
+const MAX_LEADERBOARD_ENTRIES: usize = 50;
+// Ingress messages are rejected during inspection when their argument is larger than this, except
+// for imports which carry whole backups
+const MAX_INGRESS_ARG_BYTES: usize = 64 * 1024;
+// Update methods act on the caller's own data, so anonymous ingress is rejected for all of them
+const UPDATE_METHODS: &[&str] = &[
+    "add_todo",
+    "update_todo",
+    "bulk_update_priority",
+    "rename_todo",
+    "delete_todo",
+    "update_status",
+    "complete_and_create_next",
+    "create_label",
+    "update_label",
+    "delete_label",
+    "attach_label",
+    "detach_label",
+    "import_todos_merge",
+    "set_pinned",
+    "toggle_pin",
+    "set_starred",
+    "unschedule_todo",
+    "freeze_todo",
+    "unfreeze_todo",
+    "reorder_todo",
+    "save_checkpoint",
+    "restore_checkpoint",
+    "delete_checkpoint",
+    "clone_todo",
+    "set_reminder",
+    "create_template",
+    "update_template",
+    "delete_template",
+    "instantiate_template",
+    "assign_todo",
+    "respond_to_assignment",
+    "reorder_todos",
+    "delete_all_my_todos",
+    "add_collaborator",
+    "remove_collaborator",
+    "set_field_permissions",
+    "create_share_token",
+    "revoke_share_token",
+    "offer_transfer",
+    "accept_transfer",
+    "decline_transfer",
+    "cancel_transfer",
+    "set_visibility",
+    "set_public",
+    "mark_notification_read",
+    "mark_all_read",
+    "delete_notification",
+    "subscribe",
+    "unsubscribe",
+    "set_webhook",
+    "clear_webhook",
+    "set_config",
+    "set_completion_hook",
+    "update_my_settings",
+    "admin_repair",
+    "create_saved_filter",
+    "update_saved_filter",
+    "delete_saved_filter",
+];
+// Query methods may also be called as updates; they are accepted from anyone
+const QUERY_METHODS: &[&str] = &[
+    "get_todo",
+    "get_todo_by_title",
+    "get_recently_updated",
+    "list_todo_summaries",
+    "get_todos_with_label",
+    "get_todos_due_today",
+    "get_upcoming_by_day",
+    "list_labels",
+    "list_starred",
+    "get_storage_usage",
+    "list_todos",
+    "validate_todo_payload",
+    "get_board",
+    "list_checkpoints",
+    "get_all_todos_filtered",
+    "get_upcoming_reminders",
+    "version",
+    "list_templates",
+    "get_todos_by_week",
+    "list_assigned_to_me",
+    "list_pending_assignments",
+    "list_shared_with_me",
+    "list_shared_with_me_page",
+    "get_shared_todos_changed_since_page",
+    "list_assigned_to_me_page",
+    "list_pending_assignments_page",
+    "get_todos_assigned_to_me_page",
+    "leaderboard_page",
+    "get_shared_todos_changed_since",
+    "get_average_completion_time",
+    "get_shared_todo",
+    "list_transfer_offers",
+    "get_ownership_history",
+    "get_todos_by_statuses",
+    "get_public_todo",
+    "list_public_todos",
+    "browse_public_todos",
+    "list_notifications",
+    "unread_count",
+    "get_priority_history",
+    "get_urgent_unscheduled",
+    "get_remaining_by_priority",
+    "list_webhook_deliveries",
+    "transform_webhook_response",
+    "get_plan_for_capacity",
+    "whoami",
+    "my_profile",
+    "get_my_settings",
+    "get_config",
+    "get_cycles",
+    "admin_list_todos",
+    "admin_verify_integrity",
+    "daily_digest",
+    "search_all",
+    "search_titles_prefix",
+    "my_streak",
+    "get_todos_assigned_to_me",
+    "get_focus_list",
+    "my_score",
+    "leaderboard",
+    "filter_todos_by_status",
+    "get_todos_due_in_range",
+    "get_completed_between",
+    "get_overdue_todos",
+    "get_next_n_due_todos",
+    "get_stale_todos",
+    "list_todos_filtered",
+    "list_saved_filters",
+    "run_saved_filter",
+];
+// Confirmation phrase required to delete all of a user's todos
+const DELETE_ALL_CONFIRMATION: &str = "DELETE";
+// Maximum number of collaborators a todo can be shared with
+const MAX_COLLABORATORS_PER_TODO: usize = 20;
+// Maximum number of templates per user
+const MAX_TEMPLATES_PER_USER: usize = 50;
+// Checkpoint limits
+const MAX_CHECKPOINTS_PER_TODO: usize = 5;
+const MAX_CHECKPOINT_NAME_LENGTH: usize = 50;
+// Default number of todos per board column
+const DEFAULT_BOARD_COLUMN_LIMIT: u64 = 50;
+// Spacing between manual positions, leaving room to reorder without renumbering
+const POSITION_GAP: u64 = 1024;
+// Maximum number of todos listed in a single reorder_todos call
+const MAX_REORDER_ITEMS: usize = 500;
+// Maximum number of todos changed by a single bulk_update_priority call
+const MAX_BULK_PRIORITY_ITEMS: usize = 500;
+// Maximum number of todos a user can have pinned at the same time
+const MAX_PINNED_TODOS: usize = 5;
+// Number of todos encoded to estimate the average stored size
+const STORAGE_SAMPLE_SIZE: usize = 10;
+// Maximum number of todos accepted by a single import
+const MAX_IMPORT_ITEMS: usize = 500;
+// Upper bound for the number of stored todos a single call examines when scanning all todos
+const MAX_SCAN_ENTRIES: usize = 10_000;
+// Secondary indexes in the order an integrity check visits their entries
+const INTEGRITY_INDEXES: [&str; 7] = [
+    "title",
+    "owner",
+    "status",
+    "due date",
+    "title token",
+    "assignee",
+    "collaborator",
+];
+// Bump whenever fold_text or the set of secondary indexes changes, the indexes are then rebuilt
+// after the upgrade
+const INDEX_FORMAT_VERSION: u64 = 3;
+// Timestamps are in nanoseconds since the UNIX epoch
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const NANOS_PER_DAY: u64 = 24 * 60 * 60 * NANOS_PER_SECOND;
+// Range of years accepted by week-based queries
+const MIN_WEEK_YEAR: u32 = 2020;
+const MAX_WEEK_YEAR: u32 = 2100;
+// Real-world UTC offsets range from UTC-12:00 to UTC+14:00
+const MIN_UTC_OFFSET_SECONDS: i64 = -12 * 60 * 60;
+const MAX_UTC_OFFSET_SECONDS: i64 = 14 * 60 * 60;
 
 #[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
 struct Todo {
@@ -20,17 +287,170 @@ struct Todo {
     created_at: u64,
     updated_at: Option<u64>,
     owner: String,
+    color: Option<String>,
+    assignee: Option<Principal>,
+    assignment_state: Option<AssignmentState>,
+    collaborators: Vec<(Principal, Role)>,
+    // Fields each restricted editor may change, editors without an entry may change all of them
+    field_permissions: Vec<(Principal, FieldPermission)>,
+    completed_at: Option<u64>,
+    // Time between creation and completion, in nanoseconds
+    completion_duration: Option<u64>,
+    visibility: Visibility,
+    label_ids: Vec<u64>,
+    pinned: bool,
+    starred: bool,
+    position: u64,
+    reminder_at: Option<u64>,
+    estimated_minutes: Option<u32>,
+    // Incremented on every change, see TodoPayload::expected_version
+    version: u64,
+    // Principal whose call stored the latest change
+    last_modified_by: Option<Principal>,
+    // Frozen todos reject every change until unfrozen
+    frozen: bool,
+    frozen_by: Option<String>,
 }
 
-#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+// Todo as read back from stable memory. Fields added after todos were first stored are
+// optional here, so todos written by earlier versions still decode and get defaults.
+#[derive(candid::CandidType, Deserialize)]
+struct StoredTodo {
+    id: u64,
+    title: String,
+    description: String,
+    status: TaskStatus,
+    priority: Priority,
+    due_date: Option<u64>,
+    created_at: u64,
+    updated_at: Option<u64>,
+    owner: String,
+    color: Option<String>,
+    assignee: Option<Principal>,
+    assignment_state: Option<AssignmentState>,
+    collaborators: Option<Vec<(Principal, Role)>>,
+    field_permissions: Option<Vec<(Principal, FieldPermission)>>,
+    completed_at: Option<u64>,
+    completion_duration: Option<u64>,
+    visibility: Option<Visibility>,
+    label_ids: Option<Vec<u64>>,
+    pinned: Option<bool>,
+    starred: Option<bool>,
+    position: Option<u64>,
+    reminder_at: Option<u64>,
+    estimated_minutes: Option<u32>,
+    version: Option<u64>,
+    last_modified_by: Option<Principal>,
+    frozen: Option<bool>,
+    frozen_by: Option<String>,
+}
+
+impl From<StoredTodo> for Todo {
+    fn from(stored: StoredTodo) -> Self {
+        Todo {
+            id: stored.id,
+            title: stored.title,
+            description: stored.description,
+            status: stored.status,
+            priority: stored.priority,
+            due_date: stored.due_date,
+            created_at: stored.created_at,
+            updated_at: stored.updated_at,
+            owner: stored.owner,
+            color: stored.color,
+            assignee: stored.assignee,
+            assignment_state: stored.assignment_state,
+            collaborators: stored.collaborators.unwrap_or_default(),
+            field_permissions: stored.field_permissions.unwrap_or_default(),
+            completed_at: stored.completed_at,
+            completion_duration: stored.completion_duration,
+            visibility: stored.visibility.unwrap_or_default(),
+            label_ids: stored.label_ids.unwrap_or_default(),
+            pinned: stored.pinned.unwrap_or_default(),
+            starred: stored.starred.unwrap_or_default(),
+            // Todos stored before manual ordering keep their creation order
+            position: stored.position.unwrap_or((stored.id + 1) * POSITION_GAP),
+            reminder_at: stored.reminder_at,
+            estimated_minutes: stored.estimated_minutes,
+            version: stored.version.unwrap_or(0),
+            last_modified_by: stored.last_modified_by,
+            frozen: stored.frozen.unwrap_or_default(),
+            frozen_by: stored.frozen_by,
+        }
+    }
+}
+
+// Lightweight projection of a Todo for rendering list rows, without the description
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct TodoListSummary {
+    id: u64,
+    title: String,
+    status: TaskStatus,
+    priority: Priority,
+    due_date: Option<u64>,
+    completion_percentage: u8,
+    label_ids: Vec<u64>,
+}
+
+impl From<Todo> for TodoListSummary {
+    fn from(todo: Todo) -> Self {
+        // Todos have no subtasks, so completion follows the status
+        let completion_percentage = match todo.status {
+            TaskStatus::Completed => 100,
+            _ => 0,
+        };
+        TodoListSummary {
+            id: todo.id,
+            title: todo.title,
+            status: todo.status,
+            priority: todo.priority,
+            due_date: todo.due_date,
+            completion_percentage,
+            label_ids: todo.label_ids,
+        }
+    }
+}
+
+// Fields to include in a Todo returned by a read query; left out fields come back empty
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct Projection {
+    include_description: bool,
+    include_labels: bool,
+}
+
+impl Default for Projection {
+    fn default() -> Self {
+        Projection {
+            include_description: true,
+            include_labels: true,
+        }
+    }
+}
+
+#[derive(
+    candid::CandidType,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
 enum TaskStatus {
     #[default]
     Pending,
     InProgress,
+    // Blocked on someone or something external, open but not actionable
+    Waiting,
     Completed,
 }
 
-#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+#[derive(
+    candid::CandidType, Clone, Serialize, Deserialize, Default, PartialEq, Eq, PartialOrd, Ord,
+)]
 enum Priority {
     #[default]
     Low,
@@ -39,171 +459,7424 @@ enum Priority {
     Urgent,
 }
 
-// Implement Storable for Todo
-impl Storable for Todo {
-    fn to_bytes(&self) -> std::borrow::Cow<[u8]> {
-        Cow::Owned(Encode!(self).unwrap())
-    }
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, PartialEq)]
+enum AssignmentState {
+    Proposed,
+    Accepted,
+    Declined,
+}
 
-    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
-        Decode!(bytes.as_ref(), Self).unwrap()
-    }
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default, PartialEq)]
+enum Visibility {
+    // Only visible to the owner and the principals it is shared with
+    #[default]
+    Private,
+    // Readable by anyone, including anonymous callers
+    Public,
 }
 
-// Implement BoundedStorable for Todo
-impl BoundedStorable for Todo {
-    const MAX_SIZE: u32 = 2048;
-    const IS_FIXED_SIZE: bool = false;
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, PartialEq)]
+enum Role {
+    // Can read the todo
+    Viewer,
+    // Can read the todo and change its fields and status
+    Editor,
 }
 
-thread_local! {
-    static MEMORY_MANAGER: RefCell<MemoryManager<DefaultMemoryImpl>> = RefCell::new(
-        MemoryManager::init(DefaultMemoryImpl::default())
-    );
+// Fields of a shared todo an editor may change
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, PartialEq)]
+struct FieldPermission {
+    title: bool,
+    description: bool,
+    priority: bool,
+    due_date: bool,
+    status: bool,
+}
 
-    static ID_COUNTER: RefCell<IdCell> = RefCell::new(
-        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(0))), 0)
-            .expect("Cannot create a counter")
-    );
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+enum SortBy {
+    // Manual order set through reorder_todo
+    Manual,
+    // Newest first
+    CreatedAt,
+    // Most recently updated first
+    UpdatedAt,
+    // Earliest due first, todos without due date last
+    DueDate,
+    // Most urgent first
+    Priority,
+}
 
-    static STORAGE: RefCell<StableBTreeMap<u64, Todo, Memory>> =
-        RefCell::new(StableBTreeMap::init(
-            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(1)))
-    ));
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct Label {
+    id: u64,
+    name: String,
+    color: String,
+    owner: String,
 }
 
-#[derive(candid::CandidType, Serialize, Deserialize)]
-struct TodoPayload {
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct Checkpoint {
+    id: u64,
+    todo_id: u64,
+    name: String,
+    snapshot: Todo,
+    created_at: u64,
+}
+
+// Reusable blueprint for creating todos
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct Template {
+    id: u64,
+    owner: String,
+    payload: TodoPayload,
+    label_ids: Vec<u64>,
+    created_at: u64,
+    updated_at: Option<u64>,
+}
+
+// Read-only access to a single todo granted through an unguessable token
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct ShareGrant {
+    todo_id: u64,
+    created_at: u64,
+    expires_at: Option<u64>,
+}
+
+// Pending offer to hand a todo over to another principal
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct TransferOffer {
+    todo_id: u64,
+    from: String,
+    to: Principal,
+    offered_at: u64,
+}
+
+// Todo created for an idempotency key
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct IdempotencyRecord {
+    todo_id: u64,
+    created_at: u64,
+}
+
+// Audit entry for a change of a todo's priority
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct PriorityChange {
+    todo_id: u64,
+    from: Priority,
+    to: Priority,
+    changed_by: String,
+    changed_at: u64,
+}
+
+// Audit entry for an accepted ownership transfer of a todo
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct OwnershipChange {
+    todo_id: u64,
+    from: String,
+    to: Principal,
+    transferred_at: u64,
+}
+
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, PartialEq)]
+enum NotificationKind {
+    Assigned,
+    AssignmentAccepted,
+    AssignmentDeclined,
+}
+
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct Notification {
+    id: u64,
+    recipient: Principal,
+    kind: NotificationKind,
+    todo_id: u64,
+    message: String,
+    created_at: u64,
+    read: bool,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct NotificationPage {
+    notifications: Vec<Notification>,
+    next_cursor: Option<u64>,
+}
+
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, PartialEq)]
+enum EventKind {
+    Created,
+    Completed,
+    Deleted,
+}
+
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct TodoEvent {
+    id: u64,
+    kind: EventKind,
+    todo_id: u64,
     title: String,
-    description: String,
-    priority: Priority,
-    due_date: Option<u64>,
+    owner: String,
+    occurred_at: u64,
 }
 
-#[derive(candid::CandidType, Deserialize, Serialize)]
-enum Error {
-    NotFound { msg: String },
-    InvalidInput { msg: String },
+// A canister that receives events through its on_todo_event method
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct Subscription {
+    subscriber: Principal,
+    callback: Principal,
+    events: Vec<EventKind>,
+    created_at: u64,
 }
 
-#[ic_cdk::query]
-fn get_todo(id: u64) -> Result<Todo, Error> {
-    match _get_todo(&id) {
-        Some(todo) => Ok(todo),
-        None => Err(Error::NotFound {
-            msg: format!("Todo with id={} not found", id),
-        }),
+// An event waiting to be delivered to a subscriber
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct Delivery {
+    subscriber: Principal,
+    callback: Principal,
+    event: TodoEvent,
+    attempts: u32,
+    next_attempt_at: u64,
+}
+
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct Webhook {
+    url: String,
+    secret: String,
+    created_at: u64,
+}
+
+// Outcome of a single webhook call
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct WebhookDelivery {
+    id: u64,
+    todo_id: u64,
+    attempted_at: u64,
+    succeeded: bool,
+    status_code: Option<u32>,
+    error: Option<String>,
+}
+
+// Preferences of a user
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct Settings {
+    // Used by add_todo when the payload keeps the default priority
+    default_priority: Option<Priority>,
+    // Used by add_todo when the payload has no due date
+    default_due_offset_days: Option<u32>,
+    // Used by day-bucketing queries when no offset is passed
+    timezone_offset_minutes: i32,
+    auto_escalate_overdue: bool,
+    trash_retention_days: u32,
+    // Opt-in to appear on the leaderboard, under the display name if set
+    show_on_leaderboard: bool,
+    display_name: Option<String>,
+}
+
+// Settings as read back from stable memory, fields added after settings were first stored are
+// optional here and fall back to the defaults
+#[derive(candid::CandidType, Deserialize)]
+struct StoredSettings {
+    default_priority: Option<Priority>,
+    default_due_offset_days: Option<u32>,
+    timezone_offset_minutes: i32,
+    auto_escalate_overdue: bool,
+    trash_retention_days: u32,
+    show_on_leaderboard: Option<bool>,
+    display_name: Option<String>,
+}
+
+impl From<StoredSettings> for Settings {
+    fn from(stored: StoredSettings) -> Self {
+        Settings {
+            default_priority: stored.default_priority,
+            default_due_offset_days: stored.default_due_offset_days,
+            timezone_offset_minutes: stored.timezone_offset_minutes,
+            auto_escalate_overdue: stored.auto_escalate_overdue,
+            trash_retention_days: stored.trash_retention_days,
+            show_on_leaderboard: stored
+                .show_on_leaderboard
+                .unwrap_or(Settings::default().show_on_leaderboard),
+            display_name: stored.display_name,
+        }
     }
 }
 
-#[ic_cdk::update]
-fn add_todo(payload: TodoPayload) -> Result<Todo, Error> {
-    // Input validation
-    if payload.title.trim().is_empty() {
-        return Err(Error::InvalidInput {
-            msg: "Title cannot be empty".to_string(),
-        });
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            default_priority: None,
+            default_due_offset_days: None,
+            timezone_offset_minutes: 0,
+            auto_escalate_overdue: false,
+            trash_retention_days: DEFAULT_TRASH_RETENTION_DAYS,
+            show_on_leaderboard: false,
+            display_name: None,
+        }
     }
+}
 
-    let id = ID_COUNTER
-        .with(|counter| {
-            let current_value = *counter.borrow().get();
-            counter.borrow_mut().set(current_value + 1)
-        })
-        .expect("cannot increment id counter");
+// Changes to settings, fields left out are kept. For the optional defaults, Some(None) clears them.
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct SettingsPatch {
+    default_priority: Option<Option<Priority>>,
+    default_due_offset_days: Option<Option<u32>>,
+    timezone_offset_minutes: Option<i32>,
+    auto_escalate_overdue: Option<bool>,
+    trash_retention_days: Option<u32>,
+    show_on_leaderboard: Option<bool>,
+    display_name: Option<Option<String>>,
+}
 
-    let todo = Todo {
-        id,
-        title: payload.title,
-        description: payload.description,
-        status: TaskStatus::Pending,
-        priority: payload.priority,
-        due_date: payload.due_date,
-        created_at: time(),
-        updated_at: None,
-        owner: ic_cdk::caller().to_string(),
-    };
+// Consecutive local days with at least one completed todo
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct Streak {
+    current_days: u64,
+    longest_days: u64,
+    // Start of the last local day with a completion, in UTC nanoseconds
+    last_completion_day: Option<u64>,
+}
 
-    do_insert(&todo);
-    Ok(todo)
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, PartialEq)]
+enum Achievement {
+    FirstCompletion,
+    TenCompleted,
+    HundredCompleted,
+    SevenDayStreak,
 }
 
-#[ic_cdk::update]
-fn update_todo(id: u64, payload: TodoPayload) -> Result<Todo, Error> {
-    match STORAGE.with(|service| service.borrow().get(&id)) {
-        Some(mut todo) => {
-            // Verify owner
-            if todo.owner != ic_cdk::caller().to_string() {
-                return Err(Error::NotFound {
-                    msg: format!("Not authorized to update todo with id={}", id),
-                });
-            }
+// Points earned by completing todos, weighted by priority
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct Score {
+    points: u64,
+    completed: u64,
+    achievements: Vec<Achievement>,
+}
 
-            todo.title = payload.title;
-            todo.description = payload.description;
-            todo.priority = payload.priority;
-            todo.due_date = payload.due_date;
-            todo.updated_at = Some(time());
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+enum Period {
+    AllTime,
+    // Since Monday 00:00 UTC
+    ThisWeek,
+    // Since the first of the month 00:00 UTC
+    ThisMonth,
+}
 
-            do_insert(&todo);
-            Ok(todo)
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct LeaderboardEntry {
+    principal: Principal,
+    display_name: Option<String>,
+    completed: u64,
+    points: u64,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct LeaderboardPage {
+    entries: Vec<LeaderboardEntry>,
+    // Last participant examined, pass it back to continue
+    cursor: Option<Principal>,
+    complete: bool,
+}
+
+// Aggregate information about the caller
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct Profile {
+    principal: Principal,
+    total_todos: u64,
+    pending: u64,
+    in_progress: u64,
+    waiting: u64,
+    completed: u64,
+    first_todo_created_at: Option<u64>,
+    settings: Settings,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct CyclesReport {
+    balance: u64,
+    threshold: u64,
+    low: bool,
+}
+
+// One call's worth of a scan over all todos or an index, see scan_todos and page_indexed_todos
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct ScanPage {
+    todos: Vec<Todo>,
+    // Last todo id examined, pass it back to continue the scan
+    cursor: Option<u64>,
+    complete: bool,
+}
+
+// A page of public todos, see browse_public_todos
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct PublicTodoPage {
+    todos: Vec<TodoListSummary>,
+    // Pass it back to continue browsing
+    cursor: Option<u64>,
+    complete: bool,
+}
+
+// One call's worth of an integrity check, see verify_integrity
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct IntegrityReport {
+    todo_count: u64,
+    // Next id the todo counter hands out
+    id_counter: u64,
+    max_todo_id: Option<u64>,
+    // Anomalies found by this call only
+    anomalies: Vec<String>,
+    // Last todo or index entry examined, pass it back to continue the check
+    cursor: Option<IntegrityCursor>,
+    complete: bool,
+}
+
+// Where an integrity check continues: first every todo is checked against the indexes, then the
+// entries of each index in INTEGRITY_INDEXES order are checked against the todos
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+enum IntegrityCursor {
+    // Last todo id examined
+    Todos(u64),
+    // Last entry examined in the named index, its key as stored
+    Index { name: String, key: Vec<u8>, id: u64 },
+}
+
+// Canister-wide settings, provided as init or upgrade argument or through set_config
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct Config {
+    // Only allow Pending -> InProgress -> Completed, and reopening Completed -> InProgress
+    strict_transitions: bool,
+    // Reject a second active todo with the same normalized title for the same owner
+    unique_titles: bool,
+    // Canister notified through on_todo_completed(owner, todo_id) when a todo is completed
+    completion_hook: Option<Principal>,
+    // get_cycles reports the canister as low on cycles below this balance
+    low_cycles_threshold: u64,
+    // Reject todos without a description in add_todo and update_todo
+    require_description: bool,
+    // Largest encoded todo accepted, at most the size of a storage slot
+    max_todo_size: u32,
+    // Number of todos add_todo lets a user own, 0 for no limit
+    max_todos_per_user: u64,
+    // Whether owners may make their todos public
+    allow_public_todos: bool,
+    // Reject todos without a due date in add_todo
+    require_due_date: bool,
+    // Priority of new todos whose payload and owner settings leave it open
+    default_priority: Priority,
+}
+
+// Config as read back from stable memory, fields added after the config was first stored are
+// optional here and fall back to the defaults
+#[derive(candid::CandidType, Deserialize)]
+struct StoredConfig {
+    strict_transitions: bool,
+    unique_titles: Option<bool>,
+    completion_hook: Option<Principal>,
+    low_cycles_threshold: Option<u64>,
+    require_description: Option<bool>,
+    max_todo_size: Option<u32>,
+    max_todos_per_user: Option<u64>,
+    allow_public_todos: Option<bool>,
+    require_due_date: Option<bool>,
+    default_priority: Option<Priority>,
+}
+
+impl From<StoredConfig> for Config {
+    fn from(stored: StoredConfig) -> Self {
+        let defaults = Config::default();
+        Config {
+            strict_transitions: stored.strict_transitions,
+            unique_titles: stored.unique_titles.unwrap_or(defaults.unique_titles),
+            completion_hook: stored.completion_hook,
+            low_cycles_threshold: stored
+                .low_cycles_threshold
+                .unwrap_or(defaults.low_cycles_threshold),
+            require_description: stored
+                .require_description
+                .unwrap_or(defaults.require_description),
+            max_todo_size: stored.max_todo_size.unwrap_or(defaults.max_todo_size),
+            max_todos_per_user: stored
+                .max_todos_per_user
+                .unwrap_or(defaults.max_todos_per_user),
+            allow_public_todos: stored
+                .allow_public_todos
+                .unwrap_or(defaults.allow_public_todos),
+            require_due_date: stored.require_due_date.unwrap_or(defaults.require_due_date),
+            default_priority: stored.default_priority.unwrap_or(defaults.default_priority),
         }
-        None => Err(Error::NotFound {
-            msg: format!("Couldn't update todo with id={}. Todo not found", id),
-        }),
     }
 }
 
-#[ic_cdk::update]
-fn delete_todo(id: u64) -> Result<Todo, Error> {
-    match STORAGE.with(|service| service.borrow_mut().remove(&id)) {
-        Some(todo) => {
-            // Verify owner
-            if todo.owner != ic_cdk::caller().to_string() {
-                return Err(Error::NotFound {
-                    msg: format!("Not authorized to delete todo with id={}", id),
-                });
-            }
-            Ok(todo)
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            strict_transitions: false,
+            unique_titles: false,
+            completion_hook: None,
+            low_cycles_threshold: 0,
+            require_description: false,
+            max_todo_size: Todo::MAX_SIZE,
+            max_todos_per_user: 0,
+            allow_public_todos: true,
+            require_due_date: false,
+            default_priority: Priority::default(),
         }
-        None => Err(Error::NotFound {
-            msg: format!("Couldn't delete todo with id={}. Todo not found.", id),
-        }),
     }
 }
 
-#[ic_cdk::update]
-fn update_status(id: u64, status: TaskStatus) -> Result<Todo, Error> {
-    match STORAGE.with(|service| service.borrow().get(&id)) {
-        Some(mut todo) => {
-            // Verify owner
-            if todo.owner != ic_cdk::caller().to_string() {
-                return Err(Error::NotFound {
-                    msg: format!("Not authorized to update todo with id={}", id),
-                });
-            }
-
-            todo.status = status;
-            todo.updated_at = Some(time());
+// Implement Storable for TaskStatus as a single byte, so it can be part of index keys
+impl Storable for TaskStatus {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        let byte: u8 = match self {
+            TaskStatus::Pending => 0,
+            TaskStatus::InProgress => 1,
+            TaskStatus::Waiting => 2,
+            TaskStatus::Completed => 3,
+        };
+        Cow::Owned(vec![byte])
+    }
 
-            do_insert(&todo);
-            Ok(todo)
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        match bytes[0] {
+            0 => TaskStatus::Pending,
+            1 => TaskStatus::InProgress,
+            2 => TaskStatus::Waiting,
+            3 => TaskStatus::Completed,
+            byte => panic!("invalid task status byte {}", byte),
         }
-        None => Err(Error::NotFound {
-            msg: format!("Couldn't update todo status with id={}. Todo not found", id),
-        }),
     }
 }
 
-// Helper function to insert todo
-fn do_insert(todo: &Todo) {
-    STORAGE.with(|service| service.borrow_mut().insert(todo.id, todo.clone()));
+// Implement BoundedStorable for TaskStatus
+impl BoundedStorable for TaskStatus {
+    const MAX_SIZE: u32 = 1;
+    const IS_FIXED_SIZE: bool = true;
 }
 
-// Helper function to get todo
-fn _get_todo(id: &u64) -> Option<Todo> {
-    STORAGE.with(|service| service.borrow().get(id))
+// Implement Storable for Todo
+impl Storable for Todo {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    // Decoded through StoredTodo, so todos stored before later fields existed still load
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), StoredTodo).unwrap().into()
+    }
 }
 
-// Export Candid interface
-ic_cdk::export_candid!();
+// Implement BoundedStorable for Todo, large enough for the largest todo validation accepts:
+// titles and descriptions of 4 byte characters and the maximum number of collaborators
+impl BoundedStorable for Todo {
+    const MAX_SIZE: u32 = 8192;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Label
+impl Storable for Label {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for Label
+impl BoundedStorable for Label {
+    const MAX_SIZE: u32 = 512;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for SavedFilter
+impl Storable for SavedFilter {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for SavedFilter
+impl BoundedStorable for SavedFilter {
+    const MAX_SIZE: u32 = 4096;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Checkpoint
+impl Storable for Checkpoint {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for Checkpoint, large enough to hold a full Todo snapshot
+impl BoundedStorable for Checkpoint {
+    const MAX_SIZE: u32 = Todo::MAX_SIZE + 512;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Template
+impl Storable for Template {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for Template
+impl BoundedStorable for Template {
+    const MAX_SIZE: u32 = 2048;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for ShareGrant
+impl Storable for ShareGrant {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for ShareGrant
+impl BoundedStorable for ShareGrant {
+    const MAX_SIZE: u32 = 128;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for TransferOffer
+impl Storable for TransferOffer {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for TransferOffer
+impl BoundedStorable for TransferOffer {
+    const MAX_SIZE: u32 = 256;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for IdempotencyRecord
+impl Storable for IdempotencyRecord {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for IdempotencyRecord
+impl BoundedStorable for IdempotencyRecord {
+    const MAX_SIZE: u32 = 64;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Subscription
+impl Storable for Subscription {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for Subscription
+impl BoundedStorable for Subscription {
+    const MAX_SIZE: u32 = 256;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Delivery
+impl Storable for Delivery {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for Delivery, event titles are cut to MAX_EVENT_TITLE_BYTES to fit
+impl BoundedStorable for Delivery {
+    const MAX_SIZE: u32 = 1024;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Webhook
+impl Storable for Webhook {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for Webhook
+impl BoundedStorable for Webhook {
+    const MAX_SIZE: u32 = 1024;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for WebhookDelivery
+impl Storable for WebhookDelivery {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for WebhookDelivery
+impl BoundedStorable for WebhookDelivery {
+    const MAX_SIZE: u32 = 1024;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Settings
+impl Storable for Settings {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    // Decoded through StoredSettings, so settings stored before later fields existed still load
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), StoredSettings).unwrap().into()
+    }
+}
+
+// Implement BoundedStorable for Settings
+impl BoundedStorable for Settings {
+    const MAX_SIZE: u32 = 512;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Streak
+impl Storable for Streak {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for Streak
+impl BoundedStorable for Streak {
+    const MAX_SIZE: u32 = 128;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Score
+impl Storable for Score {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for Score
+impl BoundedStorable for Score {
+    const MAX_SIZE: u32 = 128;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for PriorityChange
+impl Storable for PriorityChange {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for PriorityChange
+impl BoundedStorable for PriorityChange {
+    const MAX_SIZE: u32 = 256;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for OwnershipChange
+impl Storable for OwnershipChange {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for OwnershipChange
+impl BoundedStorable for OwnershipChange {
+    const MAX_SIZE: u32 = 256;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Notification
+impl Storable for Notification {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+}
+
+// Implement BoundedStorable for Notification, notify cuts messages to fit
+impl BoundedStorable for Notification {
+    const MAX_SIZE: u32 = 512;
+    const IS_FIXED_SIZE: bool = false;
+}
+
+// Implement Storable for Config
+impl Storable for Config {
+    fn to_bytes(&self) -> std::borrow::Cow<'_, [u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    // Decoded through StoredConfig, so a config stored before later fields existed still loads
+    fn from_bytes(bytes: std::borrow::Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), StoredConfig).unwrap().into()
+    }
+}
+
+thread_local! {
+    static MEMORY_MANAGER: RefCell<MemoryManager<DefaultMemoryImpl>> = RefCell::new(
+        MemoryManager::init(DefaultMemoryImpl::default())
+    );
+
+    static ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(0))), 0)
+            .expect("Cannot create a counter")
+    );
+
+    static STORAGE: RefCell<StableBTreeMap<u64, Todo, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(1)))
+    ));
+
+    static LABEL_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(2))), 0)
+            .expect("Cannot create a label counter")
+    );
+
+    static LABELS: RefCell<StableBTreeMap<u64, Label, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(3)))
+    ));
+
+    static CHECKPOINT_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(4))), 0)
+            .expect("Cannot create a checkpoint counter")
+    );
+
+    static CHECKPOINTS: RefCell<StableBTreeMap<u64, Checkpoint, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(5)))
+    ));
+
+    static TEMPLATE_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(6))), 0)
+            .expect("Cannot create a template counter")
+    );
+
+    static TEMPLATES: RefCell<StableBTreeMap<u64, Template, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(7)))
+    ));
+
+    static SHARE_TOKENS: RefCell<StableBTreeMap<[u8; SHARE_TOKEN_BYTES], ShareGrant, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(8)))
+    ));
+
+    static CONFIG: RefCell<Cell<Config, Memory>> = RefCell::new(
+        Cell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(9))), Config::default())
+            .expect("Cannot create the config cell")
+    );
+
+    static TRANSFER_OFFERS: RefCell<StableBTreeMap<u64, TransferOffer, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(10)))
+    ));
+
+    // Keyed by (caller, idempotency key)
+    static IDEMPOTENCY_KEYS: RefCell<
+        StableBTreeMap<(PrincipalKey, Blob<MAX_IDEMPOTENCY_KEY_LENGTH>), IdempotencyRecord, Memory>
+    > = RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(11)))
+    ));
+
+    static NOTIFICATION_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(12))), 0)
+            .expect("Cannot create a notification counter")
+    );
+
+    static NOTIFICATIONS: RefCell<StableBTreeMap<u64, Notification, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(13)))
+    ));
+
+    // Keyed by (recipient, notification id)
+    static NOTIFICATION_INDEX: RefCell<StableBTreeMap<(PrincipalKey, u64), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(14)))
+    ));
+
+    static PRIORITY_CHANGE_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(15))), 0)
+            .expect("Cannot create a priority change counter")
+    );
+
+    // Keyed by (todo id, change id) so a todo's history is a contiguous range
+    static PRIORITY_HISTORY: RefCell<StableBTreeMap<(u64, u64), PriorityChange, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(16)))
+    ));
+
+    static SUBSCRIPTIONS: RefCell<StableBTreeMap<PrincipalKey, Subscription, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(17)))
+    ));
+
+    static EVENT_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(18))), 0)
+            .expect("Cannot create an event counter")
+    );
+
+    static DELIVERY_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(19))), 0)
+            .expect("Cannot create a delivery counter")
+    );
+
+    static DELIVERY_QUEUE: RefCell<StableBTreeMap<u64, Delivery, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(20)))
+    ));
+
+    // Keyed by (owner, todo id) so an owner's todos are a contiguous range
+    static OWNER_INDEX: RefCell<StableBTreeMap<(PrincipalKey, u64), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(29)))
+    ));
+
+    // Keyed by ((owner, status), todo id) so an owner's todos in a status are a contiguous range
+    static STATUS_INDEX: RefCell<StableBTreeMap<(StatusKey, u64), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(30)))
+    ));
+
+    // Keyed by ((owner, due date), todo id), todos without a due date are not indexed
+    static DUE_DATE_INDEX: RefCell<StableBTreeMap<(DueKey, u64), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(31)))
+    ));
+
+    // Keyed by (hash of owner and normalized title, todo id), only active todos are indexed
+    static TITLE_INDEX: RefCell<StableBTreeMap<(TitleKey, u64), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(21)))
+    ));
+
+    // Keyed by ((owner, title word), todo id), for prefix search over titles
+    static TITLE_TOKEN_INDEX: RefCell<StableBTreeMap<(OwnerTokenKey, u64), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(32)))
+    ));
+
+    static SAVED_FILTER_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(33))), 0)
+            .expect("Cannot create a saved filter counter")
+    );
+
+    static SAVED_FILTERS: RefCell<StableBTreeMap<u64, SavedFilter, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(34)))
+    ));
+
+    // The INDEX_FORMAT_VERSION the secondary indexes were last completely rebuilt with, anything
+    // else while a rebuild is pending
+    static INDEX_VERSION: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(35))), 0)
+            .expect("Cannot create the index version")
+    );
+
+    // Keyed by (owner, todo id in an imported document), the id the import stored it under
+    static IMPORTED_IDS: RefCell<StableBTreeMap<(PrincipalKey, u64), u64, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(36)))
+    ));
+
+    static OWNERSHIP_CHANGE_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(37))), 0)
+            .expect("Cannot create an ownership change counter")
+    );
+
+    // Keyed by (todo id, change id) so a todo's history is a contiguous range
+    static OWNERSHIP_HISTORY: RefCell<StableBTreeMap<(u64, u64), OwnershipChange, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(38)))
+    ));
+
+    // Keyed by (todo id, checkpoint id) so a todo's checkpoints are a contiguous range
+    static CHECKPOINT_INDEX: RefCell<StableBTreeMap<(u64, u64), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(39)))
+    ));
+
+    // Keyed by (todo id, share token) so the tokens pointing at a todo are a contiguous range
+    static SHARE_TOKEN_INDEX: RefCell<StableBTreeMap<(u64, [u8; SHARE_TOKEN_BYTES]), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(40)))
+    ));
+
+    // Keyed by (assignee, todo id), todos without an assignee are not indexed
+    static ASSIGNEE_INDEX: RefCell<StableBTreeMap<(PrincipalKey, u64), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(41)))
+    ));
+
+    // Keyed by (collaborator, todo id) so the todos shared with a principal are a contiguous range
+    static COLLABORATOR_INDEX: RefCell<StableBTreeMap<(PrincipalKey, u64), (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(42)))
+    ));
+
+    // When each todo was last posted to a completion webhook, see throttle_webhook. Kept in
+    // stable memory so an upgrade doesn't lift the debounce.
+    static WEBHOOK_SENT_AT: RefCell<StableBTreeMap<u64, u64, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(43)))
+    ));
+
+    // Each owner's current webhook window start and number of posts, see throttle_webhook
+    static WEBHOOK_WINDOWS: RefCell<StableBTreeMap<PrincipalKey, (u64, u32), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(44)))
+    ));
+
+    static WEBHOOKS: RefCell<StableBTreeMap<PrincipalKey, Webhook, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(22)))
+    ));
+
+    static WEBHOOK_DELIVERY_ID_COUNTER: RefCell<IdCell> = RefCell::new(
+        IdCell::init(MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(23))), 0)
+            .expect("Cannot create a webhook delivery counter")
+    );
+
+    // Keyed by (owner, delivery id)
+    static WEBHOOK_LOG: RefCell<StableBTreeMap<(PrincipalKey, u64), WebhookDelivery, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(24)))
+    ));
+
+    static SETTINGS: RefCell<StableBTreeMap<PrincipalKey, Settings, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(25)))
+    ));
+
+    // Updated on every completion so reading a streak never scans history
+    static STREAKS: RefCell<StableBTreeMap<PrincipalKey, Streak, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(26)))
+    ));
+
+    static SCORES: RefCell<StableBTreeMap<PrincipalKey, Score, Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(27)))
+    ));
+
+    // Todos that already earned points, kept after deletion so points are never awarded twice
+    static AWARDED_TODOS: RefCell<StableBTreeMap<u64, (), Memory>> =
+        RefCell::new(StableBTreeMap::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(MemoryId::new(28)))
+    ));
+
+    // Deliveries with a call in progress, so the delivery timer doesn't send them twice
+    static IN_FLIGHT_DELIVERIES: RefCell<std::collections::BTreeSet<u64>> = RefCell::default();
+
+
+    // When the timer fires next to process deliveries or rebuild the indexes, and its id.
+    // Timers don't survive an upgrade, post_upgrade arms it again.
+    static TIMER: RefCell<Option<(u64, TimerId)>> = RefCell::default();
+
+    // Where the index rebuild continues. An upgrade restarts the rebuild from the beginning.
+    static INDEX_REBUILD_CURSOR: RefCell<Option<IntegrityCursor>> = RefCell::default();
+}
+
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct TodoPayload {
+    title: String,
+    description: String,
+    priority: Priority,
+    due_date: Option<u64>,
+    color: Option<String>,
+    estimated_minutes: Option<u32>,
+    // Retrying add_todo with the same key returns the todo created by the first call
+    idempotency_key: Option<String>,
+    // update_todo only applies the change while the todo is still at this version
+    expected_version: Option<u64>,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct LabelPayload {
+    name: String,
+    color: String,
+}
+
+// Todo as it appears in an exported JSON document. Only the title is required.
+#[derive(Deserialize)]
+struct ImportedTodo {
+    id: Option<u64>,
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    status: TaskStatus,
+    #[serde(default)]
+    priority: Priority,
+    #[serde(default)]
+    due_date: Option<u64>,
+    #[serde(default)]
+    color: Option<String>,
+    #[serde(default)]
+    estimated_minutes: Option<u32>,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct MergeReport {
+    created: u64,
+    updated: u64,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct StorageUsage {
+    todo_count: u64,
+    estimated_bytes: u64,
+    max_bytes: u64,
+}
+
+// Conditions a todo must meet to be listed; empty lists and None leave a dimension open
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct FilterCriteria {
+    statuses: Vec<TaskStatus>,
+    priorities: Vec<Priority>,
+    // Todos with any or all of these labels, see label_match; unknown label ids match nothing
+    label_ids: Vec<u64>,
+    // Defaults to Any
+    label_match: Option<LabelMatch>,
+    due_after: Option<u64>,
+    due_before: Option<u64>,
+    created_after: Option<u64>,
+    created_before: Option<u64>,
+    has_due_date: Option<bool>,
+    // Matched against the title and description, ignoring case and accents
+    text: Option<String>,
+}
+
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+enum LabelMatch {
+    Any,
+    All,
+}
+
+#[derive(candid::CandidType, Clone, Serialize, Deserialize)]
+struct SortSpec {
+    by: SortBy,
+    // Flips the order of `by`
+    reverse: bool,
+}
+
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, Default)]
+struct SavedFilter {
+    id: u64,
+    name: String,
+    criteria: FilterCriteria,
+    owner: String,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize)]
+struct SavedFilterPayload {
+    name: String,
+    criteria: FilterCriteria,
+}
+
+// Page of todos for cursor based pagination; next_cursor is passed back to get the next page
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct TodoPage {
+    todos: Vec<Todo>,
+    next_cursor: Option<u64>,
+}
+
+// The value a todo is ordered by for a sort, see sort_order
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, PartialEq)]
+enum SortKey {
+    Position(u64),
+    CreatedAt(u64),
+    UpdatedAt(u64),
+    DueDate(Option<u64>),
+    Priority(Priority),
+}
+
+// Where a filtered listing continues: the sort key and id of the last todo returned, so the
+// next page doesn't depend on that todo still existing or keeping its place
+#[derive(candid::CandidType, Clone, Serialize, Deserialize, PartialEq)]
+struct FilterCursor {
+    // None when the listing is in id order
+    key: Option<SortKey>,
+    id: u64,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct FilteredTodoPage {
+    todos: Vec<Todo>,
+    next_cursor: Option<FilterCursor>,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct DigestSection {
+    todos: Vec<Todo>,
+    has_more: bool,
+}
+
+// Overview of a single local day
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct Digest {
+    day_start: u64,
+    due_today: DigestSection,
+    overdue: DigestSection,
+    completed_yesterday: DigestSection,
+    unread_notifications: u64,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct BoardColumn {
+    todos: Vec<Todo>,
+    has_more: bool,
+}
+
+#[derive(candid::CandidType, Serialize, Deserialize, Default)]
+struct Board {
+    pending: BoardColumn,
+    in_progress: BoardColumn,
+    waiting: BoardColumn,
+    completed: BoardColumn,
+}
+
+#[derive(candid::CandidType, Deserialize, Serialize)]
+enum Error {
+    NotFound { msg: String },
+    InvalidInput { msg: String },
+    Unauthorized { msg: String },
+    InvalidTransition { msg: String },
+    Conflict { msg: String },
+    VersionConflict { current_version: u64 },
+}
+
+#[ic_cdk::init]
+fn init(config: Option<Config>) {
+    if let Some(config) = config {
+        if let Err(Error::InvalidInput { msg }) = validate_config(&config) {
+            ic_cdk::trap(&msg);
+        }
+        _set_config(config);
+    }
+    set_index_version();
+}
+
+#[ic_cdk::post_upgrade]
+fn post_upgrade(config: Option<Config>) {
+    // Keep the stored config unless the upgrade provides a new one
+    if let Some(config) = config {
+        if let Err(Error::InvalidInput { msg }) = validate_config(&config) {
+            ic_cdk::trap(&msg);
+        }
+        _set_config(config);
+    }
+
+    // Checkpoints saved before the checkpoint index existed
+    if CHECKPOINT_INDEX.with(|index| index.borrow().is_empty()) {
+        CHECKPOINTS.with(|checkpoints| {
+            CHECKPOINT_INDEX.with(|index| {
+                let mut index = index.borrow_mut();
+                for (id, checkpoint) in checkpoints.borrow().iter() {
+                    index.insert((checkpoint.todo_id, id), ());
+                }
+            })
+        });
+    }
+
+    // Share tokens created before the share token index existed
+    if SHARE_TOKEN_INDEX.with(|index| index.borrow().is_empty()) {
+        SHARE_TOKENS.with(|tokens| {
+            SHARE_TOKEN_INDEX.with(|index| {
+                let mut index = index.borrow_mut();
+                for (token, grant) in tokens.borrow().iter() {
+                    index.insert((grant.todo_id, token), ());
+                }
+            })
+        });
+    }
+
+    // Todos stored before a secondary index existed, or indexed with an older fold_text, are
+    // indexed by the timer in budgeted batches, see rebuild_indexes. Rebuilding here could run
+    // out of instructions, and a trapped upgrade leaves the canister on the old code.
+    let has_todos = STORAGE.with(|service| !service.borrow().is_empty());
+    let missing_index = TITLE_INDEX.with(|index| index.borrow().is_empty())
+        || OWNER_INDEX.with(|index| index.borrow().is_empty())
+        || STATUS_INDEX.with(|index| index.borrow().is_empty())
+        || DUE_DATE_INDEX.with(|index| index.borrow().is_empty())
+        || TITLE_TOKEN_INDEX.with(|index| index.borrow().is_empty());
+    if has_todos && missing_index {
+        INDEX_VERSION
+            .with(|version| version.borrow_mut().set(0))
+            .expect("cannot set the index version");
+    }
+    if indexes_stale() {
+        arm_timer(time());
+    }
+
+    // Deliveries that were waiting for the timer before the upgrade
+    let next_attempt = DELIVERY_QUEUE.with(|queue| {
+        queue
+            .borrow()
+            .iter()
+            .map(|(_, delivery)| delivery.next_attempt_at)
+            .min()
+    });
+    if let Some(at) = next_attempt {
+        arm_timer(at);
+    }
+}
+
+#[ic_cdk::query]
+fn get_todo(id: u64, projection: Option<Projection>) -> Result<Todo, Error> {
+    match _get_todo(&id) {
+        // Public todos are readable by anyone, including anonymous callers
+        Some(mut todo)
+            if todo.visibility == Visibility::Public || can_view(&todo, &ic_cdk::caller()) =>
+        {
+            project(&mut todo, &projection.unwrap_or_default());
+            Ok(todo)
+        }
+        Some(_) => Err(Error::Unauthorized {
+            msg: format!("Not authorized to view todo with id={}", id),
+        }),
+        None => Err(Error::NotFound {
+            msg: format!("Todo with id={} not found", id),
+        }),
+    }
+}
+
+#[ic_cdk::query]
+fn get_todo_by_title(title: String) -> Option<Todo> {
+    // Ids grow with creation, so the last match is newest
+    let id = _get_active_title_ids(&ic_cdk::caller().to_string(), &title)
+        .last()
+        .copied()?;
+    _get_todo(&id)
+}
+
+#[ic_cdk::update]
+fn add_todo(mut payload: TodoPayload) -> Result<Todo, Error> {
+    let key = match &payload.idempotency_key {
+        Some(key) => {
+            let key = idempotency_key(&ic_cdk::caller(), key)?;
+            if let Some(todo) = _get_idempotent_todo(&key) {
+                return Ok(todo);
+            }
+            Some(key)
+        }
+        None => None,
+    };
+
+    let config = _get_config();
+
+    // Fill in the caller's defaults for what the payload leaves open, then the canister's
+    let settings = _get_settings(&ic_cdk::caller());
+    if payload.priority == Priority::default() {
+        payload.priority = settings.default_priority.unwrap_or(config.default_priority);
+    }
+    if payload.due_date.is_none() {
+        if let Some(days) = settings.default_due_offset_days {
+            payload.due_date = Some(time() + days as u64 * NANOS_PER_DAY);
+        }
+    }
+
+    let todo = create_todo(payload, Vec::new())?;
+
+    if let Some(key) = key {
+        let record = IdempotencyRecord {
+            todo_id: todo.id,
+            created_at: todo.created_at,
+        };
+        IDEMPOTENCY_KEYS.with(|keys| keys.borrow_mut().insert(key, record));
+    }
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn update_todo(id: u64, payload: TodoPayload) -> Result<Todo, Error> {
+    match STORAGE.with(|service| service.borrow().get(&id)) {
+        Some(mut todo) => {
+            // Verify owner or editor
+            if !can_edit(&todo, &ic_cdk::caller()) {
+                return Err(Error::NotFound {
+                    msg: format!("Not authorized to update todo with id={}", id),
+                });
+            }
+
+            if matches!(payload.expected_version, Some(version) if version != todo.version) {
+                return Err(Error::VersionConflict {
+                    current_version: todo.version,
+                });
+            }
+
+            // Restricted editors may only change the fields they were granted
+            let permission = field_permission(&todo, &ic_cdk::caller());
+            let restricted: Vec<&str> = [
+                ("title", permission.title || payload.title == todo.title),
+                (
+                    "description",
+                    permission.description || payload.description == todo.description,
+                ),
+                (
+                    "priority",
+                    permission.priority || payload.priority == todo.priority,
+                ),
+                (
+                    "due_date",
+                    permission.due_date || payload.due_date == todo.due_date,
+                ),
+            ]
+            .into_iter()
+            .filter(|(_, allowed)| !allowed)
+            .map(|(field, _)| field)
+            .collect();
+            if !restricted.is_empty() {
+                return Err(Error::Unauthorized {
+                    msg: format!(
+                        "Not authorized to change {} of todo with id={}",
+                        restricted.join(", "),
+                        id
+                    ),
+                });
+            }
+
+            // Input validation, an unchanged due date may already be in the past
+            validate_title(&payload.title)?;
+            validate_description(&payload.description)?;
+            check_description_required(&payload.description)?;
+            validate_color(&payload.color)?;
+            validate_estimate(payload.estimated_minutes)?;
+            if payload.due_date != todo.due_date {
+                validate_due_date(payload.due_date)?;
+            }
+
+            let now = time();
+            let previous_priority = todo.priority.clone();
+
+            todo.title = payload.title;
+            todo.description = payload.description;
+            todo.priority = payload.priority;
+            todo.due_date = payload.due_date;
+            todo.color = payload.color;
+            todo.estimated_minutes = payload.estimated_minutes;
+            todo.updated_at = Some(now);
+
+            do_insert_tracking_priority(&mut todo, &previous_priority, now)?;
+            Ok(todo)
+        }
+        None => Err(Error::NotFound {
+            msg: format!("Couldn't update todo with id={}. Todo not found", id),
+        }),
+    }
+}
+
+#[ic_cdk::update]
+fn rename_todo(id: u64, title: String) -> Result<Todo, Error> {
+    validate_title(&title)?;
+
+    match STORAGE.with(|service| service.borrow().get(&id)) {
+        Some(mut todo) => {
+            // Verify owner
+            if todo.owner != ic_cdk::caller().to_string() {
+                return Err(Error::NotFound {
+                    msg: format!("Not authorized to rename todo with id={}", id),
+                });
+            }
+
+            todo.title = title;
+            todo.updated_at = Some(time());
+
+            do_insert(&mut todo)?;
+            Ok(todo)
+        }
+        None => Err(Error::NotFound {
+            msg: format!("Couldn't rename todo with id={}. Todo not found", id),
+        }),
+    }
+}
+
+#[ic_cdk::update]
+fn delete_todo(id: u64) -> Result<Todo, Error> {
+    match STORAGE.with(|service| service.borrow().get(&id)) {
+        Some(todo) => {
+            // Verify owner
+            if todo.owner != ic_cdk::caller().to_string() {
+                return Err(Error::NotFound {
+                    msg: format!("Not authorized to delete todo with id={}", id),
+                });
+            }
+            if todo.frozen {
+                return Err(Error::InvalidInput {
+                    msg: "Todo is frozen".to_string(),
+                });
+            }
+
+            do_remove(id);
+            remove_imported_ids(&owner_principal(&todo), &[id].into());
+            Ok(todo)
+        }
+        None => Err(Error::NotFound {
+            msg: format!("Couldn't delete todo with id={}. Todo not found.", id),
+        }),
+    }
+}
+
+#[ic_cdk::update]
+fn bulk_update_priority(
+    ids: Vec<u64>,
+    priority: Priority,
+) -> Result<Vec<Result<u64, Error>>, Error> {
+    if ids.len() > MAX_BULK_PRIORITY_ITEMS {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Cannot change the priority of more than {} todos at once",
+                MAX_BULK_PRIORITY_ITEMS
+            ),
+        });
+    }
+
+    let results = ids
+        .into_iter()
+        .map(|id| {
+            let mut todo = _get_owned_todo(id, "change priority of")?;
+            if todo.priority == priority {
+                return Ok(id);
+            }
+
+            let now = time();
+            let previous_priority = todo.priority.clone();
+            todo.priority = priority.clone();
+            todo.updated_at = Some(now);
+
+            do_insert_tracking_priority(&mut todo, &previous_priority, now)?;
+            Ok(id)
+        })
+        .collect();
+    Ok(results)
+}
+
+#[ic_cdk::update]
+fn update_status(id: u64, status: TaskStatus) -> Result<Todo, Error> {
+    match STORAGE.with(|service| service.borrow().get(&id)) {
+        Some(mut todo) => {
+            // Verify owner, editor or accepted assignee
+            if !can_change_status(&todo, &ic_cdk::caller()) {
+                return Err(Error::NotFound {
+                    msg: format!("Not authorized to update todo with id={}", id),
+                });
+            }
+
+            if !field_permission(&todo, &ic_cdk::caller()).status {
+                return Err(Error::Unauthorized {
+                    msg: format!("Not authorized to change status of todo with id={}", id),
+                });
+            }
+
+            check_transition(&todo.status, &status)?;
+
+            let completed = todo.status != TaskStatus::Completed && status == TaskStatus::Completed;
+            let now = time();
+            set_status(&mut todo, status, now);
+            todo.updated_at = Some(now);
+
+            do_insert(&mut todo)?;
+            if completed {
+                // Runs after this call has committed its changes, the caller doesn't wait for it
+                ic_cdk::spawn(send_completion_webhook(todo.clone()));
+                ic_cdk::spawn(call_completion_hook(todo.owner.clone(), todo.id));
+            }
+            Ok(todo)
+        }
+        None => Err(Error::NotFound {
+            msg: format!("Couldn't update todo status with id={}. Todo not found", id),
+        }),
+    }
+}
+
+#[ic_cdk::query]
+fn get_recently_updated(limit: u64, projection: Option<Projection>) -> Result<Vec<Todo>, Error> {
+    if limit == 0 {
+        return Err(Error::InvalidInput {
+            msg: "Limit must be greater than 0".to_string(),
+        });
+    }
+    let limit = limit.min(MAX_RECENT_LIMIT) as usize;
+
+    let mut todos = _get_owner_todos(&ic_cdk::caller().to_string());
+    // Most recent activity first; todos that were never updated count from creation
+    sort_todos(&mut todos, &SortBy::UpdatedAt);
+    todos.truncate(limit);
+    Ok(project_all(todos, projection))
+}
+
+#[ic_cdk::query]
+fn list_todo_summaries(offset: u64, limit: u64) -> Vec<TodoListSummary> {
+    let limit = limit.min(MAX_PAGE_LIMIT) as usize;
+
+    let mut todos = _get_owner_todos(&ic_cdk::caller().to_string());
+    pinned_first(&mut todos);
+
+    todos
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit)
+        .map(TodoListSummary::from)
+        .collect()
+}
+
+#[ic_cdk::query]
+fn get_todos_with_label(label_id: u64, offset: u64, limit: u64) -> Vec<TodoListSummary> {
+    let limit = limit.min(MAX_PAGE_LIMIT) as usize;
+
+    let mut todos: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| todo.label_ids.contains(&label_id))
+        .collect();
+    pinned_first(&mut todos);
+
+    todos
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit)
+        .map(TodoListSummary::from)
+        .collect()
+}
+
+#[ic_cdk::query]
+fn get_todos_due_today(
+    utc_offset_seconds: Option<i64>,
+    projection: Option<Projection>,
+) -> Result<Vec<Todo>, Error> {
+    let utc_offset_seconds = utc_offset_or_default(utc_offset_seconds);
+    validate_utc_offset(utc_offset_seconds)?;
+
+    let day_start = local_day_start(time(), utc_offset_seconds);
+    let day_end = day_start + NANOS_PER_DAY;
+
+    Ok(project_all(
+        _get_owner_todos(&ic_cdk::caller().to_string())
+            .into_iter()
+            .filter(|todo| matches!(todo.due_date, Some(due) if due >= day_start && due < day_end))
+            .collect(),
+        projection,
+    ))
+}
+
+#[ic_cdk::query]
+fn get_upcoming_by_day(
+    days: u64,
+    utc_offset_seconds: Option<i64>,
+    projection: Option<Projection>,
+) -> Result<Vec<(u64, Vec<Todo>)>, Error> {
+    let utc_offset_seconds = utc_offset_or_default(utc_offset_seconds);
+    validate_utc_offset(utc_offset_seconds)?;
+    if days == 0 || days > MAX_AGENDA_DAYS {
+        return Err(Error::InvalidInput {
+            msg: format!("Days must be between 1 and {}", MAX_AGENDA_DAYS),
+        });
+    }
+
+    // One bucket per local day starting today, empty days included
+    let first_day = local_day_start(time(), utc_offset_seconds);
+    let mut agenda: Vec<(u64, Vec<Todo>)> = (0..days)
+        .map(|day| (first_day + day * NANOS_PER_DAY, Vec::new()))
+        .collect();
+
+    let mut todos: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| todo.status != TaskStatus::Completed)
+        .collect();
+    todos.sort_by_key(|todo| todo.due_date);
+    let todos = project_all(todos, projection);
+    for todo in todos {
+        let Some(due) = todo.due_date else { continue };
+        if due < first_day {
+            continue;
+        }
+        if let Some((_, bucket)) = agenda.get_mut(((due - first_day) / NANOS_PER_DAY) as usize) {
+            bucket.push(todo);
+        }
+    }
+    Ok(agenda)
+}
+
+#[ic_cdk::update]
+fn complete_and_create_next(id: u64, next_due_date: Option<u64>) -> Result<(Todo, Todo), Error> {
+    match _get_todo(&id) {
+        Some(mut todo) => {
+            // Verify owner
+            if todo.owner != ic_cdk::caller().to_string() {
+                return Err(Error::NotFound {
+                    msg: format!("Not authorized to complete todo with id={}", id),
+                });
+            }
+
+            if todo.status == TaskStatus::Completed {
+                return Err(Error::InvalidInput {
+                    msg: format!("Todo with id={} is already completed", id),
+                });
+            }
+            check_transition(&todo.status, &TaskStatus::Completed)?;
+            validate_due_date(next_due_date)?;
+            check_due_date_required(next_due_date)?;
+            check_todo_limit(&ic_cdk::caller(), 1)?;
+
+            let now = time();
+            set_status(&mut todo, TaskStatus::Completed, now);
+            todo.updated_at = Some(now);
+
+            // Only the content carries over, sharing and assignment start afresh
+            let mut next = Todo {
+                id: next_id(&ID_COUNTER),
+                title: todo.title.clone(),
+                description: todo.description.clone(),
+                status: TaskStatus::Pending,
+                priority: todo.priority.clone(),
+                due_date: next_due_date,
+                created_at: now,
+                updated_at: None,
+                owner: todo.owner.clone(),
+                color: None,
+                assignee: None,
+                assignment_state: None,
+                collaborators: Vec::new(),
+                field_permissions: Vec::new(),
+                completed_at: None,
+                completion_duration: None,
+                visibility: Visibility::Private,
+                label_ids: Vec::new(),
+                pinned: false,
+                starred: false,
+                reminder_at: None,
+                version: 0,
+                last_modified_by: None,
+                frozen: false,
+                frozen_by: None,
+                estimated_minutes: None,
+                position: next_position(&todo.owner),
+            };
+
+            // An error doesn't undo earlier writes, so the checks the new todo has to pass run
+            // before the completion is stored
+            check_todo_size(&next)?;
+            check_unique_title(&next, &[todo.id])?;
+
+            do_insert(&mut todo)?;
+            do_insert(&mut next)?;
+            // Runs after this call has committed its changes, the caller doesn't wait for it
+            ic_cdk::spawn(send_completion_webhook(todo.clone()));
+            ic_cdk::spawn(call_completion_hook(todo.owner.clone(), todo.id));
+            Ok((todo, next))
+        }
+        None => Err(Error::NotFound {
+            msg: format!("Couldn't complete todo with id={}. Todo not found", id),
+        }),
+    }
+}
+
+#[ic_cdk::query]
+fn list_labels() -> Vec<Label> {
+    _get_owner_labels(&ic_cdk::caller().to_string())
+}
+
+#[ic_cdk::update]
+fn create_label(payload: LabelPayload) -> Result<Label, Error> {
+    let owner = ic_cdk::caller().to_string();
+    validate_label_payload(&payload)?;
+
+    let labels = _get_owner_labels(&owner);
+    if labels.len() >= MAX_LABELS_PER_USER {
+        return Err(Error::InvalidInput {
+            msg: format!("Cannot create more than {} labels", MAX_LABELS_PER_USER),
+        });
+    }
+    ensure_unique_label_name(&labels, &payload.name, None)?;
+
+    let id = next_id(&LABEL_ID_COUNTER);
+
+    let label = Label {
+        id,
+        name: payload.name,
+        color: payload.color,
+        owner,
+    };
+
+    LABELS.with(|labels| labels.borrow_mut().insert(label.id, label.clone()));
+    Ok(label)
+}
+
+#[ic_cdk::update]
+fn update_label(id: u64, payload: LabelPayload) -> Result<Label, Error> {
+    let owner = ic_cdk::caller().to_string();
+    validate_label_payload(&payload)?;
+
+    let mut label = _get_owned_label(id, &owner)?;
+    ensure_unique_label_name(&_get_owner_labels(&owner), &payload.name, Some(id))?;
+
+    label.name = payload.name;
+    label.color = payload.color;
+
+    LABELS.with(|labels| labels.borrow_mut().insert(label.id, label.clone()));
+    Ok(label)
+}
+
+#[ic_cdk::update]
+fn delete_label(id: u64) -> Result<Label, Error> {
+    let owner = ic_cdk::caller().to_string();
+    let label = _get_owned_label(id, &owner)?;
+
+    // Detach the label from every todo of the owner before removing it
+    let labelled: Vec<Todo> = _get_owner_todos(&owner)
+        .into_iter()
+        .filter(|todo| todo.label_ids.contains(&id))
+        .collect();
+    check_none_frozen(&labelled)?;
+    for mut todo in labelled {
+        todo.label_ids.retain(|label_id| *label_id != id);
+        todo.updated_at = Some(time());
+        do_insert(&mut todo)?;
+    }
+
+    LABELS.with(|labels| labels.borrow_mut().remove(&id));
+    Ok(label)
+}
+
+#[ic_cdk::update]
+fn attach_label(todo_id: u64, label_id: u64) -> Result<Todo, Error> {
+    let owner = ic_cdk::caller().to_string();
+    _get_owned_label(label_id, &owner)?;
+
+    match _get_todo(&todo_id) {
+        Some(mut todo) => {
+            // Verify owner
+            if todo.owner != owner {
+                return Err(Error::NotFound {
+                    msg: format!("Not authorized to update todo with id={}", todo_id),
+                });
+            }
+
+            if todo.label_ids.contains(&label_id) {
+                return Ok(todo);
+            }
+            if todo.label_ids.len() >= MAX_LABELS_PER_TODO {
+                return Err(Error::InvalidInput {
+                    msg: format!(
+                        "A todo cannot have more than {} labels",
+                        MAX_LABELS_PER_TODO
+                    ),
+                });
+            }
+
+            todo.label_ids.push(label_id);
+            todo.updated_at = Some(time());
+
+            do_insert(&mut todo)?;
+            Ok(todo)
+        }
+        None => Err(Error::NotFound {
+            msg: format!(
+                "Couldn't attach label to todo with id={}. Todo not found",
+                todo_id
+            ),
+        }),
+    }
+}
+
+#[ic_cdk::update]
+fn detach_label(todo_id: u64, label_id: u64) -> Result<Todo, Error> {
+    match _get_todo(&todo_id) {
+        Some(mut todo) => {
+            // Verify owner
+            if todo.owner != ic_cdk::caller().to_string() {
+                return Err(Error::NotFound {
+                    msg: format!("Not authorized to update todo with id={}", todo_id),
+                });
+            }
+
+            if !todo.label_ids.contains(&label_id) {
+                return Ok(todo);
+            }
+
+            todo.label_ids.retain(|id| *id != label_id);
+            todo.updated_at = Some(time());
+
+            do_insert(&mut todo)?;
+            Ok(todo)
+        }
+        None => Err(Error::NotFound {
+            msg: format!(
+                "Couldn't detach label from todo with id={}. Todo not found",
+                todo_id
+            ),
+        }),
+    }
+}
+
+#[ic_cdk::update]
+fn import_todos_merge(json: String) -> Result<MergeReport, Error> {
+    let items: Vec<ImportedTodo> =
+        serde_json::from_str(&json).map_err(|e| Error::InvalidInput {
+            msg: format!("Invalid import document: {}", e),
+        })?;
+    if items.len() > MAX_IMPORT_ITEMS {
+        return Err(Error::InvalidInput {
+            msg: format!("Cannot import more than {} todos at once", MAX_IMPORT_ITEMS),
+        });
+    }
+    let caller = ic_cdk::caller();
+    let owner = caller.to_string();
+    let updated_todo = |item: &ImportedTodo| _get_import_target(&caller, item.id);
+
+    // Validate everything up front so a bad item doesn't leave a partial import behind
+    for item in &items {
+        validate_title(&item.title)?;
+        validate_description(&item.description)?;
+        check_description_required(&item.description)?;
+        validate_color(&item.color)?;
+        validate_estimate(item.estimated_minutes)?;
+        match updated_todo(item) {
+            Some(todo) => {
+                check_none_frozen([&todo])?;
+                check_transition(&todo.status, &item.status)?;
+            }
+            None => {
+                check_due_date_required(item.due_date)?;
+                check_transition(&TaskStatus::Pending, &item.status)?;
+            }
+        }
+    }
+    let created = items
+        .iter()
+        .filter(|item| updated_todo(item).is_none())
+        .count();
+    check_todo_limit(&caller, created as u64)?;
+
+    let now = time();
+    let mut report = MergeReport::default();
+
+    for item in items {
+        match updated_todo(&item) {
+            Some(mut todo) => {
+                let previous_priority = todo.priority.clone();
+                todo.title = item.title;
+                todo.description = item.description;
+                set_status(&mut todo, item.status, now);
+                todo.priority = item.priority;
+                todo.due_date = item.due_date;
+                todo.color = item.color;
+                todo.estimated_minutes = item.estimated_minutes;
+                todo.updated_at = Some(now);
+
+                do_insert_tracking_priority(&mut todo, &previous_priority, now)?;
+                report.updated += 1;
+            }
+            None => {
+                // Imported todos always get a fresh id. The original id is remembered, so
+                // importing the same backup again updates them instead of adding duplicates.
+                let mut todo = Todo {
+                    id: next_id(&ID_COUNTER),
+                    title: item.title,
+                    description: item.description,
+                    status: TaskStatus::Pending,
+                    priority: item.priority,
+                    due_date: item.due_date,
+                    created_at: now,
+                    updated_at: None,
+                    owner: owner.clone(),
+                    color: item.color,
+                    assignee: None,
+                    assignment_state: None,
+                    collaborators: Vec::new(),
+                    field_permissions: Vec::new(),
+                    completed_at: None,
+                    completion_duration: None,
+                    visibility: Visibility::Private,
+                    label_ids: Vec::new(),
+                    pinned: false,
+                    starred: false,
+                    reminder_at: None,
+                    version: 0,
+                    last_modified_by: None,
+                    frozen: false,
+                    frozen_by: None,
+                    estimated_minutes: item.estimated_minutes,
+                    position: next_position(&owner),
+                };
+                set_status(&mut todo, item.status, now);
+
+                do_insert(&mut todo)?;
+                if let Some(original_id) = item.id {
+                    IMPORTED_IDS.with(|ids| {
+                        ids.borrow_mut()
+                            .insert((principal_key(&caller), original_id), todo.id)
+                    });
+                }
+                report.created += 1;
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[ic_cdk::update]
+fn set_pinned(id: u64, pinned: bool) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "pin")?;
+    if todo.pinned == pinned {
+        return Ok(todo);
+    }
+
+    if pinned {
+        let pinned_count = _get_owner_todos(&todo.owner)
+            .iter()
+            .filter(|todo| todo.pinned)
+            .count();
+        if pinned_count >= MAX_PINNED_TODOS {
+            return Err(Error::InvalidInput {
+                msg: format!("Cannot pin more than {} todos", MAX_PINNED_TODOS),
+            });
+        }
+    }
+
+    todo.pinned = pinned;
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn toggle_pin(id: u64) -> Result<Todo, Error> {
+    let todo = _get_owned_todo(id, "pin")?;
+    set_pinned(id, !todo.pinned)
+}
+
+#[ic_cdk::update]
+fn set_starred(id: u64, starred: bool) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "star")?;
+    if todo.starred == starred {
+        return Ok(todo);
+    }
+
+    todo.starred = starred;
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn unschedule_todo(id: u64) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "unschedule")?;
+    if todo.due_date.is_none() {
+        return Ok(todo);
+    }
+
+    todo.due_date = None;
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn freeze_todo(id: u64) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "freeze")?;
+    if todo.frozen {
+        return Ok(todo);
+    }
+
+    todo.frozen = true;
+    todo.frozen_by = Some(ic_cdk::caller().to_string());
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn unfreeze_todo(id: u64) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "unfreeze")?;
+    if !todo.frozen {
+        return Ok(todo);
+    }
+
+    todo.frozen = false;
+    todo.frozen_by = None;
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::query]
+fn list_starred(projection: Option<Projection>) -> Vec<Todo> {
+    let mut todos: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| todo.starred)
+        .collect();
+    pinned_first(&mut todos);
+    project_all(todos, projection)
+}
+
+#[ic_cdk::query]
+fn get_storage_usage() -> StorageUsage {
+    let todos = _get_owner_todos(&ic_cdk::caller().to_string());
+    let todo_count = todos.len() as u64;
+
+    // Average the encoded size over a small sample instead of encoding every todo
+    let sample: Vec<usize> = todos
+        .iter()
+        .take(STORAGE_SAMPLE_SIZE)
+        .map(|todo| todo.to_bytes().len())
+        .collect();
+    let average_entry_size = match sample.len() {
+        0 => 0,
+        n => sample.iter().sum::<usize>() as u64 / n as u64,
+    };
+
+    StorageUsage {
+        todo_count,
+        estimated_bytes: todo_count * average_entry_size,
+        max_bytes: todo_count * Todo::MAX_SIZE as u64,
+    }
+}
+
+#[ic_cdk::query]
+fn list_todos(sort_by: Option<SortBy>, projection: Option<Projection>) -> Vec<Todo> {
+    project_all(_list_caller_todos(sort_by), projection)
+}
+
+#[ic_cdk::update]
+fn reorder_todo(id: u64, after: Option<u64>) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "reorder")?;
+    if after == Some(id) {
+        return Err(Error::InvalidInput {
+            msg: "Cannot place a todo after itself".to_string(),
+        });
+    }
+
+    let mut others: Vec<Todo> = _get_owner_todos(&todo.owner)
+        .into_iter()
+        .filter(|other| other.id != id)
+        .collect();
+    sort_todos(&mut others, &SortBy::Manual);
+
+    // Index in `others` before which the todo is placed
+    let insert_at = match after {
+        None => 0,
+        Some(after_id) => match others.iter().position(|other| other.id == after_id) {
+            Some(index) => index + 1,
+            None => {
+                return Err(Error::NotFound {
+                    msg: format!("Couldn't place todo after id={}. Todo not found", after_id),
+                })
+            }
+        },
+    };
+
+    let lower = match insert_at {
+        0 => 0,
+        index => others[index - 1].position,
+    };
+    let position = match others.get(insert_at) {
+        Some(next) if next.position - lower > 1 => Some(lower + (next.position - lower) / 2),
+        Some(_) => None,
+        None => Some(lower + POSITION_GAP),
+    };
+
+    todo.updated_at = Some(time());
+    match position {
+        Some(position) => {
+            todo.position = position;
+            do_insert(&mut todo)?;
+        }
+        None => {
+            // No room left between the neighbours: renumber all of the owner's todos
+            check_none_frozen(&others)?;
+            others.insert(insert_at, todo.clone());
+            for (index, other) in others.iter_mut().enumerate() {
+                let position = (index as u64 + 1) * POSITION_GAP;
+                if other.id == id {
+                    todo.position = position;
+                    do_insert(&mut todo)?;
+                } else if other.position != position {
+                    other.position = position;
+                    do_insert(other)?;
+                }
+            }
+        }
+    }
+
+    Ok(todo)
+}
+
+#[ic_cdk::query]
+fn validate_todo_payload(payload: TodoPayload) -> Result<(), Error> {
+    validate_payload(&payload)
+}
+
+#[ic_cdk::query]
+fn get_board(column_limit: Option<u64>) -> Board {
+    _build_board(column_limit)
+}
+
+// Helper function to build the caller's board with at most `column_limit` todos per column
+fn _build_board(column_limit: Option<u64>) -> Board {
+    let limit = column_limit
+        .unwrap_or(DEFAULT_BOARD_COLUMN_LIMIT)
+        .min(MAX_PAGE_LIMIT) as usize;
+
+    // Distribute the todos over the columns in a single pass
+    let mut board = Board::default();
+    for todo in _get_owner_todos(&ic_cdk::caller().to_string()) {
+        let column = match todo.status {
+            TaskStatus::Pending => &mut board.pending,
+            TaskStatus::InProgress => &mut board.in_progress,
+            TaskStatus::Waiting => &mut board.waiting,
+            TaskStatus::Completed => &mut board.completed,
+        };
+        column.todos.push(todo);
+    }
+
+    for column in [
+        &mut board.pending,
+        &mut board.in_progress,
+        &mut board.waiting,
+        &mut board.completed,
+    ] {
+        sort_todos(&mut column.todos, &SortBy::Manual);
+        pinned_first(&mut column.todos);
+        column.has_more = column.todos.len() > limit;
+        column.todos.truncate(limit);
+    }
+
+    board
+}
+
+#[ic_cdk::update]
+fn save_checkpoint(todo_id: u64, name: String) -> Result<Checkpoint, Error> {
+    let todo = _get_owned_todo(todo_id, "checkpoint")?;
+
+    if name.trim().is_empty() {
+        return Err(Error::InvalidInput {
+            msg: "Checkpoint name cannot be empty".to_string(),
+        });
+    }
+    if name.chars().count() > MAX_CHECKPOINT_NAME_LENGTH {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Checkpoint name cannot be longer than {} characters",
+                MAX_CHECKPOINT_NAME_LENGTH
+            ),
+        });
+    }
+    if _get_todo_checkpoints(todo_id).len() >= MAX_CHECKPOINTS_PER_TODO {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "A todo cannot have more than {} checkpoints",
+                MAX_CHECKPOINTS_PER_TODO
+            ),
+        });
+    }
+
+    let checkpoint = Checkpoint {
+        id: next_id(&CHECKPOINT_ID_COUNTER),
+        todo_id,
+        name,
+        snapshot: todo,
+        created_at: time(),
+    };
+
+    CHECKPOINTS.with(|checkpoints| {
+        checkpoints
+            .borrow_mut()
+            .insert(checkpoint.id, checkpoint.clone())
+    });
+    CHECKPOINT_INDEX.with(|index| index.borrow_mut().insert((todo_id, checkpoint.id), ()));
+    Ok(checkpoint)
+}
+
+#[ic_cdk::query]
+fn list_checkpoints(todo_id: u64) -> Vec<Checkpoint> {
+    match _get_owned_todo(todo_id, "view") {
+        Ok(_) => _get_todo_checkpoints(todo_id),
+        Err(_) => Vec::new(),
+    }
+}
+
+#[ic_cdk::update]
+fn restore_checkpoint(checkpoint_id: u64) -> Result<Todo, Error> {
+    let checkpoint = _get_checkpoint(checkpoint_id)?;
+    let mut todo = _get_owned_todo(checkpoint.todo_id, "restore")?;
+    let snapshot = checkpoint.snapshot;
+    check_transition(&todo.status, &snapshot.status)?;
+    let now = time();
+    let previous_priority = todo.priority.clone();
+
+    todo.title = snapshot.title;
+    todo.description = snapshot.description;
+    set_status(&mut todo, snapshot.status, now);
+    todo.priority = snapshot.priority;
+    todo.due_date = snapshot.due_date;
+    todo.color = snapshot.color;
+    todo.estimated_minutes = snapshot.estimated_minutes;
+    // Labels deleted since the checkpoint was saved are not brought back
+    todo.label_ids = snapshot
+        .label_ids
+        .into_iter()
+        .filter(|label_id| _get_owned_label(*label_id, &todo.owner).is_ok())
+        .collect();
+    todo.updated_at = Some(now);
+
+    do_insert_tracking_priority(&mut todo, &previous_priority, now)?;
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn delete_checkpoint(checkpoint_id: u64) -> Result<(), Error> {
+    let checkpoint = _get_checkpoint(checkpoint_id)?;
+    _get_owned_todo(checkpoint.todo_id, "delete checkpoint of")?;
+
+    remove_checkpoint(&checkpoint);
+    Ok(())
+}
+
+#[ic_cdk::query]
+fn get_all_todos_filtered(include_completed: bool, projection: Option<Projection>) -> Vec<Todo> {
+    project_all(_get_caller_todos_filtered(include_completed), projection)
+}
+
+// Helper function to get the caller's todos in manual order, optionally without completed ones
+fn _get_caller_todos_filtered(include_completed: bool) -> Vec<Todo> {
+    let mut todos: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| include_completed || !matches!(todo.status, TaskStatus::Completed))
+        .collect();
+    sort_todos(&mut todos, &SortBy::Manual);
+    pinned_first(&mut todos);
+    todos
+}
+
+#[ic_cdk::update]
+fn clone_todo(id: u64, overrides: Option<TodoPayload>) -> Result<Todo, Error> {
+    let source = _get_clone_source(id, &ic_cdk::caller())?;
+    let owner = source.owner.clone();
+
+    // Only the title and due date can be overridden, the other payload fields are ignored
+    let (title, due_date) = match overrides {
+        Some(overrides) => {
+            validate_title(&overrides.title)?;
+            validate_due_date(overrides.due_date)?;
+            (overrides.title, overrides.due_date)
+        }
+        None => (source.title, source.due_date),
+    };
+    check_due_date_required(due_date)?;
+    check_todo_limit(&ic_cdk::caller(), 1)?;
+
+    // Checkpoints and presentation flags belong to the original and are not copied
+    let mut todo = Todo {
+        id: next_id(&ID_COUNTER),
+        title,
+        description: source.description,
+        status: TaskStatus::Pending,
+        priority: source.priority,
+        due_date,
+        created_at: time(),
+        updated_at: None,
+        position: next_position(&owner),
+        owner,
+        color: source.color,
+        assignee: None,
+        assignment_state: None,
+        collaborators: Vec::new(),
+        field_permissions: Vec::new(),
+        completed_at: None,
+        completion_duration: None,
+        visibility: Visibility::Private,
+        label_ids: source.label_ids,
+        pinned: false,
+        starred: false,
+        reminder_at: None,
+        version: 0,
+        last_modified_by: None,
+        frozen: false,
+        frozen_by: None,
+        estimated_minutes: source.estimated_minutes,
+    };
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+// Helper function to get a todo the caller may clone. Cloning someone else's todo is refused
+// with Unauthorized even though it only reads the original
+fn _get_clone_source(id: u64, caller: &Principal) -> Result<Todo, Error> {
+    match _get_todo(&id) {
+        Some(todo) if todo.owner == caller.to_string() => Ok(todo),
+        Some(_) => Err(Error::Unauthorized {
+            msg: format!("Not authorized to clone todo with id={}", id),
+        }),
+        None => Err(Error::NotFound {
+            msg: format!("Couldn't clone todo with id={}. Todo not found", id),
+        }),
+    }
+}
+
+#[ic_cdk::update]
+fn set_reminder(id: u64, reminder_at: Option<u64>) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "set reminder for")?;
+    if matches!(reminder_at, Some(at) if at <= time()) {
+        return Err(Error::InvalidInput {
+            msg: "Reminder must be in the future".to_string(),
+        });
+    }
+
+    todo.reminder_at = reminder_at;
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::query]
+fn get_upcoming_reminders(within_seconds: u64, projection: Option<Projection>) -> Vec<Todo> {
+    let now = time();
+    let until = now.saturating_add(within_seconds.saturating_mul(NANOS_PER_SECOND));
+
+    let mut todos: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| !matches!(todo.status, TaskStatus::Completed))
+        .filter(|todo| matches!(todo.reminder_at, Some(at) if at >= now && at <= until))
+        .collect();
+    todos.sort_by_key(|todo| (todo.reminder_at, todo.id));
+    project_all(todos, projection)
+}
+
+#[ic_cdk::query]
+fn version() -> String {
+    env!("CARGO_PKG_VERSION").to_string()
+}
+
+#[ic_cdk::query]
+fn list_templates() -> Vec<Template> {
+    let owner = ic_cdk::caller().to_string();
+    TEMPLATES.with(|templates| {
+        templates
+            .borrow()
+            .iter()
+            .filter(|(_, template)| template.owner == owner)
+            .map(|(_, template)| template)
+            .collect()
+    })
+}
+
+#[ic_cdk::update]
+fn create_template(payload: TodoPayload, label_ids: Vec<u64>) -> Result<Template, Error> {
+    let owner = ic_cdk::caller().to_string();
+    validate_template(&payload, &label_ids, &owner)?;
+
+    if list_templates().len() >= MAX_TEMPLATES_PER_USER {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Cannot create more than {} templates",
+                MAX_TEMPLATES_PER_USER
+            ),
+        });
+    }
+
+    let template = Template {
+        id: next_id(&TEMPLATE_ID_COUNTER),
+        owner,
+        payload,
+        label_ids,
+        created_at: time(),
+        updated_at: None,
+    };
+
+    store_template(&template)?;
+    Ok(template)
+}
+
+#[ic_cdk::update]
+fn update_template(id: u64, payload: TodoPayload, label_ids: Vec<u64>) -> Result<Template, Error> {
+    let owner = ic_cdk::caller().to_string();
+    let mut template = _get_owned_template(id, &owner)?;
+    validate_template(&payload, &label_ids, &owner)?;
+
+    template.payload = payload;
+    template.label_ids = label_ids;
+    template.updated_at = Some(time());
+
+    store_template(&template)?;
+    Ok(template)
+}
+
+// Helper function to insert a template, rejecting templates that don't fit into a storage slot
+fn store_template(template: &Template) -> Result<(), Error> {
+    let size = Encode!(template)
+        .map_err(|e| Error::InvalidInput {
+            msg: format!("Cannot encode template with id={}: {}", template.id, e),
+        })?
+        .len();
+    if size > Template::MAX_SIZE as usize {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Template is too large to store ({} bytes, maximum is {} bytes)",
+                size,
+                Template::MAX_SIZE
+            ),
+        });
+    }
+
+    TEMPLATES.with(|templates| templates.borrow_mut().insert(template.id, template.clone()));
+    Ok(())
+}
+
+#[ic_cdk::update]
+fn delete_template(id: u64) -> Result<Template, Error> {
+    let template = _get_owned_template(id, &ic_cdk::caller().to_string())?;
+
+    TEMPLATES.with(|templates| templates.borrow_mut().remove(&id));
+    Ok(template)
+}
+
+#[ic_cdk::update]
+fn instantiate_template(template_id: u64, due_date: Option<u64>) -> Result<Todo, Error> {
+    let owner = ic_cdk::caller().to_string();
+    let template = _get_owned_template(template_id, &owner)?;
+
+    let mut payload = template.payload;
+    payload.due_date = due_date.or(payload.due_date);
+    // Labels deleted after the template was saved are skipped
+    let label_ids = template
+        .label_ids
+        .into_iter()
+        .filter(|label_id| _get_owned_label(*label_id, &owner).is_ok())
+        .collect();
+
+    create_todo(payload, label_ids)
+}
+
+// Helper function to validate and create a new todo owned by the caller
+fn create_todo(payload: TodoPayload, label_ids: Vec<u64>) -> Result<Todo, Error> {
+    // Input validation
+    validate_payload(&payload)?;
+    check_todo_limit(&ic_cdk::caller(), 1)?;
+
+    let id = next_id(&ID_COUNTER);
+
+    let owner = ic_cdk::caller().to_string();
+    let mut todo = Todo {
+        id,
+        title: payload.title,
+        description: payload.description,
+        status: TaskStatus::Pending,
+        priority: payload.priority,
+        due_date: payload.due_date,
+        created_at: time(),
+        updated_at: None,
+        position: next_position(&owner),
+        owner,
+        color: payload.color,
+        assignee: None,
+        assignment_state: None,
+        collaborators: Vec::new(),
+        field_permissions: Vec::new(),
+        completed_at: None,
+        completion_duration: None,
+        visibility: Visibility::Private,
+        label_ids,
+        pinned: false,
+        starred: false,
+        reminder_at: None,
+        version: 0,
+        last_modified_by: None,
+        frozen: false,
+        frozen_by: None,
+        estimated_minutes: payload.estimated_minutes,
+    };
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::query]
+fn get_todos_by_week(
+    year: u32,
+    week: u8,
+    projection: Option<Projection>,
+) -> Result<Vec<Todo>, Error> {
+    if !(MIN_WEEK_YEAR..=MAX_WEEK_YEAR).contains(&year) {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Year must be between {} and {}",
+                MIN_WEEK_YEAR, MAX_WEEK_YEAR
+            ),
+        });
+    }
+    let weeks_in_year = (iso_week_one_start(year as i64 + 1) - iso_week_one_start(year as i64)) / 7;
+    if week == 0 || week as i64 > weeks_in_year {
+        return Err(Error::InvalidInput {
+            msg: format!("Week must be between 1 and {} for {}", weeks_in_year, year),
+        });
+    }
+
+    let week_start_day = iso_week_one_start(year as i64) + (week as i64 - 1) * 7;
+    let week_start = week_start_day as u64 * NANOS_PER_DAY;
+    let week_end = week_start + 7 * NANOS_PER_DAY;
+
+    let mut todos: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| matches!(todo.due_date, Some(due) if due >= week_start && due < week_end))
+        .collect();
+    sort_todos(&mut todos, &SortBy::DueDate);
+    Ok(project_all(todos, projection))
+}
+
+#[ic_cdk::update]
+fn assign_todo(id: u64, assignee: Option<Principal>) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "assign")?;
+
+    if let Some(assignee) = assignee {
+        if assignee == Principal::anonymous() {
+            return Err(Error::InvalidInput {
+                msg: "Cannot assign a todo to the anonymous principal".to_string(),
+            });
+        }
+        if assignee == ic_cdk::caller() {
+            return Err(Error::InvalidInput {
+                msg: "Cannot assign a todo to yourself".to_string(),
+            });
+        }
+    }
+
+    // Any new assignment, including a reassignment while a proposal is pending, starts over
+    todo.assignment_state = assignee.map(|_| AssignmentState::Proposed);
+    todo.assignee = assignee;
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    if let Some(assignee) = assignee {
+        notify(
+            assignee,
+            NotificationKind::Assigned,
+            &todo,
+            format!("You were asked to take on '{}'", todo.title),
+        );
+    }
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn respond_to_assignment(id: u64, accept: bool) -> Result<Todo, Error> {
+    let mut todo = match _get_todo(&id) {
+        Some(todo) => todo,
+        None => {
+            return Err(Error::NotFound {
+                msg: format!(
+                    "Couldn't respond to assignment of todo with id={}. Todo not found",
+                    id
+                ),
+            })
+        }
+    };
+    if todo.assignee != Some(ic_cdk::caller()) {
+        return Err(Error::NotFound {
+            msg: format!("Not assigned to todo with id={}", id),
+        });
+    }
+    if todo.assignment_state != Some(AssignmentState::Proposed) {
+        return Err(Error::InvalidInput {
+            msg: format!("No pending assignment for todo with id={}", id),
+        });
+    }
+
+    if accept {
+        todo.assignment_state = Some(AssignmentState::Accepted);
+    } else {
+        todo.assignee = None;
+        todo.assignment_state = Some(AssignmentState::Declined);
+    }
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    let (kind, verb) = match accept {
+        true => (NotificationKind::AssignmentAccepted, "accepted"),
+        false => (NotificationKind::AssignmentDeclined, "declined"),
+    };
+    notify(
+        owner_principal(&todo),
+        kind,
+        &todo,
+        format!("{} {} '{}'", ic_cdk::caller(), verb, todo.title),
+    );
+    Ok(todo)
+}
+
+#[ic_cdk::query]
+fn list_assigned_to_me(projection: Option<Projection>) -> Vec<Todo> {
+    project_all(
+        _get_assigned_todos(&ic_cdk::caller(), AssignmentState::Accepted),
+        projection,
+    )
+}
+
+#[ic_cdk::query]
+fn list_assigned_to_me_page(cursor: Option<u64>, projection: Option<Projection>) -> ScanPage {
+    let caller = ic_cdk::caller();
+    let page = page_indexed_todos(&ASSIGNEE_INDEX, &caller, cursor, |todo| {
+        is_assigned(todo, &caller, &AssignmentState::Accepted)
+    });
+    project_scan(page, projection)
+}
+
+#[ic_cdk::query]
+fn list_pending_assignments(projection: Option<Projection>) -> Vec<Todo> {
+    project_all(
+        _get_assigned_todos(&ic_cdk::caller(), AssignmentState::Proposed),
+        projection,
+    )
+}
+
+#[ic_cdk::query]
+fn list_pending_assignments_page(cursor: Option<u64>, projection: Option<Projection>) -> ScanPage {
+    let caller = ic_cdk::caller();
+    let page = page_indexed_todos(&ASSIGNEE_INDEX, &caller, cursor, |todo| {
+        is_assigned(todo, &caller, &AssignmentState::Proposed)
+    });
+    project_scan(page, projection)
+}
+
+#[ic_cdk::update]
+fn reorder_todos(ordered_ids: Vec<u64>) -> Result<(), Error> {
+    if ordered_ids.len() > MAX_REORDER_ITEMS {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Cannot reorder more than {} todos at once",
+                MAX_REORDER_ITEMS
+            ),
+        });
+    }
+
+    let mut listed = std::collections::BTreeSet::new();
+    let mut todos = Vec::with_capacity(ordered_ids.len());
+    for id in ordered_ids {
+        if !listed.insert(id) {
+            return Err(Error::InvalidInput {
+                msg: format!("Todo with id={} is listed more than once", id),
+            });
+        }
+        todos.push(_get_owned_todo(id, "reorder")?);
+    }
+
+    // Listed todos come first and the caller's other todos follow in their current order. The
+    // whole list is renumbered, so positions never collide with those of earlier calls.
+    let mut others: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| !listed.contains(&todo.id))
+        .collect();
+    sort_todos(&mut others, &SortBy::Manual);
+
+    let now = time();
+    let mut changed = Vec::new();
+    for (index, mut todo) in todos.into_iter().chain(others).enumerate() {
+        let position = (index as u64 + 1) * POSITION_GAP;
+        if listed.contains(&todo.id) {
+            todo.updated_at = Some(now);
+        } else if todo.position == position {
+            continue;
+        }
+        todo.position = position;
+        changed.push(todo);
+    }
+    check_none_frozen(&changed)?;
+    for mut todo in changed {
+        do_insert(&mut todo)?;
+    }
+    Ok(())
+}
+
+#[ic_cdk::update]
+fn delete_all_my_todos(confirm: String) -> Result<u64, Error> {
+    if confirm != DELETE_ALL_CONFIRMATION {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Type '{}' to confirm deleting all of your todos",
+                DELETE_ALL_CONFIRMATION
+            ),
+        });
+    }
+
+    // Just as delete_todo refuses to delete a frozen todo, nothing is deleted while any of the
+    // caller's todos is frozen; the error names one to unfreeze
+    let caller = ic_cdk::caller();
+    let todos = _get_owner_todos(&caller.to_string());
+    check_none_frozen(&todos)?;
+
+    let deleted: std::collections::BTreeSet<u64> = todos.iter().map(|todo| todo.id).collect();
+    for id in &deleted {
+        do_remove(*id);
+    }
+    remove_imported_ids(&caller, &deleted);
+    Ok(deleted.len() as u64)
+}
+
+#[ic_cdk::update]
+fn add_collaborator(id: u64, principal: Principal, role: Role) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "share")?;
+
+    if principal == Principal::anonymous() {
+        return Err(Error::InvalidInput {
+            msg: "Cannot share a todo with the anonymous principal".to_string(),
+        });
+    }
+    if principal.to_string() == todo.owner {
+        return Err(Error::InvalidInput {
+            msg: "Cannot share a todo with its owner".to_string(),
+        });
+    }
+
+    match todo.collaborators.iter_mut().find(|(p, _)| *p == principal) {
+        // Already a collaborator: only the role changes
+        Some((_, existing_role)) => *existing_role = role,
+        None => {
+            if todo.collaborators.len() >= MAX_COLLABORATORS_PER_TODO {
+                return Err(Error::InvalidInput {
+                    msg: format!(
+                        "A todo cannot have more than {} collaborators",
+                        MAX_COLLABORATORS_PER_TODO
+                    ),
+                });
+            }
+            todo.collaborators.push((principal, role));
+        }
+    }
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn remove_collaborator(id: u64, principal: Principal) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "unshare")?;
+    if !todo.collaborators.iter().any(|(p, _)| *p == principal) {
+        return Ok(todo);
+    }
+
+    todo.collaborators.retain(|(p, _)| *p != principal);
+    todo.field_permissions.retain(|(p, _)| *p != principal);
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn set_field_permissions(
+    id: u64,
+    principal: Principal,
+    permission: FieldPermission,
+) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "share")?;
+    if collaborator_role(&todo, &principal) != Some(&Role::Editor) {
+        return Err(Error::InvalidInput {
+            msg: format!("{} is not an editor of todo with id={}", principal, id),
+        });
+    }
+
+    todo.field_permissions.retain(|(p, _)| *p != principal);
+    if permission != full_field_permission() {
+        todo.field_permissions.push((principal, permission));
+    }
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::query]
+fn list_shared_with_me(projection: Option<Projection>) -> Vec<Todo> {
+    let todos = _get_indexed_todos(&COLLABORATOR_INDEX, &ic_cdk::caller());
+    project_all(todos, projection)
+}
+
+#[ic_cdk::query]
+fn list_shared_with_me_page(cursor: Option<u64>, projection: Option<Projection>) -> ScanPage {
+    let caller = ic_cdk::caller();
+    let page = page_indexed_todos(&COLLABORATOR_INDEX, &caller, cursor, |todo| {
+        collaborator_role(todo, &caller).is_some()
+    });
+    project_scan(page, projection)
+}
+
+#[ic_cdk::query]
+fn get_shared_todos_changed_since(since: u64, projection: Option<Projection>) -> Vec<Todo> {
+    let caller = ic_cdk::caller();
+    let todos = _get_indexed_todos(&COLLABORATOR_INDEX, &caller)
+        .into_iter()
+        .filter(|todo| is_shared_change(todo, &caller, since))
+        .collect();
+    project_all(todos, projection)
+}
+
+#[ic_cdk::query]
+fn get_shared_todos_changed_since_page(
+    since: u64,
+    cursor: Option<u64>,
+    projection: Option<Projection>,
+) -> ScanPage {
+    let caller = ic_cdk::caller();
+    let page = page_indexed_todos(&COLLABORATOR_INDEX, &caller, cursor, |todo| {
+        is_shared_change(todo, &caller, since)
+    });
+    project_scan(page, projection)
+}
+
+#[ic_cdk::query]
+fn get_average_completion_time() -> Option<u64> {
+    let durations: Vec<u64> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .iter()
+        .filter(|todo| todo.status == TaskStatus::Completed)
+        .filter_map(|todo| todo.completion_duration)
+        .collect();
+    match durations.len() {
+        0 => None,
+        n => Some((durations.iter().map(|d| *d as u128).sum::<u128>() / n as u128) as u64),
+    }
+}
+
+// Helper function to change the status of a todo, tracking when and how fast it was completed
+fn set_status(todo: &mut Todo, status: TaskStatus, now: u64) {
+    if status == TaskStatus::Completed {
+        if todo.status != TaskStatus::Completed {
+            todo.completed_at = Some(now);
+            todo.completion_duration = Some(now.saturating_sub(todo.created_at));
+        }
+    } else {
+        todo.completed_at = None;
+        todo.completion_duration = None;
+    }
+    todo.status = status;
+}
+
+#[ic_cdk::update]
+async fn create_share_token(id: u64, expires_at: Option<u64>) -> Result<String, Error> {
+    _get_owned_todo(id, "share")?;
+    if matches!(expires_at, Some(at) if at <= time()) {
+        return Err(Error::InvalidInput {
+            msg: "Expiry must be in the future".to_string(),
+        });
+    }
+
+    let (randomness,) = ic_cdk::api::management_canister::main::raw_rand()
+        .await
+        .map_err(|(code, msg)| Error::InvalidInput {
+            msg: format!("Cannot generate share token: {:?} {}", code, msg),
+        })?;
+    let mut token = [0u8; SHARE_TOKEN_BYTES];
+    token.copy_from_slice(&randomness[..SHARE_TOKEN_BYTES]);
+
+    // The todo may have been deleted while waiting for randomness
+    _get_owned_todo(id, "share")?;
+
+    let grant = ShareGrant {
+        todo_id: id,
+        created_at: time(),
+        expires_at,
+    };
+    SHARE_TOKENS.with(|tokens| tokens.borrow_mut().insert(token, grant));
+    SHARE_TOKEN_INDEX.with(|index| index.borrow_mut().insert((id, token), ()));
+    Ok(encode_hex(&token))
+}
+
+#[ic_cdk::update]
+fn revoke_share_token(token: String) -> Result<(), Error> {
+    let (key, grant) = _get_share_grant(&token)?;
+    _get_owned_todo(grant.todo_id, "unshare")?;
+
+    remove_share_token(grant.todo_id, key);
+    Ok(())
+}
+
+#[ic_cdk::query]
+fn get_shared_todo(token: String) -> Result<Todo, Error> {
+    let (_, grant) = _get_share_grant(&token)?;
+    if matches!(grant.expires_at, Some(at) if at <= time()) {
+        return Err(Error::NotFound {
+            msg: "Share token not found".to_string(),
+        });
+    }
+
+    _get_todo(&grant.todo_id).ok_or_else(|| Error::NotFound {
+        msg: "Share token not found".to_string(),
+    })
+}
+
+// Helper function to check a status change against the workflow when strict transitions are on
+fn check_transition(from: &TaskStatus, to: &TaskStatus) -> Result<(), Error> {
+    if !_get_config().strict_transitions || from == to {
+        return Ok(());
+    }
+    let allowed = matches!(
+        (from, to),
+        (TaskStatus::Pending, TaskStatus::InProgress)
+            | (TaskStatus::InProgress, TaskStatus::Waiting)
+            | (TaskStatus::Waiting, TaskStatus::InProgress)
+            | (TaskStatus::InProgress, TaskStatus::Completed)
+            | (TaskStatus::Completed, TaskStatus::InProgress)
+    );
+    if !allowed {
+        return Err(Error::InvalidTransition {
+            msg: format!("Cannot change status from {:?} to {:?}", from, to),
+        });
+    }
+    Ok(())
+}
+
+#[ic_cdk::update]
+fn offer_transfer(id: u64, to: Principal) -> Result<TransferOffer, Error> {
+    let todo = _get_owned_todo(id, "transfer")?;
+
+    if to == Principal::anonymous() {
+        return Err(Error::InvalidInput {
+            msg: "Cannot transfer a todo to the anonymous principal".to_string(),
+        });
+    }
+    if to.to_string() == todo.owner {
+        return Err(Error::InvalidInput {
+            msg: "Cannot transfer a todo to its owner".to_string(),
+        });
+    }
+
+    // A new offer replaces any pending one
+    let offer = TransferOffer {
+        todo_id: id,
+        from: todo.owner,
+        to,
+        offered_at: time(),
+    };
+    TRANSFER_OFFERS.with(|offers| offers.borrow_mut().insert(id, offer.clone()));
+    Ok(offer)
+}
+
+#[ic_cdk::query]
+fn list_transfer_offers() -> Vec<TransferOffer> {
+    let caller = ic_cdk::caller();
+    let now = time();
+    TRANSFER_OFFERS.with(|offers| {
+        offers
+            .borrow()
+            .iter()
+            .filter(|(_, offer)| offer.to == caller && !is_offer_expired(offer, now))
+            .map(|(_, offer)| offer)
+            .collect()
+    })
+}
+
+#[ic_cdk::update]
+fn accept_transfer(id: u64) -> Result<Todo, Error> {
+    let offer = _take_transfer_offer(id)?;
+    let todo = match _get_todo(&id) {
+        Some(todo) if todo.owner == offer.from => todo,
+        _ => {
+            return Err(Error::NotFound {
+                msg: format!("Couldn't transfer todo with id={}. Todo not found", id),
+            })
+        }
+    };
+    // The offer stays pending until the owner unfreezes the todo
+    if todo.frozen {
+        TRANSFER_OFFERS.with(|offers| offers.borrow_mut().insert(id, offer));
+        return Err(Error::InvalidInput {
+            msg: "Todo is frozen".to_string(),
+        });
+    }
+
+    // The offer stays pending when the new owner cannot store the todo, e.g. a title conflict
+    transfer_todo(todo, &offer, time()).inspect_err(|_| {
+        TRANSFER_OFFERS.with(|offers| offers.borrow_mut().insert(id, offer.clone()));
+    })
+}
+
+#[ic_cdk::query]
+fn get_ownership_history(todo_id: u64) -> Result<Vec<OwnershipChange>, Error> {
+    match _get_todo(&todo_id) {
+        Some(todo) if can_view(&todo, &ic_cdk::caller()) => Ok(_get_ownership_history(todo_id)
+            .into_iter()
+            .map(|(_, change)| change)
+            .collect()),
+        Some(_) => Err(Error::Unauthorized {
+            msg: format!("Not authorized to view todo with id={}", todo_id),
+        }),
+        None => Err(Error::NotFound {
+            msg: format!("Todo with id={} not found", todo_id),
+        }),
+    }
+}
+
+#[ic_cdk::update]
+fn decline_transfer(id: u64) -> Result<(), Error> {
+    _take_transfer_offer(id)?;
+    Ok(())
+}
+
+#[ic_cdk::update]
+fn cancel_transfer(id: u64) -> Result<(), Error> {
+    _get_owned_todo(id, "cancel transfer of")?;
+    match TRANSFER_OFFERS.with(|offers| offers.borrow_mut().remove(&id)) {
+        Some(_) => Ok(()),
+        None => Err(Error::NotFound {
+            msg: format!("No pending transfer for todo with id={}", id),
+        }),
+    }
+}
+
+#[ic_cdk::query]
+fn get_todos_by_statuses(statuses: Vec<TaskStatus>, projection: Option<Projection>) -> Vec<Todo> {
+    // An empty set of statuses matches nothing
+    let mut statuses = statuses;
+    statuses.sort();
+    statuses.dedup();
+    let caller = ic_cdk::caller();
+    let mut todos: Vec<Todo> = statuses
+        .into_iter()
+        .flat_map(|status| _get_owner_todos_with_status(&caller, status))
+        .collect();
+    sort_todos(&mut todos, &SortBy::Manual);
+    pinned_first(&mut todos);
+    project_all(todos, projection)
+}
+
+#[ic_cdk::update]
+fn set_visibility(id: u64, visibility: Visibility) -> Result<Todo, Error> {
+    let mut todo = _get_owned_todo(id, "change visibility of")?;
+    if todo.visibility == visibility {
+        return Ok(todo);
+    }
+    if visibility == Visibility::Public && !_get_config().allow_public_todos {
+        return Err(Error::InvalidInput {
+            msg: "Public todos are disabled".to_string(),
+        });
+    }
+
+    todo.visibility = visibility;
+    todo.updated_at = Some(time());
+
+    do_insert(&mut todo)?;
+    Ok(todo)
+}
+
+#[ic_cdk::update]
+fn set_public(id: u64, is_public: bool) -> Result<Todo, Error> {
+    let visibility = if is_public {
+        Visibility::Public
+    } else {
+        Visibility::Private
+    };
+    set_visibility(id, visibility)
+}
+
+#[ic_cdk::query]
+fn get_public_todo(id: u64) -> Result<Todo, Error> {
+    // Private todos are reported as missing so their existence isn't revealed
+    match _get_todo(&id) {
+        Some(todo) if todo.visibility == Visibility::Public => Ok(todo),
+        _ => Err(Error::NotFound {
+            msg: format!("Public todo with id={} not found", id),
+        }),
+    }
+}
+
+#[ic_cdk::query]
+fn list_public_todos(
+    owner: Principal,
+    cursor: Option<u64>,
+    limit: u64,
+    projection: Option<Projection>,
+) -> TodoPage {
+    let limit = limit.clamp(1, MAX_PAGE_LIMIT) as usize;
+    let start = cursor.map_or(0, |cursor| cursor.saturating_add(1));
+
+    let mut todos: Vec<Todo> = _get_owner_todos(&owner.to_string())
+        .into_iter()
+        .filter(|todo| todo.visibility == Visibility::Public && todo.id >= start)
+        .collect();
+    todos.sort_by_key(|todo| todo.id);
+
+    let next_cursor = if todos.len() > limit {
+        Some(todos[limit - 1].id)
+    } else {
+        None
+    };
+    todos.truncate(limit);
+    TodoPage {
+        todos: project_all(todos, projection),
+        next_cursor,
+    }
+}
+
+#[ic_cdk::query]
+fn browse_public_todos(cursor: Option<u64>, limit: u64) -> PublicTodoPage {
+    let limit = limit.clamp(1, MAX_PAGE_LIMIT) as usize;
+    let mut page = scan_todos(cursor, |todo| todo.visibility == Visibility::Public);
+
+    // A full page continues after its last todo rather than after the last one examined
+    if page.todos.len() > limit {
+        page.todos.truncate(limit);
+        page.cursor = page.todos.last().map(|todo| todo.id);
+        page.complete = false;
+    }
+    PublicTodoPage {
+        todos: summarize_all(page.todos),
+        cursor: page.cursor,
+        complete: page.complete,
+    }
+}
+
+#[ic_cdk::query]
+fn list_notifications(unread_only: bool, cursor: Option<u64>, limit: u64) -> NotificationPage {
+    let limit = limit.clamp(1, MAX_PAGE_LIMIT) as usize;
+
+    // Newest first; the cursor is the id of the last notification of the previous page
+    let mut notifications: Vec<Notification> = _get_recipient_notification_ids(&ic_cdk::caller())
+        .into_iter()
+        .rev()
+        .filter(|id| cursor.is_none_or(|cursor| *id < cursor))
+        .filter_map(|id| NOTIFICATIONS.with(|n| n.borrow().get(&id)))
+        .filter(|notification| !unread_only || !notification.read)
+        .take(limit + 1)
+        .collect();
+
+    let next_cursor = if notifications.len() > limit {
+        notifications.truncate(limit);
+        notifications.last().map(|notification| notification.id)
+    } else {
+        None
+    };
+    NotificationPage {
+        notifications,
+        next_cursor,
+    }
+}
+
+#[ic_cdk::query]
+fn unread_count() -> u64 {
+    _get_recipient_notification_ids(&ic_cdk::caller())
+        .into_iter()
+        .filter_map(|id| NOTIFICATIONS.with(|n| n.borrow().get(&id)))
+        .filter(|notification| !notification.read)
+        .count() as u64
+}
+
+#[ic_cdk::query]
+fn get_priority_history(todo_id: u64) -> Result<Vec<PriorityChange>, Error> {
+    match _get_todo(&todo_id) {
+        Some(todo) if can_view(&todo, &ic_cdk::caller()) => Ok(_get_priority_history(todo_id)
+            .into_iter()
+            .map(|(_, change)| change)
+            .collect()),
+        Some(_) => Err(Error::Unauthorized {
+            msg: format!("Not authorized to view todo with id={}", todo_id),
+        }),
+        None => Err(Error::NotFound {
+            msg: format!("Todo with id={} not found", todo_id),
+        }),
+    }
+}
+
+#[ic_cdk::update]
+fn mark_notification_read(id: u64) -> Result<Notification, Error> {
+    let mut notification = _get_received_notification(id)?;
+
+    // Marking an already read notification is a no-op
+    if !notification.read {
+        notification.read = true;
+        NOTIFICATIONS.with(|n| n.borrow_mut().insert(id, notification.clone()));
+    }
+    Ok(notification)
+}
+
+#[ic_cdk::update]
+fn mark_all_read() -> u64 {
+    let mut changed = 0;
+    for id in _get_recipient_notification_ids(&ic_cdk::caller()) {
+        let Some(mut notification) = NOTIFICATIONS.with(|n| n.borrow().get(&id)) else {
+            continue;
+        };
+        if !notification.read {
+            notification.read = true;
+            NOTIFICATIONS.with(|n| n.borrow_mut().insert(id, notification));
+            changed += 1;
+        }
+    }
+    changed
+}
+
+#[ic_cdk::update]
+fn delete_notification(id: u64) -> Result<(), Error> {
+    let notification = _get_received_notification(id)?;
+
+    NOTIFICATIONS.with(|n| n.borrow_mut().remove(&id));
+    NOTIFICATION_INDEX.with(|index| {
+        index
+            .borrow_mut()
+            .remove(&(principal_key(&notification.recipient), id))
+    });
+    Ok(())
+}
+
+#[ic_cdk::query]
+fn get_urgent_unscheduled(projection: Option<Projection>) -> Vec<Todo> {
+    let mut todos: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| {
+            todo.priority >= Priority::High
+                && todo.due_date.is_none()
+                && todo.status != TaskStatus::Completed
+        })
+        .collect();
+
+    // Most urgent first, oldest first within the same priority
+    todos.sort_by(|a, b| {
+        b.priority
+            .cmp(&a.priority)
+            .then(a.created_at.cmp(&b.created_at))
+    });
+    project_all(todos, projection)
+}
+
+#[ic_cdk::query]
+fn get_remaining_by_priority() -> Vec<(Priority, u64)> {
+    let mut remaining = vec![
+        (Priority::Urgent, 0),
+        (Priority::High, 0),
+        (Priority::Medium, 0),
+        (Priority::Low, 0),
+    ];
+    for todo in _get_owner_todos(&ic_cdk::caller().to_string()) {
+        if todo.status == TaskStatus::Completed {
+            continue;
+        }
+        if let Some((_, count)) = remaining
+            .iter_mut()
+            .find(|(priority, _)| *priority == todo.priority)
+        {
+            *count += 1;
+        }
+    }
+    remaining
+}
+
+// Subscribers are pushed events about their own todos. Controllers of this canister are its
+// admins and are pushed the events about every todo, so their callback sees all users' events.
+#[ic_cdk::update]
+fn subscribe(callback: Principal, events: Vec<EventKind>) -> Result<Subscription, Error> {
+    let subscriber = ic_cdk::caller();
+    if subscriber == Principal::anonymous() {
+        return Err(Error::Unauthorized {
+            msg: "Anonymous callers cannot subscribe to events".to_string(),
+        });
+    }
+    if callback == Principal::anonymous() {
+        return Err(Error::InvalidInput {
+            msg: "Callback must be a canister principal".to_string(),
+        });
+    }
+
+    let mut kinds: Vec<EventKind> = Vec::new();
+    for kind in events {
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+    }
+    if kinds.is_empty() {
+        return Err(Error::InvalidInput {
+            msg: "Subscribe to at least one event kind".to_string(),
+        });
+    }
+
+    // Subscribing again replaces the previous subscription
+    let subscription = Subscription {
+        subscriber,
+        callback,
+        events: kinds,
+        created_at: time(),
+    };
+    SUBSCRIPTIONS.with(|subscriptions| {
+        subscriptions
+            .borrow_mut()
+            .insert(principal_key(&subscriber), subscription.clone())
+    });
+    Ok(subscription)
+}
+
+#[ic_cdk::update]
+fn unsubscribe() -> Result<(), Error> {
+    let subscriber = ic_cdk::caller();
+    match SUBSCRIPTIONS.with(|s| s.borrow_mut().remove(&principal_key(&subscriber))) {
+        Some(_) => {
+            // Drop events that were queued but not yet delivered
+            let pending: Vec<u64> = DELIVERY_QUEUE.with(|queue| {
+                queue
+                    .borrow()
+                    .iter()
+                    .filter(|(_, delivery)| delivery.subscriber == subscriber)
+                    .map(|(id, _)| id)
+                    .collect()
+            });
+            for id in pending {
+                DELIVERY_QUEUE.with(|queue| queue.borrow_mut().remove(&id));
+            }
+            Ok(())
+        }
+        None => Err(Error::NotFound {
+            msg: "No subscription found for the caller".to_string(),
+        }),
+    }
+}
+
+// Called when the timer armed by arm_timer expires
+fn run_timer() {
+    TIMER.with(|timer| *timer.borrow_mut() = None);
+    rebuild_indexes();
+    process_deliveries();
+}
+
+// Helper function to repair the secondary indexes by one batch while they are stale, arming
+// the timer for the next batch until the rebuild is complete
+fn rebuild_indexes() {
+    if !indexes_stale() {
+        return;
+    }
+    let cursor = INDEX_REBUILD_CURSOR.with(|cursor| cursor.borrow_mut().take());
+    match repair_integrity(cursor) {
+        Ok(report) if report.complete => set_index_version(),
+        Ok(report) => {
+            INDEX_REBUILD_CURSOR.with(|cursor| *cursor.borrow_mut() = report.cursor);
+            arm_timer(time());
+        }
+        // The cursor is no longer valid, e.g. it names an index this version doesn't have, so
+        // the rebuild starts over instead of trapping and losing it
+        Err(_) => arm_timer(time()),
+    }
+}
+
+// Helper function to send the queued deliveries that are due and arm the timer for the next one
+fn process_deliveries() {
+    let now = time();
+    let mut due: Vec<(u64, Delivery)> = Vec::new();
+    let mut next_attempt: Option<u64> = None;
+    DELIVERY_QUEUE.with(|queue| {
+        for (id, delivery) in queue.borrow().iter() {
+            if IN_FLIGHT_DELIVERIES.with(|in_flight| in_flight.borrow().contains(&id)) {
+                continue;
+            }
+            if delivery.next_attempt_at <= now && due.len() < MAX_DELIVERIES_PER_RUN {
+                due.push((id, delivery));
+            } else {
+                // Due deliveries left over from a full run are sent in the next round
+                let at = delivery.next_attempt_at.max(now);
+                next_attempt = Some(next_attempt.map_or(at, |next| next.min(at)));
+            }
+        }
+    });
+
+    for (id, delivery) in due {
+        IN_FLIGHT_DELIVERIES.with(|in_flight| in_flight.borrow_mut().insert(id));
+        ic_cdk::spawn(deliver(id, delivery));
+    }
+    if let Some(at) = next_attempt {
+        arm_timer(at);
+    }
+}
+
+// Helper function to make the global timer fire no later than the given time
+fn arm_timer(at: u64) {
+    if TIMER.with(|timer| timer.borrow().is_some_and(|(armed, _)| armed <= at)) {
+        return;
+    }
+    // A time in the past fires in the next round
+    let delay = Duration::from_nanos(at.saturating_sub(time()));
+    let id = ic_cdk_timers::set_timer(delay, run_timer);
+    if let Some((_, previous)) = TIMER.with(|timer| timer.borrow_mut().replace((at, id))) {
+        ic_cdk_timers::clear_timer(previous);
+    }
+}
+
+#[ic_cdk::update]
+fn set_webhook(url: String, secret: String) -> Result<(), Error> {
+    validate_webhook_url(&url)?;
+    if secret.is_empty() || secret.len() > MAX_WEBHOOK_SECRET_LENGTH {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Webhook secret must be between 1 and {} bytes",
+                MAX_WEBHOOK_SECRET_LENGTH
+            ),
+        });
+    }
+
+    let webhook = Webhook {
+        url,
+        secret,
+        created_at: time(),
+    };
+    WEBHOOKS.with(|webhooks| {
+        webhooks
+            .borrow_mut()
+            .insert(principal_key(&ic_cdk::caller()), webhook)
+    });
+    Ok(())
+}
+
+#[ic_cdk::update]
+fn clear_webhook() -> Result<(), Error> {
+    match WEBHOOKS.with(|webhooks| {
+        webhooks
+            .borrow_mut()
+            .remove(&principal_key(&ic_cdk::caller()))
+    }) {
+        Some(_) => Ok(()),
+        None => Err(Error::NotFound {
+            msg: "No webhook configured".to_string(),
+        }),
+    }
+}
+
+#[ic_cdk::query]
+fn list_webhook_deliveries() -> Vec<WebhookDelivery> {
+    // Newest first
+    _get_webhook_log(&ic_cdk::caller())
+        .into_iter()
+        .rev()
+        .map(|(_, delivery)| delivery)
+        .collect()
+}
+
+// Drops the parts of a webhook response that differ between replicas
+#[ic_cdk::query]
+fn transform_webhook_response(args: TransformArgs) -> HttpResponse {
+    HttpResponse {
+        status: args.response.status,
+        headers: Vec::new(),
+        body: Vec::new(),
+    }
+}
+
+#[ic_cdk::query]
+fn get_plan_for_capacity(available_minutes: u32, projection: Option<Projection>) -> Vec<Todo> {
+    let mut candidates: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| is_actionable(todo) && todo.estimated_minutes.is_some())
+        .collect();
+
+    // Highest priority first, then earliest due date, then oldest
+    candidates.sort_by(|a, b| {
+        b.priority
+            .cmp(&a.priority)
+            .then(due_date_order(a.due_date, b.due_date))
+            .then(a.created_at.cmp(&b.created_at))
+    });
+
+    // Greedily take every todo that still fits into the remaining capacity
+    let mut remaining = available_minutes;
+    let mut plan = Vec::new();
+    for todo in candidates {
+        let estimate = todo.estimated_minutes.unwrap_or_default();
+        if estimate <= remaining {
+            remaining -= estimate;
+            plan.push(todo);
+        }
+    }
+    project_all(plan, projection)
+}
+
+#[ic_cdk::query]
+fn whoami() -> Principal {
+    ic_cdk::caller()
+}
+
+#[ic_cdk::query]
+fn my_profile() -> Profile {
+    let principal = ic_cdk::caller();
+    let todos = _get_owner_todos(&principal.to_string());
+    let count =
+        |status: TaskStatus| todos.iter().filter(|todo| todo.status == status).count() as u64;
+
+    Profile {
+        principal,
+        total_todos: todos.len() as u64,
+        pending: count(TaskStatus::Pending),
+        in_progress: count(TaskStatus::InProgress),
+        waiting: count(TaskStatus::Waiting),
+        completed: count(TaskStatus::Completed),
+        first_todo_created_at: todos.iter().map(|todo| todo.created_at).min(),
+        settings: _get_settings(&principal),
+    }
+}
+
+#[ic_cdk::query]
+fn get_config() -> Config {
+    _get_config()
+}
+
+#[ic_cdk::update]
+fn set_config(config: Config) -> Result<(), Error> {
+    if !ic_cdk::api::is_controller(&ic_cdk::caller()) {
+        return Err(Error::Unauthorized {
+            msg: "Only controllers can change the config".to_string(),
+        });
+    }
+
+    validate_config(&config)?;
+    _set_config(config);
+    Ok(())
+}
+
+#[ic_cdk::update]
+fn set_completion_hook(canister: Option<Principal>) -> Result<(), Error> {
+    if !ic_cdk::api::is_controller(&ic_cdk::caller()) {
+        return Err(Error::Unauthorized {
+            msg: "Only controllers can set the completion hook".to_string(),
+        });
+    }
+
+    let mut config = _get_config();
+    config.completion_hook = canister;
+    _set_config(config);
+    Ok(())
+}
+
+#[ic_cdk::query]
+fn get_my_settings() -> Settings {
+    _get_settings(&ic_cdk::caller())
+}
+
+#[ic_cdk::update]
+fn update_my_settings(patch: SettingsPatch) -> Result<Settings, Error> {
+    let caller = ic_cdk::caller();
+    if caller == Principal::anonymous() {
+        return Err(Error::Unauthorized {
+            msg: "Anonymous callers cannot store settings".to_string(),
+        });
+    }
+
+    let mut settings = _get_settings(&caller);
+    if let Some(default_priority) = patch.default_priority {
+        settings.default_priority = default_priority;
+    }
+    if let Some(default_due_offset_days) = patch.default_due_offset_days {
+        settings.default_due_offset_days = default_due_offset_days;
+    }
+    if let Some(timezone_offset_minutes) = patch.timezone_offset_minutes {
+        settings.timezone_offset_minutes = timezone_offset_minutes;
+    }
+    if let Some(auto_escalate_overdue) = patch.auto_escalate_overdue {
+        settings.auto_escalate_overdue = auto_escalate_overdue;
+    }
+    if let Some(trash_retention_days) = patch.trash_retention_days {
+        settings.trash_retention_days = trash_retention_days;
+    }
+    if let Some(show_on_leaderboard) = patch.show_on_leaderboard {
+        settings.show_on_leaderboard = show_on_leaderboard;
+    }
+    if let Some(display_name) = patch.display_name {
+        settings.display_name = display_name.map(|name| name.trim().to_string());
+    }
+    validate_settings(&settings)?;
+
+    SETTINGS.with(|s| {
+        s.borrow_mut()
+            .insert(principal_key(&caller), settings.clone())
+    });
+    Ok(settings)
+}
+
+#[ic_cdk::query]
+fn get_cycles() -> CyclesReport {
+    let balance = ic_cdk::api::canister_balance();
+    let threshold = _get_config().low_cycles_threshold;
+    CyclesReport {
+        balance,
+        threshold,
+        low: balance < threshold,
+    }
+}
+
+#[ic_cdk::query]
+fn admin_verify_integrity(cursor: Option<IntegrityCursor>) -> Result<IntegrityReport, Error> {
+    if !ic_cdk::api::is_controller(&ic_cdk::caller()) {
+        return Err(Error::Unauthorized {
+            msg: "Only controllers can verify the integrity".to_string(),
+        });
+    }
+    verify_integrity(cursor)
+}
+
+#[ic_cdk::query]
+fn admin_list_todos(
+    cursor: Option<u64>,
+    projection: Option<Projection>,
+) -> Result<ScanPage, Error> {
+    if !ic_cdk::api::is_controller(&ic_cdk::caller()) {
+        return Err(Error::Unauthorized {
+            msg: "Only controllers can list all todos".to_string(),
+        });
+    }
+    Ok(project_scan(scan_todos(cursor, |_| true), projection))
+}
+
+// Repairs the indexes one batch per call, see repair_integrity. Pass the cursor of each report
+// back until it is complete.
+#[ic_cdk::update]
+fn admin_repair(cursor: Option<IntegrityCursor>) -> Result<IntegrityReport, Error> {
+    if !ic_cdk::api::is_controller(&ic_cdk::caller()) {
+        return Err(Error::Unauthorized {
+            msg: "Only controllers can repair the indexes".to_string(),
+        });
+    }
+    repair_integrity(cursor)
+}
+
+#[ic_cdk::query]
+fn daily_digest(for_day: Option<u64>) -> Digest {
+    let caller = ic_cdk::caller();
+    let utc_offset_seconds = _get_settings(&caller).timezone_offset_minutes as i64 * 60;
+    let day_start = local_day_start(for_day.unwrap_or_else(time), utc_offset_seconds);
+    let day_end = day_start + NANOS_PER_DAY;
+    let yesterday_start = day_start.saturating_sub(NANOS_PER_DAY);
+
+    let mut digest = Digest {
+        day_start,
+        ..Default::default()
+    };
+    for todo in _get_owner_todos(&caller.to_string()) {
+        let section = match (&todo.status, todo.due_date, todo.completed_at) {
+            (TaskStatus::Completed, _, Some(completed))
+                if completed >= yesterday_start && completed < day_start =>
+            {
+                &mut digest.completed_yesterday
+            }
+            (TaskStatus::Completed, _, _) => continue,
+            (_, Some(due), _) if due >= day_start && due < day_end => &mut digest.due_today,
+            (_, Some(due), _) if due < day_start => &mut digest.overdue,
+            _ => continue,
+        };
+        section.todos.push(todo);
+    }
+
+    digest.due_today.todos.sort_by_key(|todo| todo.due_date);
+    digest.overdue.todos.sort_by_key(|todo| todo.due_date);
+    digest
+        .completed_yesterday
+        .todos
+        .sort_by_key(|todo| todo.completed_at);
+    for section in [
+        &mut digest.due_today,
+        &mut digest.overdue,
+        &mut digest.completed_yesterday,
+    ] {
+        if section.todos.len() > DIGEST_SECTION_LIMIT {
+            section.has_more = true;
+            section.todos.truncate(DIGEST_SECTION_LIMIT);
+        }
+    }
+    digest.unread_notifications = unread_count();
+    digest
+}
+
+#[ic_cdk::query]
+fn search_all(query: String, projection: Option<Projection>) -> Result<Vec<Todo>, Error> {
+    _search_caller_todos(query).map(|todos| project_all(todos, projection))
+}
+
+#[ic_cdk::query]
+fn search_titles_prefix(
+    prefix: String,
+    limit: u32,
+    projection: Option<Projection>,
+) -> Result<Vec<Todo>, Error> {
+    let tokens = title_tokens(&prefix);
+    let [prefix] = tokens.as_slice() else {
+        return Err(Error::InvalidInput {
+            msg: "Prefix must be a single word".to_string(),
+        });
+    };
+    let limit = (limit as u64).clamp(1, MAX_PAGE_LIMIT) as usize;
+    if indexes_stale() {
+        return Err(Error::Conflict {
+            msg: "Title search is unavailable while the indexes are rebuilt".to_string(),
+        });
+    }
+
+    let owner = principal_key(&ic_cdk::caller());
+    let prefix_key = token_key(prefix);
+    let mut seen = std::collections::BTreeSet::new();
+    let mut todos = Vec::new();
+    TITLE_TOKEN_INDEX.with(|index| {
+        for (((other, token), id), _) in index.borrow().range(((owner, prefix_key), 0)..) {
+            if other != owner || !token.as_slice().starts_with(prefix_key.as_slice()) {
+                break;
+            }
+            // A todo whose title has several matching words is listed once
+            if !seen.insert(id) {
+                continue;
+            }
+            // Words longer than a token key only match on their first bytes, check the full
+            // title before the todo counts toward the limit
+            let Some(todo) = _get_todo(&id) else { continue };
+            if title_tokens(&todo.title)
+                .iter()
+                .any(|token| token.starts_with(prefix.as_str()))
+            {
+                todos.push(todo);
+                if todos.len() == limit {
+                    break;
+                }
+            }
+        }
+    });
+    Ok(project_all(todos, projection))
+}
+
+// Helper function to search the caller's todos by title, label name and description
+fn _search_caller_todos(query: String) -> Result<Vec<Todo>, Error> {
+    let query = fold_text(query.trim());
+    if query.is_empty() {
+        return Err(Error::InvalidInput {
+            msg: "Search query cannot be empty".to_string(),
+        });
+    }
+    if query.chars().count() > MAX_TITLE_LENGTH {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Search query cannot be longer than {} characters",
+                MAX_TITLE_LENGTH
+            ),
+        });
+    }
+
+    let owner = ic_cdk::caller().to_string();
+    let matching_labels: Vec<u64> = _get_owner_labels(&owner)
+        .into_iter()
+        .filter(|label| fold_text(&label.name).contains(&query))
+        .map(|label| label.id)
+        .collect();
+
+    // Rank by where the query matched: title, then label, then description
+    let mut ranked: Vec<(u8, Todo)> = _get_owner_todos(&owner)
+        .into_iter()
+        .filter_map(|todo| {
+            let rank = if fold_text(&todo.title).contains(&query) {
+                0
+            } else if todo.label_ids.iter().any(|id| matching_labels.contains(id)) {
+                1
+            } else if fold_text(&todo.description).contains(&query) {
+                2
+            } else {
+                return None;
+            };
+            Some((rank, todo))
+        })
+        .collect();
+    ranked.sort_by_key(|(rank, _)| *rank);
+
+    Ok(ranked.into_iter().map(|(_, todo)| todo).collect())
+}
+
+#[ic_cdk::query]
+fn my_streak() -> Streak {
+    let caller = ic_cdk::caller();
+    let mut streak = STREAKS
+        .with(|streaks| streaks.borrow().get(&principal_key(&caller)))
+        .unwrap_or_default();
+
+    // The current streak is broken once a whole local day passed without completions
+    let utc_offset_seconds = _get_settings(&caller).timezone_offset_minutes as i64 * 60;
+    let yesterday = local_day_start(time(), utc_offset_seconds).saturating_sub(NANOS_PER_DAY);
+    if streak.last_completion_day.is_none_or(|day| day < yesterday) {
+        streak.current_days = 0;
+    }
+    streak
+}
+
+#[ic_cdk::query]
+fn get_todos_assigned_to_me(projection: Option<Projection>) -> Vec<Todo> {
+    let caller = ic_cdk::caller();
+    let owner = caller.to_string();
+    // Only accepted assignments like list_assigned_to_me, proposals are in list_pending_assignments
+    let mut todos: Vec<Todo> = _get_assigned_todos(&caller, AssignmentState::Accepted)
+        .into_iter()
+        .filter(|todo| todo.owner != owner)
+        .collect();
+
+    todos.sort_by(|a, b| {
+        b.priority
+            .cmp(&a.priority)
+            .then(due_date_order(a.due_date, b.due_date))
+    });
+    project_all(todos, projection)
+}
+
+// Pages are in id order, only get_todos_assigned_to_me ranks by priority and due date
+#[ic_cdk::query]
+fn get_todos_assigned_to_me_page(cursor: Option<u64>, projection: Option<Projection>) -> ScanPage {
+    let caller = ic_cdk::caller();
+    let owner = caller.to_string();
+    let page = page_indexed_todos(&ASSIGNEE_INDEX, &caller, cursor, |todo| {
+        is_assigned(todo, &caller, &AssignmentState::Accepted) && todo.owner != owner
+    });
+    project_scan(page, projection)
+}
+
+#[ic_cdk::query]
+fn get_focus_list(max: u8, projection: Option<Projection>) -> Result<Vec<Todo>, Error> {
+    if max == 0 {
+        return Err(Error::InvalidInput {
+            msg: "Max must be greater than 0".to_string(),
+        });
+    }
+    let max = max.min(MAX_FOCUS_ITEMS) as usize;
+
+    // Overdue first, then due soon, then everything else
+    let now = time();
+    let urgency = |todo: &Todo| match todo.due_date {
+        Some(due) if due < now => 0,
+        Some(due) if due < now.saturating_add(DUE_SOON_WINDOW) => 1,
+        _ => 2,
+    };
+
+    let mut todos: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(is_actionable)
+        .collect();
+    todos.sort_by(|a, b| {
+        urgency(a)
+            .cmp(&urgency(b))
+            .then(b.priority.cmp(&a.priority))
+            .then(due_date_order(a.due_date, b.due_date))
+            .then(a.created_at.cmp(&b.created_at))
+    });
+    todos.truncate(max);
+    Ok(project_all(todos, projection))
+}
+
+#[ic_cdk::query]
+fn my_score() -> Score {
+    SCORES
+        .with(|scores| scores.borrow().get(&principal_key(&ic_cdk::caller())))
+        .unwrap_or_default()
+}
+
+#[ic_cdk::query]
+fn leaderboard(period: Period) -> Vec<LeaderboardEntry> {
+    let since = period_start(&period, time());
+
+    // Only users who opted in are ever looked at
+    let participants: Vec<(Principal, Settings)> = SETTINGS.with(|settings| {
+        settings
+            .borrow()
+            .iter()
+            .filter(|(_, settings)| settings.show_on_leaderboard)
+            .map(|(key, settings)| (Principal::from_slice(key.as_slice()), settings))
+            .collect()
+    });
+
+    let mut entries: Vec<LeaderboardEntry> = participants
+        .into_iter()
+        .map(|(principal, settings)| {
+            let completed = _get_owner_todos_with_status(&principal, TaskStatus::Completed);
+            let (completed, points) = tally_completions(&completed, since);
+            LeaderboardEntry {
+                principal,
+                display_name: settings.display_name,
+                completed,
+                points,
+            }
+        })
+        .filter(|entry| entry.completed > 0)
+        .collect();
+
+    entries.sort_by(|a, b| b.points.cmp(&a.points).then(b.completed.cmp(&a.completed)));
+    entries.truncate(MAX_LEADERBOARD_ENTRIES);
+    entries
+}
+
+// Tallies participants in principal order until MAX_SCAN_ENTRIES completed todos were read;
+// clients merge the entries of all pages and rank them
+#[ic_cdk::query]
+fn leaderboard_page(period: Period, cursor: Option<Principal>) -> LeaderboardPage {
+    let since = period_start(&period, time());
+    let start = cursor.map_or(Bound::Unbounded, |cursor| {
+        Bound::Excluded(principal_key(&cursor))
+    });
+
+    let mut page = LeaderboardPage {
+        entries: Vec::new(),
+        cursor,
+        complete: true,
+    };
+    let mut examined = 0;
+    SETTINGS.with(|settings| {
+        for (key, settings) in settings.borrow().range((start, Bound::Unbounded)) {
+            if examined >= MAX_SCAN_ENTRIES {
+                page.complete = false;
+                break;
+            }
+            let principal = Principal::from_slice(key.as_slice());
+            page.cursor = Some(principal);
+            if !settings.show_on_leaderboard {
+                continue;
+            }
+
+            let completed = _get_owner_todos_with_status(&principal, TaskStatus::Completed);
+            examined += completed.len();
+            let (completed, points) = tally_completions(&completed, since);
+            if completed > 0 {
+                page.entries.push(LeaderboardEntry {
+                    principal,
+                    display_name: settings.display_name,
+                    completed,
+                    points,
+                });
+            }
+        }
+    });
+    page
+}
+
+#[ic_cdk::query]
+fn filter_todos_by_status(status: TaskStatus, projection: Option<Projection>) -> Vec<Todo> {
+    project_all(_filter_caller_todos_by_status(status), projection)
+}
+
+// Helper function to get the caller's todos with the given status in manual order
+fn _filter_caller_todos_by_status(status: TaskStatus) -> Vec<Todo> {
+    let mut todos = _get_owner_todos_with_status(&ic_cdk::caller(), status);
+    sort_todos(&mut todos, &SortBy::Manual);
+    pinned_first(&mut todos);
+    todos
+}
+
+#[ic_cdk::query]
+fn get_todos_due_in_range(
+    from: u64,
+    to: u64,
+    projection: Option<Projection>,
+) -> Result<Vec<Todo>, Error> {
+    if from >= to {
+        return Err(Error::InvalidInput {
+            msg: "Range start must be before its end".to_string(),
+        });
+    }
+    Ok(project_all(
+        _get_owner_todos_due_between(&ic_cdk::caller(), from, to).collect(),
+        projection,
+    ))
+}
+
+#[ic_cdk::query]
+fn get_completed_between(
+    start: u64,
+    end: u64,
+    projection: Option<Projection>,
+) -> Result<Vec<Todo>, Error> {
+    if start > end {
+        return Err(Error::InvalidInput {
+            msg: "Range start cannot be after its end".to_string(),
+        });
+    }
+
+    let mut todos: Vec<Todo> =
+        _get_owner_todos_with_status(&ic_cdk::caller(), TaskStatus::Completed)
+            .into_iter()
+            .filter(|todo| {
+                todo.completed_at
+                    .is_some_and(|completed_at| (start..=end).contains(&completed_at))
+            })
+            .collect();
+    todos.sort_by_key(|todo| todo.completed_at);
+    Ok(project_all(todos, projection))
+}
+
+#[ic_cdk::query]
+fn get_overdue_todos(projection: Option<Projection>) -> Vec<Todo> {
+    let todos = _get_owner_todos_due_between(&ic_cdk::caller(), 0, time())
+        .filter(|todo| todo.status != TaskStatus::Completed)
+        .collect();
+    project_all(todos, projection)
+}
+
+#[ic_cdk::query]
+fn get_next_n_due_todos(n: u64, projection: Option<Projection>) -> Vec<Todo> {
+    let n = n.min(MAX_PAGE_LIMIT) as usize;
+    let todos = _get_owner_todos_due_between(&ic_cdk::caller(), time(), u64::MAX)
+        .filter(|todo| todo.status != TaskStatus::Completed)
+        .take(n)
+        .collect();
+    project_all(todos, projection)
+}
+
+#[ic_cdk::query]
+fn get_stale_todos(limit: u64, projection: Option<Projection>) -> Vec<Todo> {
+    let limit = limit.min(MAX_PAGE_LIMIT) as usize;
+
+    let mut todos: Vec<Todo> = _get_owner_todos(&ic_cdk::caller().to_string())
+        .into_iter()
+        .filter(|todo| todo.status != TaskStatus::Completed)
+        .collect();
+    // Oldest first, ids break ties between todos created in the same round
+    todos.sort_by_key(|todo| (todo.created_at, todo.id));
+    todos.truncate(limit);
+    project_all(todos, projection)
+}
+
+#[ic_cdk::inspect_message]
+fn inspect_message() {
+    let method = ic_cdk::api::call::method_name();
+    if accepts_message(
+        &method,
+        &ic_cdk::caller(),
+        ic_cdk::api::call::arg_data_raw_size(),
+    ) {
+        ic_cdk::api::call::accept_message();
+    }
+}
+
+// Helper function to decide whether an ingress message is accepted. Only what is certainly
+// invalid is rejected, everything else is left to the method itself.
+fn accepts_message(method: &str, caller: &Principal, arg_size: usize) -> bool {
+    let is_update = UPDATE_METHODS.contains(&method);
+    if !is_update && !QUERY_METHODS.contains(&method) {
+        return false;
+    }
+    if is_update && *caller == Principal::anonymous() {
+        return false;
+    }
+    method == "import_todos_merge" || arg_size <= MAX_INGRESS_ARG_BYTES
+}
+
+#[ic_cdk::query]
+fn list_todos_filtered(
+    criteria: FilterCriteria,
+    sort: Option<SortSpec>,
+    cursor: Option<FilterCursor>,
+    limit: u64,
+    projection: Option<Projection>,
+) -> Result<FilteredTodoPage, Error> {
+    validate_filter_criteria(&criteria)?;
+    _filter_caller_todos(&criteria, sort.as_ref(), cursor.as_ref(), limit).map(|page| {
+        FilteredTodoPage {
+            todos: project_all(page.todos, projection),
+            ..page
+        }
+    })
+}
+
+#[ic_cdk::query]
+fn list_saved_filters() -> Vec<SavedFilter> {
+    _get_owner_saved_filters(&ic_cdk::caller().to_string())
+}
+
+#[ic_cdk::update]
+fn create_saved_filter(mut payload: SavedFilterPayload) -> Result<SavedFilter, Error> {
+    let owner = ic_cdk::caller().to_string();
+    validate_saved_filter_payload(&mut payload)?;
+
+    if _get_owner_saved_filters(&owner).len() >= MAX_SAVED_FILTERS_PER_USER {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Cannot create more than {} saved filters",
+                MAX_SAVED_FILTERS_PER_USER
+            ),
+        });
+    }
+
+    let filter = SavedFilter {
+        id: next_id(&SAVED_FILTER_ID_COUNTER),
+        name: payload.name,
+        criteria: payload.criteria,
+        owner,
+    };
+
+    SAVED_FILTERS.with(|filters| filters.borrow_mut().insert(filter.id, filter.clone()));
+    Ok(filter)
+}
+
+#[ic_cdk::update]
+fn update_saved_filter(id: u64, mut payload: SavedFilterPayload) -> Result<SavedFilter, Error> {
+    let mut filter = _get_owned_saved_filter(id, &ic_cdk::caller().to_string())?;
+    validate_saved_filter_payload(&mut payload)?;
+
+    filter.name = payload.name;
+    filter.criteria = payload.criteria;
+
+    SAVED_FILTERS.with(|filters| filters.borrow_mut().insert(filter.id, filter.clone()));
+    Ok(filter)
+}
+
+#[ic_cdk::update]
+fn delete_saved_filter(id: u64) -> Result<SavedFilter, Error> {
+    let filter = _get_owned_saved_filter(id, &ic_cdk::caller().to_string())?;
+    SAVED_FILTERS.with(|filters| filters.borrow_mut().remove(&id));
+    Ok(filter)
+}
+
+#[ic_cdk::query]
+fn run_saved_filter(
+    id: u64,
+    cursor: Option<FilterCursor>,
+    limit: u64,
+    projection: Option<Projection>,
+) -> Result<FilteredTodoPage, Error> {
+    let filter = _get_owned_saved_filter(id, &ic_cdk::caller().to_string())?;
+    _filter_caller_todos(&filter.criteria, None, cursor.as_ref(), limit).map(|page| {
+        FilteredTodoPage {
+            todos: project_all(page.todos, projection),
+            ..page
+        }
+    })
+}
+
+// Helper function to forget the imported ids of an owner that map to deleted todos
+fn remove_imported_ids(owner: &Principal, deleted: &std::collections::BTreeSet<u64>) {
+    let owner = principal_key(owner);
+    let stale: Vec<(PrincipalKey, u64)> = IMPORTED_IDS.with(|ids| {
+        ids.borrow()
+            .range((owner, 0)..)
+            .take_while(|((other, _), _)| *other == owner)
+            .filter(|(_, id)| deleted.contains(id))
+            .map(|(key, _)| key)
+            .collect()
+    });
+    for key in stale {
+        IMPORTED_IDS.with(|ids| ids.borrow_mut().remove(&key));
+    }
+}
+
+// Helper function to find the todo an imported item updates: the todo an earlier import of the
+// same id created, otherwise the owner's own todo with that id
+fn _get_import_target(owner: &Principal, item_id: Option<u64>) -> Option<Todo> {
+    let item_id = item_id?;
+    let imported = IMPORTED_IDS.with(|ids| ids.borrow().get(&(principal_key(owner), item_id)));
+    imported
+        .into_iter()
+        .chain([item_id])
+        .filter_map(|id| _get_todo(&id))
+        .find(|todo| todo.owner == owner.to_string())
+}
+
+// Helper function to take the next id from an id counter
+fn next_id(counter: &'static LocalKey<RefCell<IdCell>>) -> u64 {
+    counter
+        .with(|counter| {
+            let current_value = *counter.borrow().get();
+            counter.borrow_mut().set(current_value + 1)
+        })
+        .expect("cannot increment id counter")
+}
+
+// Helper function to insert todo and bump its version, rejecting todos that don't fit into a
+// storage slot
+fn do_insert(todo: &mut Todo) -> Result<(), Error> {
+    check_todo_size(todo)?;
+    let stored = _get_todo(&todo.id);
+    if can_duplicate_title(stored.as_ref(), todo) {
+        check_unique_title(todo, &[])?;
+    }
+
+    // Only freeze_todo and unfreeze_todo store a todo whose frozen flag changes
+    if todo.frozen && stored.as_ref().is_some_and(|stored| stored.frozen) {
+        return Err(Error::InvalidInput {
+            msg: "Todo is frozen".to_string(),
+        });
+    }
+
+    todo.version = stored.map_or(0, |stored| stored.version + 1);
+    todo.last_modified_by = Some(ic_cdk::caller());
+
+    let previous = STORAGE.with(|service| service.borrow_mut().insert(todo.id, todo.clone()));
+    index_title(previous.as_ref(), Some(todo));
+    index_owner(previous.as_ref(), Some(todo));
+    index_status(previous.as_ref(), Some(todo));
+    index_due_date(previous.as_ref(), Some(todo));
+    index_title_tokens(previous.as_ref(), Some(todo));
+    index_assignee(previous.as_ref(), Some(todo));
+    index_collaborators(previous.as_ref(), Some(todo));
+    // Every path that completes a stored todo extends the streak and awards points, a todo
+    // created as completed, e.g. by an import, earns neither
+    match previous {
+        None => publish_event(EventKind::Created, todo),
+        Some(previous)
+            if previous.status != TaskStatus::Completed && todo.status == TaskStatus::Completed =>
+        {
+            record_completion_day(todo);
+            award_completion(todo);
+            publish_event(EventKind::Completed, todo)
+        }
+        Some(_) => {}
+    }
+    Ok(())
+}
+
+// Helper function to reject a todo that doesn't fit into a storage slot
+fn check_todo_size(todo: &Todo) -> Result<(), Error> {
+    let size = Encode!(todo)
+        .map_err(|e| Error::InvalidInput {
+            msg: format!("Cannot encode todo with id={}: {}", todo.id, e),
+        })?
+        .len();
+    let max_size = _get_config().max_todo_size.min(Todo::MAX_SIZE);
+    if size > max_size as usize {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Todo is too large to store ({} bytes, maximum is {} bytes)",
+                size, max_size
+            ),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to reject a batch write before its first insert when any target is frozen
+fn check_none_frozen<'a>(todos: impl IntoIterator<Item = &'a Todo>) -> Result<(), Error> {
+    match todos.into_iter().find(|todo| todo.frozen) {
+        Some(todo) => Err(Error::InvalidInput {
+            msg: format!("Todo with id={} is frozen", todo.id),
+        }),
+        None => Ok(()),
+    }
+}
+
+// Helper function to remove a todo together with the data that only exists for it
+fn do_remove(id: u64) {
+    if let Some(todo) = STORAGE.with(|service| service.borrow_mut().remove(&id)) {
+        index_title(Some(&todo), None);
+        index_owner(Some(&todo), None);
+        index_status(Some(&todo), None);
+        index_due_date(Some(&todo), None);
+        index_title_tokens(Some(&todo), None);
+        index_assignee(Some(&todo), None);
+        index_collaborators(Some(&todo), None);
+        publish_event(EventKind::Deleted, &todo);
+    }
+    for checkpoint in _get_todo_checkpoints(id) {
+        remove_checkpoint(&checkpoint);
+    }
+    for token in _get_todo_share_tokens(id) {
+        remove_share_token(id, token);
+    }
+    TRANSFER_OFFERS.with(|offers| offers.borrow_mut().remove(&id));
+    for key in _get_priority_history(id).into_iter().map(|(key, _)| key) {
+        PRIORITY_HISTORY.with(|history| history.borrow_mut().remove(&key));
+    }
+    for key in _get_ownership_history(id).into_iter().map(|(key, _)| key) {
+        OWNERSHIP_HISTORY.with(|history| history.borrow_mut().remove(&key));
+    }
+}
+
+// Helper function to get todo
+fn _get_todo(id: &u64) -> Option<Todo> {
+    STORAGE.with(|service| service.borrow().get(id))
+}
+
+// Helper function to get a todo owned by the caller, action describes the attempted operation
+fn _get_owned_todo(id: u64, action: &str) -> Result<Todo, Error> {
+    match _get_todo(&id) {
+        Some(todo) if todo.owner == ic_cdk::caller().to_string() => Ok(todo),
+        Some(_) => Err(Error::NotFound {
+            msg: format!("Not authorized to {} todo with id={}", action, id),
+        }),
+        None => Err(Error::NotFound {
+            msg: format!("Couldn't {} todo with id={}. Todo not found", action, id),
+        }),
+    }
+}
+
+// Helper function to get the role of a principal among a todo's collaborators
+fn collaborator_role<'a>(todo: &'a Todo, principal: &Principal) -> Option<&'a Role> {
+    todo.collaborators
+        .iter()
+        .find(|(p, _)| p == principal)
+        .map(|(_, role)| role)
+}
+
+// Helper function to check whether a principal may read a todo: its owner, any collaborator,
+// or the assignee while the assignment is proposed or accepted
+fn can_view(todo: &Todo, principal: &Principal) -> bool {
+    todo.owner == principal.to_string()
+        || todo.assignee.as_ref() == Some(principal)
+        || collaborator_role(todo, principal).is_some()
+}
+
+// Helper function to check whether a principal may change the fields of a todo: its owner or
+// an editor
+fn can_edit(todo: &Todo, principal: &Principal) -> bool {
+    todo.owner == principal.to_string() || collaborator_role(todo, principal) == Some(&Role::Editor)
+}
+
+// Helper function to get a permission that allows changing every field
+fn full_field_permission() -> FieldPermission {
+    FieldPermission {
+        title: true,
+        description: true,
+        priority: true,
+        due_date: true,
+        status: true,
+    }
+}
+
+// Helper function to get the fields a principal may change on a todo it can edit. Only editors
+// with an explicit entry are restricted.
+fn field_permission(todo: &Todo, principal: &Principal) -> FieldPermission {
+    todo.field_permissions
+        .iter()
+        .find(|(p, _)| p == principal)
+        .map(|(_, permission)| permission.clone())
+        .unwrap_or_else(full_field_permission)
+}
+
+// Helper function to check whether a principal may change the status of a todo: anyone who
+// may edit it, or an assignee who accepted the assignment
+fn can_change_status(todo: &Todo, principal: &Principal) -> bool {
+    can_edit(todo, principal)
+        || (todo.assignee.as_ref() == Some(principal)
+            && todo.assignment_state == Some(AssignmentState::Accepted))
+}
+
+// Helper function to get the todos assigned to a principal in the given assignment state
+fn _get_assigned_todos(assignee: &Principal, state: AssignmentState) -> Vec<Todo> {
+    _get_indexed_todos(&ASSIGNEE_INDEX, assignee)
+        .into_iter()
+        .filter(|todo| is_assigned(todo, assignee, &state))
+        .collect()
+}
+
+// Helper function to check whether a todo is assigned to a principal in the given state
+fn is_assigned(todo: &Todo, assignee: &Principal, state: &AssignmentState) -> bool {
+    todo.assignee.as_ref() == Some(assignee) && todo.assignment_state.as_ref() == Some(state)
+}
+
+// Helper function to check whether a todo shared with the caller was changed by someone else
+// since the given time
+fn is_shared_change(todo: &Todo, caller: &Principal, since: u64) -> bool {
+    collaborator_role(todo, caller).is_some()
+        && todo.updated_at.unwrap_or(todo.created_at) >= since
+        && todo.last_modified_by != Some(*caller)
+}
+
+// Helper function to get all todos of an owner, in id order
+fn _get_owner_todos(owner: &str) -> Vec<Todo> {
+    match Principal::from_text(owner) {
+        Ok(owner) => _get_indexed_todos(&OWNER_INDEX, &owner),
+        Err(_) => Vec::new(),
+    }
+}
+
+// Helper function to get the todos a principal keyed index lists under a principal, in id order
+fn _get_indexed_todos(
+    index: &'static LocalKey<RefCell<TodoIndex<PrincipalKey>>>,
+    principal: &Principal,
+) -> Vec<Todo> {
+    let key = principal_key(principal);
+    let ids: Vec<u64> = index.with(|index| {
+        index
+            .borrow()
+            .range((key, 0)..)
+            .take_while(|((other, _), _)| *other == key)
+            .map(|((_, id), _)| id)
+            .collect()
+    });
+    STORAGE.with(|service| {
+        let service = service.borrow();
+        ids.into_iter().filter_map(|id| service.get(&id)).collect()
+    })
+}
+
+// Helper function to page through the todos a principal keyed index lists under a principal,
+// examining at most MAX_PAGE_LIMIT entries with ids after the cursor per call
+fn page_indexed_todos(
+    index: &'static LocalKey<RefCell<TodoIndex<PrincipalKey>>>,
+    principal: &Principal,
+    cursor: Option<u64>,
+    keep: impl Fn(&Todo) -> bool,
+) -> ScanPage {
+    let key = principal_key(principal);
+    let start = cursor.map_or(Bound::Included((key, 0)), |id| Bound::Excluded((key, id)));
+    let limit = MAX_PAGE_LIMIT as usize;
+    // One entry more than a page tells whether the listing continues
+    let ids: Vec<u64> = index.with(|index| {
+        index
+            .borrow()
+            .range((start, Bound::Unbounded))
+            .take_while(|((other, _), _)| *other == key)
+            .map(|((_, id), _)| id)
+            .take(limit + 1)
+            .collect()
+    });
+    let mut page = ScanPage {
+        todos: Vec::new(),
+        cursor,
+        complete: ids.len() <= limit,
+    };
+    STORAGE.with(|service| {
+        let service = service.borrow();
+        for id in ids.into_iter().take(limit) {
+            page.cursor = Some(id);
+            match service.get(&id) {
+                Some(todo) if keep(&todo) => page.todos.push(todo),
+                _ => {}
+            }
+        }
+    });
+    page
+}
+
+// Helper function to move a todo's owner index entry when it is stored, transferred or removed
+fn index_owner(previous: Option<&Todo>, current: Option<&Todo>) {
+    if let Some(todo) = previous {
+        let key = (principal_key(&owner_principal(todo)), todo.id);
+        OWNER_INDEX.with(|index| index.borrow_mut().remove(&key));
+    }
+    if let Some(todo) = current {
+        let key = (principal_key(&owner_principal(todo)), todo.id);
+        OWNER_INDEX.with(|index| index.borrow_mut().insert(key, ()));
+    }
+}
+
+// Helper function to move a todo's assignee index entry when its assignee changes or it is
+// removed
+fn index_assignee(previous: Option<&Todo>, current: Option<&Todo>) {
+    if let Some((todo, assignee)) = previous.and_then(|todo| todo.assignee.map(|a| (todo, a))) {
+        let key = (principal_key(&assignee), todo.id);
+        ASSIGNEE_INDEX.with(|index| index.borrow_mut().remove(&key));
+    }
+    if let Some((todo, assignee)) = current.and_then(|todo| todo.assignee.map(|a| (todo, a))) {
+        let key = (principal_key(&assignee), todo.id);
+        ASSIGNEE_INDEX.with(|index| index.borrow_mut().insert(key, ()));
+    }
+}
+
+// Helper function to replace the collaborator index entries of a todo
+fn index_collaborators(previous: Option<&Todo>, current: Option<&Todo>) {
+    COLLABORATOR_INDEX.with(|index| {
+        let mut index = index.borrow_mut();
+        if let Some(todo) = previous {
+            for key in collaborator_index_keys(todo) {
+                index.remove(&(key, todo.id));
+            }
+        }
+        if let Some(todo) = current {
+            for key in collaborator_index_keys(todo) {
+                index.insert((key, todo.id), ());
+            }
+        }
+    });
+}
+
+// Helper function to run all validation rules for a new todo
+fn validate_payload(payload: &TodoPayload) -> Result<(), Error> {
+    validate_title(&payload.title)?;
+    validate_description(&payload.description)?;
+    check_description_required(&payload.description)?;
+    validate_color(&payload.color)?;
+    validate_estimate(payload.estimated_minutes)?;
+    validate_due_date(payload.due_date)?;
+    check_due_date_required(payload.due_date)
+}
+
+// Helper function to validate a todo title
+fn validate_title(title: &str) -> Result<(), Error> {
+    if title.trim().is_empty() {
+        return Err(Error::InvalidInput {
+            msg: "Title cannot be empty".to_string(),
+        });
+    }
+    if title.chars().count() > MAX_TITLE_LENGTH {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Title cannot be longer than {} characters",
+                MAX_TITLE_LENGTH
+            ),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to validate a todo description
+fn validate_description(description: &str) -> Result<(), Error> {
+    if description.chars().count() > MAX_DESCRIPTION_LENGTH {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Description cannot be longer than {} characters",
+                MAX_DESCRIPTION_LENGTH
+            ),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to validate an optional todo color
+fn validate_color(color: &Option<String>) -> Result<(), Error> {
+    match color {
+        Some(color) => validate_hex_color(color),
+        None => Ok(()),
+    }
+}
+
+// Helper function to validate that a due date, if set, is in the future
+fn validate_due_date(due_date: Option<u64>) -> Result<(), Error> {
+    match due_date {
+        Some(due) if due <= time() => Err(Error::InvalidInput {
+            msg: "Due date must be in the future".to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+// Helper function to validate a label name and color
+fn validate_label_payload(payload: &LabelPayload) -> Result<(), Error> {
+    if payload.name.trim().is_empty() {
+        return Err(Error::InvalidInput {
+            msg: "Label name cannot be empty".to_string(),
+        });
+    }
+    if payload.name.chars().count() > MAX_LABEL_NAME_LENGTH {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Label name cannot be longer than {} characters",
+                MAX_LABEL_NAME_LENGTH
+            ),
+        });
+    }
+    validate_hex_color(&payload.color)
+}
+
+// Helper function to validate a color in the #RRGGBB format
+fn validate_hex_color(color: &str) -> Result<(), Error> {
+    let valid = color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+    if !valid {
+        return Err(Error::InvalidInput {
+            msg: format!("Invalid color '{}', expected #RRGGBB", color),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to check that no other label of the owner already uses the name
+fn ensure_unique_label_name(
+    labels: &[Label],
+    name: &str,
+    except_id: Option<u64>,
+) -> Result<(), Error> {
+    let folded = fold_text(name.trim());
+    let taken = labels
+        .iter()
+        .any(|label| Some(label.id) != except_id && fold_text(label.name.trim()) == folded);
+    if taken {
+        return Err(Error::InvalidInput {
+            msg: format!("A label named '{}' already exists", name.trim()),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to validate a UTC offset given in seconds
+fn validate_utc_offset(utc_offset_seconds: i64) -> Result<(), Error> {
+    if !(MIN_UTC_OFFSET_SECONDS..=MAX_UTC_OFFSET_SECONDS).contains(&utc_offset_seconds) {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "UTC offset must be between {} and {} seconds",
+                MIN_UTC_OFFSET_SECONDS, MAX_UTC_OFFSET_SECONDS
+            ),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to get the start (in UTC nanoseconds) of the local day containing timestamp
+fn local_day_start(timestamp: u64, utc_offset_seconds: i64) -> u64 {
+    let offset = utc_offset_seconds as i128 * NANOS_PER_SECOND as i128;
+    let local = timestamp as i128 + offset;
+    let local_day_start = local - local.rem_euclid(NANOS_PER_DAY as i128);
+    (local_day_start - offset).max(0) as u64
+}
+
+// Helper function to get all labels of an owner
+fn _get_owner_labels(owner: &str) -> Vec<Label> {
+    LABELS.with(|labels| {
+        labels
+            .borrow()
+            .iter()
+            .filter(|(_, label)| label.owner == owner)
+            .map(|(_, label)| label)
+            .collect()
+    })
+}
+
+// Helper function to get a label owned by the given owner
+fn _get_owned_label(id: u64, owner: &str) -> Result<Label, Error> {
+    match LABELS.with(|labels| labels.borrow().get(&id)) {
+        Some(label) if label.owner == owner => Ok(label),
+        Some(_) => Err(Error::NotFound {
+            msg: format!("Not authorized to use label with id={}", id),
+        }),
+        None => Err(Error::NotFound {
+            msg: format!("Label with id={} not found", id),
+        }),
+    }
+}
+
+// Helper function to move pinned todos to the front, keeping the existing order otherwise
+fn pinned_first(todos: &mut [Todo]) {
+    todos.sort_by_key(|todo| !todo.pinned);
+}
+
+// Helper function to sort todos by the given criteria, ties are broken by id for a stable order
+fn sort_todos(todos: &mut [Todo], sort_by: &SortBy) {
+    todos.sort_by(|a, b| sort_order(a, b, sort_by));
+}
+
+// Helper function to compare two todos in a sort order; ties are broken by id, so the order is
+// total
+fn sort_order(a: &Todo, b: &Todo, sort_by: &SortBy) -> std::cmp::Ordering {
+    key_order(&sort_key(a, sort_by), a.id, &sort_key(b, sort_by), b.id)
+}
+
+// Helper function to get the value a todo is ordered by for a sort
+fn sort_key(todo: &Todo, sort_by: &SortBy) -> SortKey {
+    match sort_by {
+        SortBy::Manual => SortKey::Position(todo.position),
+        SortBy::CreatedAt => SortKey::CreatedAt(todo.created_at),
+        SortBy::UpdatedAt => SortKey::UpdatedAt(todo.updated_at.unwrap_or(todo.created_at)),
+        SortBy::DueDate => SortKey::DueDate(todo.due_date),
+        SortBy::Priority => SortKey::Priority(todo.priority.clone()),
+    }
+}
+
+// Helper function to compare two sort keys of the same sort, then their todo ids: manual
+// positions and due dates ascending with missing due dates last, everything else descending
+fn key_order(a: &SortKey, a_id: u64, b: &SortKey, b_id: u64) -> std::cmp::Ordering {
+    let by_key = match (a, b) {
+        (SortKey::Position(a), SortKey::Position(b)) => a.cmp(b),
+        (SortKey::CreatedAt(a), SortKey::CreatedAt(b))
+        | (SortKey::UpdatedAt(a), SortKey::UpdatedAt(b)) => b.cmp(a),
+        (SortKey::DueDate(a), SortKey::DueDate(b)) => (a.is_none(), a).cmp(&(b.is_none(), b)),
+        (SortKey::Priority(a), SortKey::Priority(b)) => b.cmp(a),
+        _ => std::cmp::Ordering::Equal,
+    };
+    by_key.then(a_id.cmp(&b_id))
+}
+
+// Helper function to get the manual position for a new todo, at the end of the owner's list
+fn next_position(owner: &str) -> u64 {
+    _get_owner_todos(owner)
+        .iter()
+        .map(|todo| todo.position)
+        .max()
+        .map_or(POSITION_GAP, |max| max + POSITION_GAP)
+}
+
+// Helper function to get a checkpoint by id
+fn _get_checkpoint(id: u64) -> Result<Checkpoint, Error> {
+    CHECKPOINTS
+        .with(|checkpoints| checkpoints.borrow().get(&id))
+        .ok_or_else(|| Error::NotFound {
+            msg: format!("Checkpoint with id={} not found", id),
+        })
+}
+
+// Helper function to get all checkpoints of a todo, oldest first
+fn _get_todo_checkpoints(todo_id: u64) -> Vec<Checkpoint> {
+    let ids: Vec<u64> = CHECKPOINT_INDEX.with(|index| {
+        index
+            .borrow()
+            .range((todo_id, 0)..)
+            .take_while(|((other, _), _)| *other == todo_id)
+            .map(|((_, id), _)| id)
+            .collect()
+    });
+    CHECKPOINTS.with(|checkpoints| {
+        let checkpoints = checkpoints.borrow();
+        ids.into_iter()
+            .filter_map(|id| checkpoints.get(&id))
+            .collect()
+    })
+}
+
+// Helper function to remove a checkpoint together with its index entry
+fn remove_checkpoint(checkpoint: &Checkpoint) {
+    CHECKPOINTS.with(|checkpoints| checkpoints.borrow_mut().remove(&checkpoint.id));
+    CHECKPOINT_INDEX.with(|index| {
+        index
+            .borrow_mut()
+            .remove(&(checkpoint.todo_id, checkpoint.id))
+    });
+}
+
+// Helper function to validate a template's payload and labels. The due date is only
+// checked when the template is instantiated.
+fn validate_template(payload: &TodoPayload, label_ids: &[u64], owner: &str) -> Result<(), Error> {
+    validate_title(&payload.title)?;
+    validate_description(&payload.description)?;
+    validate_color(&payload.color)?;
+    validate_estimate(payload.estimated_minutes)?;
+    if label_ids.len() > MAX_LABELS_PER_TODO {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "A template cannot have more than {} labels",
+                MAX_LABELS_PER_TODO
+            ),
+        });
+    }
+    for label_id in label_ids {
+        _get_owned_label(*label_id, owner)?;
+    }
+    Ok(())
+}
+
+// Helper function to get a template owned by the given owner
+fn _get_owned_template(id: u64, owner: &str) -> Result<Template, Error> {
+    match TEMPLATES.with(|templates| templates.borrow().get(&id)) {
+        Some(template) if template.owner == owner => Ok(template),
+        Some(_) => Err(Error::NotFound {
+            msg: format!("Not authorized to use template with id={}", id),
+        }),
+        None => Err(Error::NotFound {
+            msg: format!("Template with id={} not found", id),
+        }),
+    }
+}
+
+// Helper function to get the number of days since the UNIX epoch for a calendar date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // Algorithm from Howard Hinnant's "chrono-Compatible Low-Level Date Algorithms"
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// Helper function to get the day (since the UNIX epoch) on which ISO week 1 of a year starts,
+// i.e. the Monday of the week containing January 4th
+fn iso_week_one_start(year: i64) -> i64 {
+    let january_fourth = days_from_civil(year, 1, 4);
+    // 1970-01-01 was a Thursday, so shifting by 3 makes Monday weekday 0
+    let weekday = (january_fourth + 3).rem_euclid(7);
+    january_fourth - weekday
+}
+
+// Helper function to look up a share grant by its hex encoded token
+fn _get_share_grant(token: &str) -> Result<([u8; SHARE_TOKEN_BYTES], ShareGrant), Error> {
+    let not_found = || Error::NotFound {
+        msg: "Share token not found".to_string(),
+    };
+    let key: [u8; SHARE_TOKEN_BYTES] = decode_hex(token)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(not_found)?;
+    let grant = SHARE_TOKENS
+        .with(|tokens| tokens.borrow().get(&key))
+        .ok_or_else(not_found)?;
+    Ok((key, grant))
+}
+
+// Helper function to get the share tokens pointing at a todo
+fn _get_todo_share_tokens(todo_id: u64) -> Vec<[u8; SHARE_TOKEN_BYTES]> {
+    SHARE_TOKEN_INDEX.with(|index| {
+        index
+            .borrow()
+            .range((todo_id, [0; SHARE_TOKEN_BYTES])..)
+            .take_while(|((other, _), _)| *other == todo_id)
+            .map(|((_, token), _)| token)
+            .collect()
+    })
+}
+
+// Helper function to remove a share token together with its index entry
+fn remove_share_token(todo_id: u64, token: [u8; SHARE_TOKEN_BYTES]) {
+    SHARE_TOKENS.with(|tokens| tokens.borrow_mut().remove(&token));
+    SHARE_TOKEN_INDEX.with(|index| index.borrow_mut().remove(&(todo_id, token)));
+}
+
+// Helper function to encode bytes as lowercase hex
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// Helper function to decode a hex string, None if it is not valid hex
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+// Helper function to get the canister config
+fn _get_config() -> Config {
+    CONFIG.with(|config| config.borrow().get().clone())
+}
+
+// Helper function to replace the canister config
+fn _set_config(config: Config) {
+    CONFIG
+        .with(|cell| cell.borrow_mut().set(config))
+        .expect("cannot store config");
+}
+
+// Helper function to check whether a transfer offer has expired
+fn is_offer_expired(offer: &TransferOffer, now: u64) -> bool {
+    now >= offer.offered_at.saturating_add(TRANSFER_OFFER_TTL)
+}
+
+// Helper function to remove and return the pending transfer offer for a todo made to the
+// caller. Expired offers are removed as well, but reported as not found.
+fn _take_transfer_offer(id: u64) -> Result<TransferOffer, Error> {
+    let not_found = || Error::NotFound {
+        msg: format!("No pending transfer for todo with id={}", id),
+    };
+    let offer = TRANSFER_OFFERS
+        .with(|offers| offers.borrow().get(&id))
+        .ok_or_else(not_found)?;
+    if offer.to != ic_cdk::caller() {
+        return Err(not_found());
+    }
+
+    TRANSFER_OFFERS.with(|offers| offers.borrow_mut().remove(&id));
+    if is_offer_expired(&offer, time()) {
+        return Err(not_found());
+    }
+    Ok(offer)
+}
+
+// Helper function to build the storage key for a caller's idempotency key
+fn idempotency_key(
+    caller: &Principal,
+    key: &str,
+) -> Result<(PrincipalKey, Blob<MAX_IDEMPOTENCY_KEY_LENGTH>), Error> {
+    if key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LENGTH {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Idempotency key must be between 1 and {} bytes",
+                MAX_IDEMPOTENCY_KEY_LENGTH
+            ),
+        });
+    }
+    let key = Blob::try_from(key.as_bytes()).expect("key length was checked");
+    Ok((principal_key(caller), key))
+}
+
+// Helper function to get the todo previously created with an idempotency key, if the key was
+// used within the window. Expired keys of the same caller are pruned along the way.
+fn _get_idempotent_todo(key: &(PrincipalKey, Blob<MAX_IDEMPOTENCY_KEY_LENGTH>)) -> Option<Todo> {
+    let now = time();
+    let expired: Vec<_> = IDEMPOTENCY_KEYS.with(|keys| {
+        keys.borrow()
+            .range((key.0, Blob::default())..)
+            .take_while(|(other, _)| other.0 == key.0)
+            .filter(|(_, record)| now >= record.created_at.saturating_add(IDEMPOTENCY_WINDOW))
+            .map(|(other, _)| other)
+            .collect()
+    });
+    IDEMPOTENCY_KEYS.with(|keys| {
+        let mut keys = keys.borrow_mut();
+        for expired_key in &expired {
+            keys.remove(expired_key);
+        }
+    });
+
+    let record = IDEMPOTENCY_KEYS.with(|keys| keys.borrow().get(key))?;
+    // The todo may have been deleted or handed over since; then a new one is created
+    let owner = Principal::from_slice(key.0.as_slice()).to_string();
+    _get_todo(&record.todo_id).filter(|todo| todo.owner == owner)
+}
+
+// Helper function to get the stable map key of a principal
+fn principal_key(principal: &Principal) -> PrincipalKey {
+    Blob::try_from(principal.as_slice()).expect("principals are at most 29 bytes")
+}
+
+// Helper function to get the principal owning a todo
+fn owner_principal(todo: &Todo) -> Principal {
+    Principal::from_text(&todo.owner).expect("todo owners are principals")
+}
+
+// Helper function to get the ids of a recipient's notifications, oldest first
+fn _get_recipient_notification_ids(recipient: &Principal) -> Vec<u64> {
+    let key = principal_key(recipient);
+    NOTIFICATION_INDEX.with(|index| {
+        index
+            .borrow()
+            .range((key, 0)..)
+            .take_while(|((other, _), _)| *other == key)
+            .map(|((_, id), _)| id)
+            .collect()
+    })
+}
+
+// Helper function to deliver a notification about a todo. Old notifications of the recipient are
+// pruned first so the inbox stays bounded.
+fn notify(recipient: Principal, kind: NotificationKind, todo: &Todo, message: String) {
+    let now = time();
+    let key = principal_key(&recipient);
+
+    let ids = _get_recipient_notification_ids(&recipient);
+    let mut kept = ids.len();
+    for id in ids {
+        let notification = NOTIFICATIONS.with(|n| n.borrow().get(&id));
+        let expired =
+            notification.is_none_or(|n| now >= n.created_at.saturating_add(NOTIFICATION_RETENTION));
+        // Ids grow over time, so the oldest notifications are dropped first
+        if expired || kept >= MAX_NOTIFICATIONS_PER_USER {
+            NOTIFICATIONS.with(|n| n.borrow_mut().remove(&id));
+            NOTIFICATION_INDEX.with(|index| index.borrow_mut().remove(&(key, id)));
+            kept -= 1;
+        }
+    }
+
+    let notification = Notification {
+        id: next_id(&NOTIFICATION_ID_COUNTER),
+        recipient,
+        kind,
+        todo_id: todo.id,
+        message: truncate_to_bytes(&message, MAX_NOTIFICATION_MESSAGE_BYTES),
+        created_at: now,
+        read: false,
+    };
+    NOTIFICATION_INDEX.with(|index| index.borrow_mut().insert((key, notification.id), ()));
+    NOTIFICATIONS.with(|n| n.borrow_mut().insert(notification.id, notification));
+}
+
+// Helper function to store a todo and record a change of its priority once it is stored
+fn do_insert_tracking_priority(
+    todo: &mut Todo,
+    previous: &Priority,
+    now: u64,
+) -> Result<(), Error> {
+    do_insert(todo)?;
+    if *previous != todo.priority {
+        record_priority_change(todo, previous, now);
+    }
+    Ok(())
+}
+
+// Helper function to record that the caller changed the priority of a stored todo
+fn record_priority_change(todo: &Todo, from: &Priority, now: u64) {
+    let change = PriorityChange {
+        todo_id: todo.id,
+        from: from.clone(),
+        to: todo.priority.clone(),
+        changed_by: ic_cdk::caller().to_string(),
+        changed_at: now,
+    };
+    let key = (todo.id, next_id(&PRIORITY_CHANGE_ID_COUNTER));
+    PRIORITY_HISTORY.with(|history| history.borrow_mut().insert(key, change));
+}
+
+// Helper function to get a todo's priority changes with their keys, oldest first
+fn _get_priority_history(todo_id: u64) -> Vec<((u64, u64), PriorityChange)> {
+    PRIORITY_HISTORY.with(|history| {
+        history
+            .borrow()
+            .range((todo_id, 0)..)
+            .take_while(|((id, _), _)| *id == todo_id)
+            .collect()
+    })
+}
+
+// Helper function to hand a todo over to the principal of an accepted offer. Nothing but the
+// todo itself changes unless it was stored.
+fn transfer_todo(mut todo: Todo, offer: &TransferOffer, now: u64) -> Result<Todo, Error> {
+    let new_owner = offer.to;
+    todo.owner = new_owner.to_string();
+    // The new owner no longer needs to be a collaborator or assignee of their own todo;
+    // everyone else keeps their access and their field restrictions
+    todo.collaborators.retain(|(p, _)| *p != new_owner);
+    todo.field_permissions.retain(|(p, _)| *p != new_owner);
+    if todo.assignee == Some(new_owner) {
+        todo.assignee = None;
+        todo.assignment_state = None;
+    }
+    // Labels belong to the previous owner and pins count against the previous owner's limit
+    todo.label_ids.clear();
+    todo.pinned = false;
+    todo.position = next_position(&todo.owner);
+    todo.updated_at = Some(now);
+
+    do_insert(&mut todo)?;
+
+    // Share links were handed out by the previous owner
+    for token in _get_todo_share_tokens(todo.id) {
+        remove_share_token(todo.id, token);
+    }
+    record_ownership_change(offer, now);
+    Ok(todo)
+}
+
+// Helper function to record an accepted transfer offer
+fn record_ownership_change(offer: &TransferOffer, now: u64) {
+    let change = OwnershipChange {
+        todo_id: offer.todo_id,
+        from: offer.from.clone(),
+        to: offer.to,
+        transferred_at: now,
+    };
+    let key = (offer.todo_id, next_id(&OWNERSHIP_CHANGE_ID_COUNTER));
+    OWNERSHIP_HISTORY.with(|history| history.borrow_mut().insert(key, change));
+}
+
+// Helper function to get a todo's ownership changes with their keys, oldest first
+fn _get_ownership_history(todo_id: u64) -> Vec<((u64, u64), OwnershipChange)> {
+    OWNERSHIP_HISTORY.with(|history| {
+        history
+            .borrow()
+            .range((todo_id, 0)..)
+            .take_while(|((id, _), _)| *id == todo_id)
+            .collect()
+    })
+}
+
+// Helper function to get a notification addressed to the caller
+fn _get_received_notification(id: u64) -> Result<Notification, Error> {
+    match NOTIFICATIONS.with(|n| n.borrow().get(&id)) {
+        Some(notification) if notification.recipient == ic_cdk::caller() => Ok(notification),
+        Some(_) => Err(Error::NotFound {
+            msg: format!("Not authorized to access notification with id={}", id),
+        }),
+        None => Err(Error::NotFound {
+            msg: format!("Notification with id={} not found", id),
+        }),
+    }
+}
+
+// Helper function to queue an event for every subscriber interested in it. Subscribers only get
+// events about their own todos, except controllers who get all of them.
+fn publish_event(kind: EventKind, todo: &Todo) {
+    let subscriptions: Vec<Subscription> = SUBSCRIPTIONS.with(|subscriptions| {
+        subscriptions
+            .borrow()
+            .iter()
+            .map(|(_, subscription)| subscription)
+            .filter(|subscription| subscription.events.contains(&kind))
+            .filter(|subscription| {
+                subscription.subscriber.to_string() == todo.owner
+                    || ic_cdk::api::is_controller(&subscription.subscriber)
+            })
+            .collect()
+    });
+    if subscriptions.is_empty() {
+        return;
+    }
+
+    let event = TodoEvent {
+        id: next_id(&EVENT_ID_COUNTER),
+        kind,
+        todo_id: todo.id,
+        title: truncate_to_bytes(&todo.title, MAX_EVENT_TITLE_BYTES),
+        owner: todo.owner.clone(),
+        occurred_at: time(),
+    };
+    for subscription in subscriptions {
+        // Delivery is best effort, a full queue drops new events
+        if DELIVERY_QUEUE.with(|queue| queue.borrow().len()) >= MAX_QUEUED_DELIVERIES {
+            return;
+        }
+        let delivery = Delivery {
+            subscriber: subscription.subscriber,
+            callback: subscription.callback,
+            event: event.clone(),
+            attempts: 0,
+            next_attempt_at: event.occurred_at,
+        };
+        let id = next_id(&DELIVERY_ID_COUNTER);
+        DELIVERY_QUEUE.with(|queue| queue.borrow_mut().insert(id, delivery));
+        arm_timer(event.occurred_at);
+    }
+}
+
+// Helper function to send a queued event to its subscriber, rescheduling it on failure
+async fn deliver(id: u64, mut delivery: Delivery) {
+    let result: ic_cdk::api::call::CallResult<()> = ic_cdk::call(
+        delivery.callback,
+        "on_todo_event",
+        (delivery.event.clone(),),
+    )
+    .await;
+    IN_FLIGHT_DELIVERIES.with(|in_flight| in_flight.borrow_mut().remove(&id));
+
+    delivery.attempts += 1;
+    if result.is_ok() || delivery.attempts >= MAX_DELIVERY_ATTEMPTS {
+        DELIVERY_QUEUE.with(|queue| queue.borrow_mut().remove(&id));
+        return;
+    }
+    // The subscriber may have unsubscribed while the call was in flight
+    if DELIVERY_QUEUE.with(|queue| queue.borrow().contains_key(&id)) {
+        delivery.next_attempt_at = time() + (DELIVERY_RETRY_DELAY << (delivery.attempts - 1));
+        arm_timer(delivery.next_attempt_at);
+        DELIVERY_QUEUE.with(|queue| queue.borrow_mut().insert(id, delivery));
+    }
+}
+
+// Helper function to get the title index key of a todo, titles are compared folded like in
+// search, see fold_text, and with collapsed whitespace
+fn title_key(owner: &str, title: &str) -> TitleKey {
+    let normalized = fold_text(title)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut hasher = Sha256::new();
+    hasher.update(owner.as_bytes());
+    hasher.update([0]);
+    hasher.update(normalized.as_bytes());
+    TitleKey::try_from(hasher.finalize().as_slice()).expect("a SHA-256 hash is 32 bytes")
+}
+
+// Helper function to replace the title index entry of a todo; completed todos are not indexed
+fn index_title(previous: Option<&Todo>, current: Option<&Todo>) {
+    if let Some(todo) = previous.filter(|todo| todo.status != TaskStatus::Completed) {
+        let key = (title_key(&todo.owner, &todo.title), todo.id);
+        TITLE_INDEX.with(|index| index.borrow_mut().remove(&key));
+    }
+    if let Some(todo) = current.filter(|todo| todo.status != TaskStatus::Completed) {
+        let key = (title_key(&todo.owner, &todo.title), todo.id);
+        TITLE_INDEX.with(|index| index.borrow_mut().insert(key, ()));
+    }
+}
+
+// Helper function to get the ids of an owner's active todos with a title, in id order. While
+// the indexes are rebuilt the title index may be incomplete, so only the exact title matches.
+fn _get_active_title_ids(owner: &str, title: &str) -> Vec<u64> {
+    if indexes_stale() {
+        return _get_owner_todos(owner)
+            .into_iter()
+            .filter(|todo| todo.status != TaskStatus::Completed && todo.title == title)
+            .map(|todo| todo.id)
+            .collect();
+    }
+
+    let key = title_key(owner, title);
+    TITLE_INDEX.with(|index| {
+        index
+            .borrow()
+            .range((key, 0)..)
+            .take_while(|((other, _), _)| *other == key)
+            .map(|((_, id), _)| id)
+            .collect()
+    })
+}
+
+// Helper function to reject an active todo whose title another active todo of the same owner
+// already uses, when unique titles are enabled. `ignored` lists todos that are completed in the
+// same call and so don't count.
+fn check_unique_title(todo: &Todo, ignored: &[u64]) -> Result<(), Error> {
+    if !_get_config().unique_titles || todo.status == TaskStatus::Completed {
+        return Ok(());
+    }
+
+    let duplicate = _get_active_title_ids(&todo.owner, &todo.title)
+        .into_iter()
+        .find(|id| *id != todo.id && !ignored.contains(id));
+    match duplicate {
+        Some(id) => Err(Error::Conflict {
+            msg: format!("Todo with id={} already has the title '{}'", id, todo.title),
+        }),
+        None => Ok(()),
+    }
+}
+
+// Helper function to tell whether storing a todo can add a duplicate active title. Titles that
+// were already shared when unique_titles was turned on don't block other edits of those todos
+fn can_duplicate_title(stored: Option<&Todo>, todo: &Todo) -> bool {
+    match stored {
+        Some(stored) => {
+            stored.title != todo.title
+                || stored.owner != todo.owner
+                || stored.status == TaskStatus::Completed
+        }
+        None => true,
+    }
+}
+
+// Helper function to validate a webhook url, only https is allowed
+fn validate_webhook_url(url: &str) -> Result<(), Error> {
+    if url.len() > MAX_WEBHOOK_URL_LENGTH {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Webhook url cannot be longer than {} bytes",
+                MAX_WEBHOOK_URL_LENGTH
+            ),
+        });
+    }
+    let host = url
+        .strip_prefix("https://")
+        .and_then(|rest| rest.split(['/', '?', '#']).next());
+    if !matches!(host, Some(host) if !host.is_empty()) || url.chars().any(char::is_whitespace) {
+        return Err(Error::InvalidInput {
+            msg: "Webhook url must be a valid https url".to_string(),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to compute an HMAC-SHA256 (RFC 2104)
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+    if key.len() > block.len() {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|byte| byte ^ 0x36));
+    inner.update(message);
+    let mut outer = Sha256::new();
+    outer.update(block.map(|byte| byte ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+// Helper function to get an owner's webhook deliveries with their keys, oldest first
+fn _get_webhook_log(owner: &Principal) -> Vec<((PrincipalKey, u64), WebhookDelivery)> {
+    let key = principal_key(owner);
+    WEBHOOK_LOG.with(|log| {
+        log.borrow()
+            .range((key, 0)..)
+            .take_while(|((other, _), _)| *other == key)
+            .collect()
+    })
+}
+
+// Helper function to POST a completed todo to its owner's webhook and log the outcome
+async fn send_completion_webhook(todo: Todo) {
+    let owner = owner_principal(&todo);
+    let Some(webhook) = WEBHOOKS.with(|webhooks| webhooks.borrow().get(&principal_key(&owner)))
+    else {
+        return;
+    };
+    if let Err(error) = throttle_webhook(&owner, todo.id, time()) {
+        log_webhook_delivery(&owner, todo.id, false, None, Some(error));
+        return;
+    }
+
+    let body = serde_json::json!({
+        "event": "todo.completed",
+        "todo": TodoListSummary::from(todo.clone()),
+        "completed_at": todo.completed_at,
+    })
+    .to_string()
+    .into_bytes();
+    let signature = encode_hex(&hmac_sha256(webhook.secret.as_bytes(), &body));
+    let request = CanisterHttpRequestArgument {
+        url: webhook.url,
+        max_response_bytes: Some(WEBHOOK_MAX_RESPONSE_BYTES),
+        method: HttpMethod::POST,
+        headers: vec![
+            HttpHeader {
+                name: "Content-Type".to_string(),
+                value: "application/json".to_string(),
+            },
+            HttpHeader {
+                name: "X-Signature-256".to_string(),
+                value: format!("sha256={}", signature),
+            },
+        ],
+        body: Some(body),
+        transform: Some(TransformContext::from_name(
+            "transform_webhook_response".to_string(),
+            Vec::new(),
+        )),
+    };
+
+    let (succeeded, status_code, error) = match http_request(request, WEBHOOK_CYCLES).await {
+        Ok((response,)) => {
+            let status_code = u32::try_from(response.status.0).ok();
+            let succeeded = matches!(status_code, Some(200..=299));
+            (succeeded, status_code, None)
+        }
+        Err((code, msg)) => {
+            let error: String = format!("{:?}: {}", code, msg)
+                .chars()
+                .take(MAX_WEBHOOK_ERROR_LENGTH)
+                .collect();
+            (false, None, Some(error))
+        }
+    };
+
+    log_webhook_delivery(&owner, todo.id, succeeded, status_code, error);
+}
+
+// Helper function to count a completion webhook post against the todo's debounce period and
+// the owner's window, rejecting it with the reason once either is used up
+fn throttle_webhook(owner: &Principal, todo_id: u64, now: u64) -> Result<(), String> {
+    // Forget posts and windows that have expired, so both maps only hold recent activity
+    WEBHOOK_SENT_AT.with(|sent_at| {
+        let mut sent_at = sent_at.borrow_mut();
+        let expired: Vec<u64> = sent_at
+            .iter()
+            .filter(|(_, at)| now.saturating_sub(*at) >= WEBHOOK_TODO_DEBOUNCE)
+            .map(|(id, _)| id)
+            .collect();
+        for id in expired {
+            sent_at.remove(&id);
+        }
+    });
+    WEBHOOK_WINDOWS.with(|windows| {
+        let mut windows = windows.borrow_mut();
+        let expired: Vec<PrincipalKey> = windows
+            .iter()
+            .filter(|(_, (start, _))| now.saturating_sub(*start) >= WEBHOOK_OWNER_WINDOW)
+            .map(|(key, _)| key)
+            .collect();
+        for key in expired {
+            windows.remove(&key);
+        }
+    });
+
+    if WEBHOOK_SENT_AT.with(|sent_at| sent_at.borrow().contains_key(&todo_id)) {
+        return Err("Skipped, the todo was posted recently".to_string());
+    }
+
+    let key = principal_key(owner);
+    let (start, posts) = WEBHOOK_WINDOWS
+        .with(|windows| windows.borrow().get(&key))
+        .unwrap_or((now, 0));
+    if posts >= MAX_WEBHOOKS_PER_WINDOW {
+        return Err("Skipped, too many webhook posts for this owner".to_string());
+    }
+
+    WEBHOOK_WINDOWS.with(|windows| windows.borrow_mut().insert(key, (start, posts + 1)));
+    WEBHOOK_SENT_AT.with(|sent_at| sent_at.borrow_mut().insert(todo_id, now));
+    Ok(())
+}
+
+// Helper function to log the outcome of a webhook post, keeping only the most recent entries
+fn log_webhook_delivery(
+    owner: &Principal,
+    todo_id: u64,
+    succeeded: bool,
+    status_code: Option<u32>,
+    error: Option<String>,
+) {
+    let key = principal_key(owner);
+    let log = _get_webhook_log(owner);
+    let excess = (log.len() + 1).saturating_sub(MAX_WEBHOOK_LOG_ENTRIES);
+    for (old_key, _) in log.into_iter().take(excess) {
+        WEBHOOK_LOG.with(|log| log.borrow_mut().remove(&old_key));
+    }
+
+    let delivery = WebhookDelivery {
+        id: next_id(&WEBHOOK_DELIVERY_ID_COUNTER),
+        todo_id,
+        attempted_at: time(),
+        succeeded,
+        status_code,
+        error,
+    };
+    WEBHOOK_LOG.with(|log| log.borrow_mut().insert((key, delivery.id), delivery));
+}
+
+// Helper function to validate an effort estimate in minutes
+fn validate_estimate(estimated_minutes: Option<u32>) -> Result<(), Error> {
+    match estimated_minutes {
+        Some(minutes) if minutes == 0 || minutes > MAX_ESTIMATED_MINUTES => {
+            Err(Error::InvalidInput {
+                msg: format!(
+                    "Estimated minutes must be between 1 and {}",
+                    MAX_ESTIMATED_MINUTES
+                ),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+// Helper function to tell the completion hook canister about a completed todo. The hook is best
+// effort, failures are ignored.
+async fn call_completion_hook(owner: String, todo_id: u64) {
+    let Some(canister) = _get_config().completion_hook else {
+        return;
+    };
+    let _: ic_cdk::api::call::CallResult<()> =
+        ic_cdk::call(canister, "on_todo_completed", (owner, todo_id)).await;
+}
+
+// Helper function to get the settings of a principal, defaults when none are stored
+fn _get_settings(principal: &Principal) -> Settings {
+    SETTINGS
+        .with(|settings| settings.borrow().get(&principal_key(principal)))
+        .unwrap_or_default()
+}
+
+// Helper function to validate settings
+fn validate_settings(settings: &Settings) -> Result<(), Error> {
+    let utc_offset_seconds = settings.timezone_offset_minutes as i64 * 60;
+    if !(MIN_UTC_OFFSET_SECONDS..=MAX_UTC_OFFSET_SECONDS).contains(&utc_offset_seconds) {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Timezone offset must be between {} and {} minutes",
+                MIN_UTC_OFFSET_SECONDS / 60,
+                MAX_UTC_OFFSET_SECONDS / 60
+            ),
+        });
+    }
+    if matches!(settings.default_due_offset_days, Some(days) if days == 0 || days > MAX_DEFAULT_DUE_OFFSET_DAYS)
+    {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Default due date offset must be between 1 and {} days",
+                MAX_DEFAULT_DUE_OFFSET_DAYS
+            ),
+        });
+    }
+    if let Some(name) = &settings.display_name {
+        if name.is_empty() || name.chars().count() > MAX_DISPLAY_NAME_LENGTH {
+            return Err(Error::InvalidInput {
+                msg: format!(
+                    "Display name must be between 1 and {} characters",
+                    MAX_DISPLAY_NAME_LENGTH
+                ),
+            });
+        }
+    }
+    if !(MIN_TRASH_RETENTION_DAYS..=MAX_TRASH_RETENTION_DAYS)
+        .contains(&settings.trash_retention_days)
+    {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Trash retention must be between {} and {} days",
+                MIN_TRASH_RETENTION_DAYS, MAX_TRASH_RETENTION_DAYS
+            ),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to get the UTC offset to use for day bucketing, the caller's timezone setting
+// unless one is given
+fn utc_offset_or_default(utc_offset_seconds: Option<i64>) -> i64 {
+    utc_offset_seconds
+        .unwrap_or_else(|| _get_settings(&ic_cdk::caller()).timezone_offset_minutes as i64 * 60)
+}
+
+// Helper function to extend the owner's streak with the local day a todo was completed on
+fn record_completion_day(todo: &Todo) {
+    let owner = owner_principal(todo);
+    let key = principal_key(&owner);
+    let utc_offset_seconds = _get_settings(&owner).timezone_offset_minutes as i64 * 60;
+    let day = local_day_start(todo.completed_at.unwrap_or_else(time), utc_offset_seconds);
+
+    let mut streak = STREAKS
+        .with(|streaks| streaks.borrow().get(&key))
+        .unwrap_or_default();
+    match streak.last_completion_day {
+        // Already counted, or completed on a day before the last one
+        Some(last) if day <= last => return,
+        Some(last) if day == last + NANOS_PER_DAY => streak.current_days += 1,
+        _ => streak.current_days = 1,
+    }
+    streak.longest_days = streak.longest_days.max(streak.current_days);
+    streak.last_completion_day = Some(day);
+
+    STREAKS.with(|streaks| streaks.borrow_mut().insert(key, streak));
+}
+
+// Helper function to order due dates earliest first, todos without a due date last
+fn due_date_order(a: Option<u64>, b: Option<u64>) -> std::cmp::Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
+}
+
+// Helper function to award the owner points for completing a todo, once per todo. Like streaks,
+// points go to the owner even when an editor or assignee completed it.
+fn award_completion(todo: &Todo) {
+    if AWARDED_TODOS
+        .with(|awarded| awarded.borrow_mut().insert(todo.id, ()))
+        .is_some()
+    {
+        return;
+    }
+
+    let key = principal_key(&owner_principal(todo));
+    let mut score = SCORES
+        .with(|scores| scores.borrow().get(&key))
+        .unwrap_or_default();
+    score.points += completion_points(&todo.priority);
+    score.completed += 1;
+
+    let streak = STREAKS
+        .with(|streaks| streaks.borrow().get(&key))
+        .unwrap_or_default();
+    let earned = [
+        (Achievement::FirstCompletion, score.completed >= 1),
+        (Achievement::TenCompleted, score.completed >= 10),
+        (Achievement::HundredCompleted, score.completed >= 100),
+        (Achievement::SevenDayStreak, streak.longest_days >= 7),
+    ];
+    for (achievement, reached) in earned {
+        if reached && !score.achievements.contains(&achievement) {
+            score.achievements.push(achievement);
+        }
+    }
+
+    SCORES.with(|scores| scores.borrow_mut().insert(key, score));
+}
+
+// Helper function to check whether a todo can be worked on now: open and not waiting
+fn is_actionable(todo: &Todo) -> bool {
+    matches!(todo.status, TaskStatus::Pending | TaskStatus::InProgress)
+}
+
+// Helper function to get the points a completed todo of the given priority is worth
+fn completion_points(priority: &Priority) -> u64 {
+    match priority {
+        Priority::Low => 1,
+        Priority::Medium => 2,
+        Priority::High => 3,
+        Priority::Urgent => 5,
+    }
+}
+
+// Helper function to count the completed todos finished since a timestamp and the points they
+// are worth. Computed from the todos on every call; a cached per-period tally can replace it.
+fn tally_completions(completed: &[Todo], since: u64) -> (u64, u64) {
+    completed
+        .iter()
+        .filter(|todo| matches!(todo.completed_at, Some(completed) if completed >= since))
+        .fold((0, 0), |(completed, points), todo| {
+            (completed + 1, points + completion_points(&todo.priority))
+        })
+}
+
+// Helper function to get the UTC start of the period containing a timestamp
+fn period_start(period: &Period, now: u64) -> u64 {
+    let day = (now / NANOS_PER_DAY) as i64;
+    let start_day = match period {
+        Period::AllTime => return 0,
+        // The UNIX epoch was a Thursday
+        Period::ThisWeek => day - (day + 3).rem_euclid(7),
+        Period::ThisMonth => {
+            let (year, month, _) = civil_from_days(day);
+            days_from_civil(year, month, 1)
+        }
+    };
+    start_day as u64 * NANOS_PER_DAY
+}
+
+// Helper function to get the calendar date of a number of days since the UNIX epoch
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    // Algorithm from Howard Hinnant's "chrono-Compatible Low-Level Date Algorithms"
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+// Helper function to get an owner's todos in a status, in id order
+fn _get_owner_todos_with_status(owner: &Principal, status: TaskStatus) -> Vec<Todo> {
+    let prefix = (principal_key(owner), status);
+    let ids: Vec<u64> = STATUS_INDEX.with(|index| {
+        index
+            .borrow()
+            .range((prefix.clone(), 0)..)
+            .take_while(|((other, _), _)| *other == prefix)
+            .map(|((_, id), _)| id)
+            .collect()
+    });
+    STORAGE.with(|service| {
+        let service = service.borrow();
+        ids.into_iter().filter_map(|id| service.get(&id)).collect()
+    })
+}
+
+// Helper function to move a todo's status index entry when it is stored, changed or removed
+fn index_status(previous: Option<&Todo>, current: Option<&Todo>) {
+    if let Some(todo) = previous {
+        let key = (
+            (principal_key(&owner_principal(todo)), todo.status.clone()),
+            todo.id,
+        );
+        STATUS_INDEX.with(|index| index.borrow_mut().remove(&key));
+    }
+    if let Some(todo) = current {
+        let key = (
+            (principal_key(&owner_principal(todo)), todo.status.clone()),
+            todo.id,
+        );
+        STATUS_INDEX.with(|index| index.borrow_mut().insert(key, ()));
+    }
+}
+
+// Helper function to get an owner's todos due in [from, to), earliest due date first
+fn _get_owner_todos_due_between(
+    owner: &Principal,
+    from: u64,
+    to: u64,
+) -> impl Iterator<Item = Todo> {
+    let owner = principal_key(owner);
+    let ids: Vec<u64> = DUE_DATE_INDEX.with(|index| {
+        index
+            .borrow()
+            .range(((owner, from), 0)..)
+            .take_while(|(((other, due), _), _)| *other == owner && *due < to)
+            .map(|((_, id), _)| id)
+            .collect()
+    });
+    ids.into_iter().filter_map(|id| _get_todo(&id))
+}
+
+// Helper function to move a todo's due date index entry when it is stored, changed or removed
+fn index_due_date(previous: Option<&Todo>, current: Option<&Todo>) {
+    if let Some((todo, due)) = previous.and_then(|todo| todo.due_date.map(|due| (todo, due))) {
+        let key = ((principal_key(&owner_principal(todo)), due), todo.id);
+        DUE_DATE_INDEX.with(|index| index.borrow_mut().remove(&key));
+    }
+    if let Some((todo, due)) = current.and_then(|todo| todo.due_date.map(|due| (todo, due))) {
+        let key = ((principal_key(&owner_principal(todo)), due), todo.id);
+        DUE_DATE_INDEX.with(|index| index.borrow_mut().insert(key, ()));
+    }
+}
+
+// Helper function to reject an empty description when the canister requires descriptions
+fn check_description_required(description: &str) -> Result<(), Error> {
+    if _get_config().require_description && description.trim().is_empty() {
+        return Err(Error::InvalidInput {
+            msg: "Description cannot be empty".to_string(),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to reject a missing due date when the canister requires due dates
+fn check_due_date_required(due_date: Option<u64>) -> Result<(), Error> {
+    if _get_config().require_due_date && due_date.is_none() {
+        return Err(Error::InvalidInput {
+            msg: "Due date is required".to_string(),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to reject new todos that would take the owner past the per-user limit
+fn check_todo_limit(owner: &Principal, new_todos: u64) -> Result<(), Error> {
+    let limit = _get_config().max_todos_per_user;
+    if limit > 0 && owner_todo_count(owner) + new_todos > limit {
+        return Err(Error::InvalidInput {
+            msg: format!("Cannot have more than {} todos", limit),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to get the caller's todos in the given order, pinned todos first
+fn _list_caller_todos(sort_by: Option<SortBy>) -> Vec<Todo> {
+    let mut todos = _get_owner_todos(&ic_cdk::caller().to_string());
+    sort_todos(&mut todos, &sort_by.unwrap_or(SortBy::Manual));
+    pinned_first(&mut todos);
+    todos
+}
+
+// Helper function to empty, in place, the fields of a todo that a projection leaves out
+fn project(todo: &mut Todo, projection: &Projection) {
+    if !projection.include_description {
+        todo.description = String::new();
+    }
+    if !projection.include_labels {
+        todo.label_ids = Vec::new();
+    }
+}
+
+// Helper function to apply a projection to a list of todos, the default keeps every field
+fn project_all(mut todos: Vec<Todo>, projection: Option<Projection>) -> Vec<Todo> {
+    if let Some(projection) = projection {
+        for todo in &mut todos {
+            project(todo, &projection);
+        }
+    }
+    todos
+}
+
+// Helper function to turn a list of todos into list rows
+fn summarize_all(todos: Vec<Todo>) -> Vec<TodoListSummary> {
+    todos.into_iter().map(TodoListSummary::from).collect()
+}
+
+// Helper function to apply a projection to the todos of a scan page
+fn project_scan(page: ScanPage, projection: Option<Projection>) -> ScanPage {
+    ScanPage {
+        todos: project_all(page.todos, projection),
+        ..page
+    }
+}
+
+// State of a single call of an integrity check, see check_integrity_within
+struct IntegrityScan {
+    repair: bool,
+    // Todos and index entries the call may still examine
+    remaining: usize,
+    anomalies: Vec<String>,
+}
+
+// Helper function to check the todo storage, the id counter and every secondary index,
+// examining at most MAX_SCAN_ENTRIES todos and index entries per call
+fn verify_integrity(cursor: Option<IntegrityCursor>) -> Result<IntegrityReport, Error> {
+    check_integrity_within(cursor, MAX_SCAN_ENTRIES, false)
+}
+
+// Helper function to check the integrity like verify_integrity and also repair what it finds:
+// missing index entries are added, stale ones removed and the id counter moved past the
+// highest id. The indexes are repaired in place up to the cursor, so a repair that stops
+// halfway is resumed rather than left with partly rebuilt indexes.
+fn repair_integrity(cursor: Option<IntegrityCursor>) -> Result<IntegrityReport, Error> {
+    check_integrity_within(cursor, MAX_SCAN_ENTRIES, true)
+}
+
+// Helper function to check, and with repair also fix, the integrity with an explicit budget of
+// examined todos and index entries
+fn check_integrity_within(
+    cursor: Option<IntegrityCursor>,
+    budget: usize,
+    repair: bool,
+) -> Result<IntegrityReport, Error> {
+    let mut scan = IntegrityScan {
+        repair,
+        remaining: budget,
+        anomalies: Vec::new(),
+    };
+    let todo_count = STORAGE.with(|service| service.borrow().len());
+    let max_todo_id = STORAGE.with(|service| service.borrow().last_key_value().map(|(id, _)| id));
+    let id_counter = ID_COUNTER.with(|counter| *counter.borrow().get());
+    // Reported once, by the first call of a check
+    if let Some(max_id) = max_todo_id.filter(|max_id| cursor.is_none() && *max_id >= id_counter) {
+        scan.anomalies.push(format!(
+            "Id counter {} is not past the highest todo id={}",
+            id_counter, max_id
+        ));
+        // Move the counter past the highest id so new todos don't overwrite stored ones
+        if repair {
+            ID_COUNTER
+                .with(|counter| counter.borrow_mut().set(max_id + 1))
+                .expect("cannot set id counter");
+        }
+    }
+
+    // Stage 0 checks the todos, stage n the entries of INTEGRITY_INDEXES[n - 1]
+    let (mut stage, mut todo_after, mut entry_after) = match cursor.clone() {
+        None => (0, None, None),
+        Some(IntegrityCursor::Todos(id)) => (0, Some(id), None),
+        Some(IntegrityCursor::Index { name, key, id }) => {
+            match INTEGRITY_INDEXES.iter().position(|index| *index == name) {
+                Some(position) => (position + 1, None, Some((key, id))),
+                None => {
+                    return Err(Error::InvalidInput {
+                        msg: format!("Unknown index '{}'", name),
+                    })
+                }
+            }
+        }
+    };
+
+    let mut report = IntegrityReport {
+        todo_count,
+        id_counter,
+        max_todo_id,
+        anomalies: Vec::new(),
+        cursor,
+        complete: true,
+    };
+    while stage <= INTEGRITY_INDEXES.len() {
+        let (last, done) = match stage {
+            0 => check_todos(todo_after.take(), &mut scan),
+            _ => check_index_entries(stage - 1, entry_after.take(), &mut scan),
+        };
+        if last.is_some() {
+            report.cursor = last;
+        }
+        if !done {
+            report.complete = false;
+            break;
+        }
+        stage += 1;
+    }
+    report.anomalies = scan.anomalies;
+    Ok(report)
+}
+
+// Helper function to check the todos after the cursor against every index, returning the last
+// todo examined and whether no todo is left
+fn check_todos(after: Option<u64>, scan: &mut IntegrityScan) -> (Option<IntegrityCursor>, bool) {
+    let start = after.map_or(Bound::Unbounded, Bound::Excluded);
+    STORAGE.with(|service| {
+        let mut last = None;
+        for (id, todo) in service.borrow().range((start, Bound::Unbounded)) {
+            if scan.remaining == 0 {
+                return (last, false);
+            }
+            scan.remaining -= 1;
+            last = Some(IntegrityCursor::Todos(id));
+
+            if todo.id != id {
+                scan.anomalies
+                    .push(format!("Todo stored under id={} has id={}", id, todo.id));
+            }
+            check_indexed("title", &TITLE_INDEX, &todo, title_index_keys, scan);
+            check_indexed("owner", &OWNER_INDEX, &todo, owner_index_keys, scan);
+            check_indexed("status", &STATUS_INDEX, &todo, status_index_keys, scan);
+            check_indexed(
+                "due date",
+                &DUE_DATE_INDEX,
+                &todo,
+                due_date_index_keys,
+                scan,
+            );
+            check_indexed(
+                "title token",
+                &TITLE_TOKEN_INDEX,
+                &todo,
+                title_token_index_keys,
+                scan,
+            );
+            check_indexed(
+                "assignee",
+                &ASSIGNEE_INDEX,
+                &todo,
+                assignee_index_keys,
+                scan,
+            );
+            check_indexed(
+                "collaborator",
+                &COLLABORATOR_INDEX,
+                &todo,
+                collaborator_index_keys,
+                scan,
+            );
+        }
+        (last, true)
+    })
+}
+
+// Helper function to report, and with repair add, the entries missing for a todo in an index;
+// `expected` gives the keys a todo should be indexed under
+fn check_indexed<K, E>(
+    name: &str,
+    index: &'static LocalKey<RefCell<TodoIndex<K>>>,
+    todo: &Todo,
+    expected: impl Fn(&Todo) -> E,
+    scan: &mut IntegrityScan,
+) where
+    K: BoundedStorable + Default + Ord + Clone,
+    E: IntoIterator<Item = K>,
+{
+    let missing: Vec<(K, u64)> = index.with(|index| {
+        let index = index.borrow();
+        expected(todo)
+            .into_iter()
+            .map(|key| (key, todo.id))
+            .filter(|entry| !index.contains_key(entry))
+            .collect()
+    });
+    if missing.is_empty() {
+        return;
+    }
+
+    scan.anomalies.push(format!(
+        "Todo with id={} is missing from the {} index",
+        todo.id, name
+    ));
+    if scan.repair {
+        index.with(|index| {
+            let mut index = index.borrow_mut();
+            for entry in missing {
+                index.insert(entry, ());
+            }
+        });
+    }
+}
+
+// Helper function to check the entries of INTEGRITY_INDEXES[position] after the cursor against
+// the todos, returning the last entry examined and whether no entry is left
+fn check_index_entries(
+    position: usize,
+    after: Option<(Vec<u8>, u64)>,
+    scan: &mut IntegrityScan,
+) -> (Option<IntegrityCursor>, bool) {
+    let name = INTEGRITY_INDEXES[position];
+    match position {
+        0 => check_entries(name, &TITLE_INDEX, after, title_index_keys, scan),
+        1 => check_entries(name, &OWNER_INDEX, after, owner_index_keys, scan),
+        2 => check_entries(name, &STATUS_INDEX, after, status_index_keys, scan),
+        3 => check_entries(name, &DUE_DATE_INDEX, after, due_date_index_keys, scan),
+        4 => check_entries(
+            name,
+            &TITLE_TOKEN_INDEX,
+            after,
+            title_token_index_keys,
+            scan,
+        ),
+        5 => check_entries(name, &ASSIGNEE_INDEX, after, assignee_index_keys, scan),
+        _ => check_entries(
+            name,
+            &COLLABORATOR_INDEX,
+            after,
+            collaborator_index_keys,
+            scan,
+        ),
+    }
+}
+
+// Helper function to report, and with repair remove, index entries without a matching todo,
+// see check_index_entries
+fn check_entries<K, E>(
+    name: &str,
+    index: &'static LocalKey<RefCell<TodoIndex<K>>>,
+    after: Option<(Vec<u8>, u64)>,
+    expected: impl Fn(&Todo) -> E,
+    scan: &mut IntegrityScan,
+) -> (Option<IntegrityCursor>, bool)
+where
+    K: BoundedStorable + Default + Ord + Clone,
+    E: IntoIterator<Item = K>,
+{
+    let start = after.map_or(Bound::Unbounded, |(key, id)| {
+        Bound::Excluded((K::from_bytes(Cow::Owned(key)), id))
+    });
+    let mut last = None;
+    let mut done = true;
+    let mut stale = Vec::new();
+    index.with(|index| {
+        for ((key, id), _) in index.borrow().range((start, Bound::Unbounded)) {
+            if scan.remaining == 0 {
+                done = false;
+                break;
+            }
+            scan.remaining -= 1;
+            last = Some(IntegrityCursor::Index {
+                name: name.to_string(),
+                key: key.to_bytes().into_owned(),
+                id,
+            });
+
+            match _get_todo(&id) {
+                None => scan.anomalies.push(format!(
+                    "The {} index has an entry for missing todo with id={}",
+                    name, id
+                )),
+                Some(todo) if !expected(&todo).into_iter().any(|other| other == key) => {
+                    scan.anomalies.push(format!(
+                        "The {} index has a stale entry for todo with id={}",
+                        name, id
+                    ))
+                }
+                Some(_) => continue,
+            }
+            stale.push((key, id));
+        }
+    });
+
+    // Removed after the iteration, a cursor past a removed entry still resumes correctly
+    if scan.repair {
+        index.with(|index| {
+            let mut index = index.borrow_mut();
+            for entry in &stale {
+                index.remove(entry);
+            }
+        });
+    }
+    (last, done)
+}
+
+// Helper functions to get the keys a todo is indexed under in each secondary index
+fn title_index_keys(todo: &Todo) -> Option<TitleKey> {
+    (todo.status != TaskStatus::Completed).then(|| title_key(&todo.owner, &todo.title))
+}
+
+fn owner_index_keys(todo: &Todo) -> Option<PrincipalKey> {
+    Some(principal_key(&owner_principal(todo)))
+}
+
+fn status_index_keys(todo: &Todo) -> Option<StatusKey> {
+    Some((principal_key(&owner_principal(todo)), todo.status.clone()))
+}
+
+fn due_date_index_keys(todo: &Todo) -> Option<DueKey> {
+    todo.due_date
+        .map(|due| (principal_key(&owner_principal(todo)), due))
+}
+
+fn title_token_index_keys(todo: &Todo) -> Vec<OwnerTokenKey> {
+    let owner = principal_key(&owner_principal(todo));
+    title_tokens(&todo.title)
+        .iter()
+        .map(|token| (owner, token_key(token)))
+        .collect()
+}
+
+fn assignee_index_keys(todo: &Todo) -> Option<PrincipalKey> {
+    todo.assignee.as_ref().map(principal_key)
+}
+
+fn collaborator_index_keys(todo: &Todo) -> Vec<PrincipalKey> {
+    todo.collaborators
+        .iter()
+        .map(|(principal, _)| principal_key(principal))
+        .collect()
+}
+
+// Helper function to collect the matching todos with ids after the cursor, examining at most
+// MAX_SCAN_ENTRIES todos so a scan over all users stays within the instruction limit
+fn scan_todos(cursor: Option<u64>, keep: impl Fn(&Todo) -> bool) -> ScanPage {
+    scan_todos_within(cursor, MAX_SCAN_ENTRIES, keep)
+}
+
+// Helper function to scan like scan_todos with an explicit budget of examined todos
+fn scan_todos_within(cursor: Option<u64>, budget: usize, keep: impl Fn(&Todo) -> bool) -> ScanPage {
+    let start = cursor.map_or(Bound::Unbounded, Bound::Excluded);
+    STORAGE.with(|service| {
+        let mut page = ScanPage {
+            todos: Vec::new(),
+            cursor,
+            complete: true,
+        };
+        for (examined, (id, todo)) in service
+            .borrow()
+            .range((start, Bound::Unbounded))
+            .enumerate()
+        {
+            if examined == budget {
+                page.complete = false;
+                break;
+            }
+            // The cursor follows every examined todo, so calls progress even when few match
+            page.cursor = Some(id);
+            if keep(&todo) {
+                page.todos.push(todo);
+            }
+        }
+        page
+    })
+}
+
+// Helper function to validate a canister config
+fn validate_config(config: &Config) -> Result<(), Error> {
+    if config.max_todo_size == 0 || config.max_todo_size > Todo::MAX_SIZE {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Maximum todo size must be between 1 and {} bytes",
+                Todo::MAX_SIZE
+            ),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to count the todos owned by a principal
+fn owner_todo_count(owner: &Principal) -> u64 {
+    let key = principal_key(owner);
+    OWNER_INDEX.with(|index| {
+        index
+            .borrow()
+            .range((key, 0)..)
+            .take_while(|((other, _), _)| *other == key)
+            .count() as u64
+    })
+}
+
+// Helper function to split a title into its distinct folded words, see fold_text
+fn title_tokens(title: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = fold_text(title)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect();
+    tokens.sort();
+    tokens.dedup();
+    tokens
+}
+
+// Helper function to cut text to at most max_bytes bytes on a char boundary
+fn truncate_to_bytes(text: &str, max_bytes: usize) -> String {
+    let mut end = text.len().min(max_bytes);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text[..end].to_string()
+}
+
+// Helper function to get the index key of a title word, long words are cut on a char boundary
+fn token_key(token: &str) -> TokenKey {
+    let mut end = token.len().min(TokenKey::MAX_SIZE as usize);
+    while !token.is_char_boundary(end) {
+        end -= 1;
+    }
+    TokenKey::try_from(&token.as_bytes()[..end]).expect("token keys are cut to fit")
+}
+
+// Helper function to record that the title and title token indexes match the current fold_text
+fn set_index_version() {
+    INDEX_VERSION
+        .with(|version| version.borrow_mut().set(INDEX_FORMAT_VERSION))
+        .expect("cannot set the index version");
+}
+
+// Helper function to check whether the secondary indexes are being rebuilt. Until the rebuild
+// is complete they may lack entries or hold entries built with an older fold_text.
+fn indexes_stale() -> bool {
+    INDEX_VERSION.with(|version| *version.borrow().get()) != INDEX_FORMAT_VERSION
+}
+
+// Helper function to replace the title token index entries of a todo
+fn index_title_tokens(previous: Option<&Todo>, current: Option<&Todo>) {
+    TITLE_TOKEN_INDEX.with(|index| {
+        let mut index = index.borrow_mut();
+        if let Some(todo) = previous {
+            let owner = principal_key(&owner_principal(todo));
+            for token in title_tokens(&todo.title) {
+                index.remove(&((owner, token_key(&token)), todo.id));
+            }
+        }
+        if let Some(todo) = current {
+            let owner = principal_key(&owner_principal(todo));
+            for token in title_tokens(&todo.title) {
+                index.insert(((owner, token_key(&token)), todo.id), ());
+            }
+        }
+    });
+}
+
+// Helper function to fold text for matching titles, search and label names: Unicode full case
+// folding followed by NFKD with the combining marks removed, so "Café", "CAFE" and "cafe"
+// compare equal and ß, ﬁ and ² match ss, fi and 2. Latin letters with a stroke and the æ and œ
+// ligatures, which have no decomposition, are spelled out as well. The default folding is used
+// rather than the Turkic one: İ folds to i, while the dotless ı stays a separate letter. The
+// data lives in fold_table.rs, generated by gen_fold_table.py; changing either requires bumping
+// INDEX_FORMAT_VERSION.
+fn fold_text(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars() {
+        fold_char(c, &mut folded);
+    }
+    folded
+}
+
+// Helper function to append the folding of a single character, see fold_text
+fn fold_char(c: char, folded: &mut String) {
+    if let Some(jamo) = decompose_hangul(c) {
+        folded.extend(jamo.into_iter().flatten());
+        return;
+    }
+    let is_mark = fold_table::MARKS
+        .binary_search_by(|(start, end)| {
+            if *end < c {
+                std::cmp::Ordering::Less
+            } else if *start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok();
+    if is_mark {
+        return;
+    }
+    match fold_table::FOLDS.binary_search_by_key(&c, |(from, _)| *from) {
+        Ok(index) => folded.push_str(fold_table::FOLDS[index].1),
+        Err(_) => folded.push(c),
+    }
+}
+
+// Helper function to decompose a precomposed Hangul syllable into its leading consonant, vowel
+// and optional trailing consonant, which Unicode defines arithmetically rather than by table
+fn decompose_hangul(c: char) -> Option<[Option<char>; 3]> {
+    const SYLLABLE_BASE: u32 = 0xac00;
+    const LEADING_BASE: u32 = 0x1100;
+    const VOWEL_BASE: u32 = 0x1161;
+    const TRAILING_BASE: u32 = 0x11a7;
+    const VOWELS: u32 = 21;
+    const TRAILINGS: u32 = 28;
+    const SYLLABLES: u32 = 19 * VOWELS * TRAILINGS;
+
+    let index = (c as u32).checked_sub(SYLLABLE_BASE)?;
+    if index >= SYLLABLES {
+        return None;
+    }
+    let leading = char::from_u32(LEADING_BASE + index / (VOWELS * TRAILINGS));
+    let vowel = char::from_u32(VOWEL_BASE + index % (VOWELS * TRAILINGS) / TRAILINGS);
+    let trailing = match index % TRAILINGS {
+        0 => None,
+        trailing => char::from_u32(TRAILING_BASE + trailing),
+    };
+    Some([leading, vowel, trailing])
+}
+
+// Helper function to get all saved filters of an owner
+fn _get_owner_saved_filters(owner: &str) -> Vec<SavedFilter> {
+    SAVED_FILTERS.with(|filters| {
+        filters
+            .borrow()
+            .iter()
+            .filter(|(_, filter)| filter.owner == owner)
+            .map(|(_, filter)| filter)
+            .collect()
+    })
+}
+
+// Helper function to get a saved filter owned by the given owner
+fn _get_owned_saved_filter(id: u64, owner: &str) -> Result<SavedFilter, Error> {
+    match SAVED_FILTERS.with(|filters| filters.borrow().get(&id)) {
+        Some(filter) if filter.owner == owner => Ok(filter),
+        Some(_) => Err(Error::NotFound {
+            msg: format!("Not authorized to access saved filter with id={}", id),
+        }),
+        None => Err(Error::NotFound {
+            msg: format!("Saved filter with id={} not found", id),
+        }),
+    }
+}
+
+// Helper function to validate a saved filter, dropping repeated statuses and priorities
+fn validate_saved_filter_payload(payload: &mut SavedFilterPayload) -> Result<(), Error> {
+    if payload.name.trim().is_empty() {
+        return Err(Error::InvalidInput {
+            msg: "Filter name cannot be empty".to_string(),
+        });
+    }
+    if payload.name.chars().count() > MAX_FILTER_NAME_LENGTH {
+        return Err(Error::InvalidInput {
+            msg: format!(
+                "Filter name cannot be longer than {} characters",
+                MAX_FILTER_NAME_LENGTH
+            ),
+        });
+    }
+
+    let criteria = &mut payload.criteria;
+    criteria.statuses.sort();
+    criteria.statuses.dedup();
+    criteria.priorities.sort();
+    criteria.priorities.dedup();
+    validate_filter_criteria(criteria)?;
+
+    let open = criteria.statuses.is_empty()
+        && criteria.priorities.is_empty()
+        && criteria.label_ids.is_empty()
+        && criteria.due_after.is_none()
+        && criteria.due_before.is_none()
+        && criteria.created_after.is_none()
+        && criteria.created_before.is_none()
+        && criteria.has_due_date.is_none()
+        && criteria.text.is_none();
+    if open {
+        return Err(Error::InvalidInput {
+            msg: "Filter criteria cannot be empty".to_string(),
+        });
+    }
+    Ok(())
+}
+
+// Helper function to validate filter criteria
+fn validate_filter_criteria(criteria: &FilterCriteria) -> Result<(), Error> {
+    if criteria.label_ids.len() > MAX_LABELS_PER_USER {
+        return Err(Error::InvalidInput {
+            msg: format!("Cannot filter on more than {} labels", MAX_LABELS_PER_USER),
+        });
+    }
+    if let (Some(after), Some(before)) = (criteria.due_after, criteria.due_before) {
+        if after > before {
+            return Err(Error::InvalidInput {
+                msg: "Due window start cannot be after its end".to_string(),
+            });
+        }
+    }
+    if let (Some(after), Some(before)) = (criteria.created_after, criteria.created_before) {
+        if after > before {
+            return Err(Error::InvalidInput {
+                msg: "Creation window start cannot be after its end".to_string(),
+            });
+        }
+    }
+    if let Some(text) = &criteria.text {
+        if text.trim().is_empty() {
+            return Err(Error::InvalidInput {
+                msg: "Filter text cannot be empty".to_string(),
+            });
+        }
+        if text.chars().count() > MAX_TITLE_LENGTH {
+            return Err(Error::InvalidInput {
+                msg: format!(
+                    "Filter text cannot be longer than {} characters",
+                    MAX_TITLE_LENGTH
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+// Helper function to check a todo against filter criteria, cheapest checks first; `text` is the
+// criteria text already folded with fold_text
+fn matches_criteria(todo: &Todo, criteria: &FilterCriteria, text: Option<&str>) -> bool {
+    if !criteria.statuses.is_empty() && !criteria.statuses.contains(&todo.status) {
+        return false;
+    }
+    if !criteria.priorities.is_empty() && !criteria.priorities.contains(&todo.priority) {
+        return false;
+    }
+    if criteria
+        .has_due_date
+        .is_some_and(|has_due_date| has_due_date != todo.due_date.is_some())
+    {
+        return false;
+    }
+    if criteria
+        .created_after
+        .is_some_and(|after| todo.created_at < after)
+        || criteria
+            .created_before
+            .is_some_and(|before| todo.created_at > before)
+    {
+        return false;
+    }
+    if criteria.due_after.is_some() || criteria.due_before.is_some() {
+        let Some(due) = todo.due_date else {
+            return false;
+        };
+        if criteria.due_after.is_some_and(|after| due < after)
+            || criteria.due_before.is_some_and(|before| due > before)
+        {
+            return false;
+        }
+    }
+    if !criteria.label_ids.is_empty() {
+        let has_labels = match criteria.label_match.as_ref().unwrap_or(&LabelMatch::Any) {
+            LabelMatch::Any => todo
+                .label_ids
+                .iter()
+                .any(|id| criteria.label_ids.contains(id)),
+            LabelMatch::All => criteria
+                .label_ids
+                .iter()
+                .all(|id| todo.label_ids.contains(id)),
+        };
+        if !has_labels {
+            return false;
+        }
+    }
+    match text {
+        Some(text) => {
+            fold_text(&todo.title).contains(text) || fold_text(&todo.description).contains(text)
+        }
+        None => true,
+    }
+}
+
+// Helper function to get a page of the caller's todos matching the criteria, see
+// page_filtered_todos
+fn _filter_caller_todos(
+    criteria: &FilterCriteria,
+    sort: Option<&SortSpec>,
+    cursor: Option<&FilterCursor>,
+    limit: u64,
+) -> Result<FilteredTodoPage, Error> {
+    let caller = ic_cdk::caller();
+
+    // Start from the status index when the statuses narrow down the owner's todos
+    let candidates = if criteria.statuses.is_empty() {
+        _get_owner_todos(&caller.to_string())
+    } else {
+        let mut statuses = criteria.statuses.clone();
+        statuses.sort();
+        statuses.dedup();
+        statuses
+            .into_iter()
+            .flat_map(|status| _get_owner_todos_with_status(&caller, status))
+            .collect()
+    };
+    page_filtered_todos(candidates, criteria, sort, cursor, limit)
+}
+
+// Helper function to filter, sort and page the candidate todos. Without a sort the todos are in
+// id order. A page continues after the sort key and id in the cursor.
+fn page_filtered_todos(
+    candidates: Vec<Todo>,
+    criteria: &FilterCriteria,
+    sort: Option<&SortSpec>,
+    cursor: Option<&FilterCursor>,
+    limit: u64,
+) -> Result<FilteredTodoPage, Error> {
+    let limit = limit.clamp(1, MAX_PAGE_LIMIT) as usize;
+    let text = criteria.text.as_deref().map(|text| fold_text(text.trim()));
+    let key = |todo: &Todo| sort.map(|sort| sort_key(todo, &sort.by));
+    let reverse = sort.is_some_and(|sort| sort.reverse);
+    let order = |a: &Option<SortKey>, a_id: u64, b: &Option<SortKey>, b_id: u64| {
+        let order = match (a, b) {
+            (Some(a), Some(b)) => key_order(a, a_id, b, b_id),
+            _ => a_id.cmp(&b_id),
+        };
+        if reverse {
+            order.reverse()
+        } else {
+            order
+        }
+    };
+
+    if let Some(cursor) = cursor {
+        let fits_sort = match (&cursor.key, sort) {
+            (None, None) => true,
+            (Some(cursor_key), Some(sort)) => matches!(
+                (cursor_key, &sort.by),
+                (SortKey::Position(_), SortBy::Manual)
+                    | (SortKey::CreatedAt(_), SortBy::CreatedAt)
+                    | (SortKey::UpdatedAt(_), SortBy::UpdatedAt)
+                    | (SortKey::DueDate(_), SortBy::DueDate)
+                    | (SortKey::Priority(_), SortBy::Priority)
+            ),
+            _ => false,
+        };
+        if !fits_sort {
+            return Err(Error::InvalidInput {
+                msg: "Cursor belongs to a different sort order".to_string(),
+            });
+        }
+    }
+
+    let mut todos: Vec<(Option<SortKey>, Todo)> = candidates
+        .into_iter()
+        .filter(|todo| matches_criteria(todo, criteria, text.as_deref()))
+        .map(|todo| (key(&todo), todo))
+        .filter(|(key, todo)| {
+            cursor.is_none_or(|cursor| {
+                order(key, todo.id, &cursor.key, cursor.id) == std::cmp::Ordering::Greater
+            })
+        })
+        .collect();
+    todos.sort_by(|(a_key, a), (b_key, b)| order(a_key, a.id, b_key, b.id));
+
+    let next_cursor = if todos.len() > limit {
+        let (key, todo) = &todos[limit - 1];
+        Some(FilterCursor {
+            key: key.clone(),
+            id: todo.id,
+        })
+    } else {
+        None
+    };
+    todos.truncate(limit);
+    Ok(FilteredTodoPage {
+        todos: todos.into_iter().map(|(_, todo)| todo).collect(),
+        next_cursor,
+    })
+}
+
+// Export Candid interface
+ic_cdk::export_candid!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Names of the methods in the exported service, split into updates and queries
+    fn exported_methods() -> (Vec<String>, Vec<String>) {
+        let did = __export_service();
+        let service = &did[did.find("service :").expect("did has a service")..];
+        let body = &service[service.find('{').expect("service has a body") + 1..];
+
+        // Long signatures wrap over several lines and records contain ';' too, so methods are
+        // split on the terminators outside of any brackets
+        let mut methods = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+        for (index, c) in body.char_indices() {
+            match c {
+                '{' | '(' => depth += 1,
+                '}' | ')' => depth -= 1,
+                ';' if depth == 0 => {
+                    methods.push(body[start..index].trim());
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+
+        let mut updates = Vec::new();
+        let mut queries = Vec::new();
+        for method in methods {
+            let (name, signature) = method.split_once(" : ").expect("method has a signature");
+            match signature.ends_with("query") {
+                true => queries.push(name.to_string()),
+                false => updates.push(name.to_string()),
+            }
+        }
+        (updates, queries)
+    }
+
+    fn sorted(methods: &[&str]) -> Vec<String> {
+        let mut methods: Vec<String> = methods.iter().map(|m| m.to_string()).collect();
+        methods.sort();
+        methods
+    }
+
+    fn stored_todo(id: u64, owner: Principal) -> Todo {
+        Todo {
+            id,
+            title: format!("Todo {}", id),
+            description: String::new(),
+            status: TaskStatus::Pending,
+            priority: Priority::default(),
+            due_date: None,
+            created_at: id,
+            updated_at: None,
+            position: (id + 1) * POSITION_GAP,
+            owner: owner.to_string(),
+            color: None,
+            assignee: None,
+            assignment_state: None,
+            collaborators: Vec::new(),
+            field_permissions: Vec::new(),
+            completed_at: None,
+            completion_duration: None,
+            visibility: Visibility::Private,
+            label_ids: Vec::new(),
+            pinned: false,
+            starred: false,
+            reminder_at: None,
+            version: 0,
+            last_modified_by: None,
+            frozen: false,
+            frozen_by: None,
+            estimated_minutes: None,
+        }
+    }
+
+    fn index(todo: &Todo) {
+        index_title(None, Some(todo));
+        index_owner(None, Some(todo));
+        index_status(None, Some(todo));
+        index_due_date(None, Some(todo));
+        index_title_tokens(None, Some(todo));
+        index_assignee(None, Some(todo));
+        index_collaborators(None, Some(todo));
+    }
+
+    // Stores todos with their index entries, then corrupts the indexes: some entries are
+    // missing, some are stale and some point to todos that don't exist
+    fn store_with_corrupted_indexes(total: u64) {
+        let owners = [Principal::anonymous(), Principal::management_canister()];
+        for id in 0..total {
+            let mut todo = stored_todo(id, owners[(id % 3 == 0) as usize]);
+            todo.due_date = (id % 2 == 0).then_some(1_000 + id);
+            todo.assignee = (id % 4 == 0).then_some(owners[1]);
+            if id % 6 == 0 {
+                todo.collaborators = vec![(owners[0], Role::Viewer)];
+            }
+            STORAGE.with(|service| service.borrow_mut().insert(id, todo.clone()));
+            index(&todo);
+        }
+        for id in (0..total).step_by(5) {
+            let todo = _get_todo(&id).expect("todo is stored");
+            index_owner(Some(&todo), None);
+            index_assignee(Some(&todo), None);
+            index_collaborators(Some(&todo), None);
+        }
+        for id in (1..total).step_by(7) {
+            let mut todo = _get_todo(&id).expect("todo is stored");
+            todo.title = format!("Renamed {}", id);
+            index_title(None, Some(&todo));
+            index_title_tokens(None, Some(&todo));
+        }
+        let mut missing = stored_todo(total + 10, owners[0]);
+        missing.due_date = Some(5);
+        index(&missing);
+    }
+
+    #[test]
+    fn integrity_checks_in_batches_match_an_unbounded_check() {
+        store_with_corrupted_indexes(60);
+        let unbounded = check_integrity_within(None, usize::MAX, false)
+            .unwrap_or_else(|_| panic!("the check starts without a cursor"));
+        assert!(unbounded.complete);
+        assert!(!unbounded.anomalies.is_empty());
+
+        let mut anomalies = Vec::new();
+        let mut cursor = None;
+        let mut calls = 0;
+        loop {
+            let report = check_integrity_within(cursor, 7, false)
+                .unwrap_or_else(|_| panic!("cursors name known indexes"));
+            anomalies.extend(report.anomalies);
+            calls += 1;
+            if report.complete {
+                break;
+            }
+            cursor = report.cursor;
+        }
+        assert!(calls > 1);
+        assert_eq!(anomalies, unbounded.anomalies);
+    }
+
+    fn entries<K>(index: &'static LocalKey<RefCell<TodoIndex<K>>>) -> Vec<Vec<u8>>
+    where
+        K: BoundedStorable + Default + Ord + Clone,
+    {
+        index.with(|index| {
+            index
+                .borrow()
+                .iter()
+                .map(|(entry, _)| entry.to_bytes().into_owned())
+                .collect()
+        })
+    }
+
+    fn all_index_entries() -> Vec<Vec<Vec<u8>>> {
+        vec![
+            entries(&TITLE_INDEX),
+            entries(&OWNER_INDEX),
+            entries(&STATUS_INDEX),
+            entries(&DUE_DATE_INDEX),
+            entries(&TITLE_TOKEN_INDEX),
+            entries(&ASSIGNEE_INDEX),
+            entries(&COLLABORATOR_INDEX),
+        ]
+    }
+
+    fn clear_index<K>(index: &'static LocalKey<RefCell<TodoIndex<K>>>)
+    where
+        K: BoundedStorable + Default + Ord + Clone,
+    {
+        index.with(|index| {
+            let keys: Vec<(K, u64)> = index.borrow().iter().map(|(key, _)| key).collect();
+            let mut index = index.borrow_mut();
+            for key in &keys {
+                index.remove(key);
+            }
+        });
+    }
+
+    fn clear_storage_and_indexes() {
+        let ids: Vec<u64> =
+            STORAGE.with(|service| service.borrow().iter().map(|(id, _)| id).collect());
+        STORAGE.with(|service| {
+            let mut service = service.borrow_mut();
+            for id in ids {
+                service.remove(&id);
+            }
+        });
+        clear_index(&TITLE_INDEX);
+        clear_index(&OWNER_INDEX);
+        clear_index(&STATUS_INDEX);
+        clear_index(&DUE_DATE_INDEX);
+        clear_index(&TITLE_TOKEN_INDEX);
+        clear_index(&ASSIGNEE_INDEX);
+        clear_index(&COLLABORATOR_INDEX);
+    }
+
+    #[test]
+    fn repairs_in_batches_match_an_unbounded_repair() {
+        store_with_corrupted_indexes(60);
+        let unbounded = check_integrity_within(None, usize::MAX, true)
+            .unwrap_or_else(|_| panic!("the repair starts without a cursor"));
+        assert!(unbounded.complete);
+        let repaired = all_index_entries();
+
+        clear_storage_and_indexes();
+        ID_COUNTER
+            .with(|counter| counter.borrow_mut().set(0))
+            .expect("counter is set");
+        store_with_corrupted_indexes(60);
+        let mut anomalies = Vec::new();
+        let mut cursor = None;
+        loop {
+            let report = check_integrity_within(cursor, 7, true)
+                .unwrap_or_else(|_| panic!("cursors name known indexes"));
+            anomalies.extend(report.anomalies);
+            if report.complete {
+                break;
+            }
+            cursor = report.cursor;
+        }
+        assert_eq!(anomalies, unbounded.anomalies);
+        assert_eq!(all_index_entries(), repaired);
+
+        // Nothing is left to repair, and the repaired indexes are the ones built from scratch
+        let check = check_integrity_within(None, usize::MAX, false)
+            .unwrap_or_else(|_| panic!("the check starts without a cursor"));
+        assert!(check.anomalies.is_empty());
+        let todos: Vec<Todo> =
+            STORAGE.with(|service| service.borrow().iter().map(|(_, todo)| todo).collect());
+        clear_storage_and_indexes();
+        for todo in &todos {
+            index(todo);
+        }
+        assert_eq!(all_index_entries(), repaired);
+    }
+
+    #[test]
+    fn assigned_todos_are_looked_up_by_assignee_and_state() {
+        let owner = Principal::management_canister();
+        let assignee = Principal::anonymous();
+        let states = [
+            Some(AssignmentState::Proposed),
+            Some(AssignmentState::Accepted),
+            Some(AssignmentState::Declined),
+            None,
+        ];
+        for (id, state) in states.into_iter().enumerate() {
+            let mut todo = stored_todo(id as u64, owner);
+            todo.assignee = state.is_some().then_some(assignee);
+            todo.assignment_state = state;
+            STORAGE.with(|service| service.borrow_mut().insert(todo.id, todo.clone()));
+            index(&todo);
+        }
+
+        assert_eq!(
+            ids(&_get_assigned_todos(&assignee, AssignmentState::Accepted)),
+            vec![1]
+        );
+        assert_eq!(
+            ids(&_get_assigned_todos(&assignee, AssignmentState::Proposed)),
+            vec![0]
+        );
+
+        // Unassigning moves the todo out of the assignee's range
+        let previous = _get_todo(&1).expect("todo is stored");
+        let mut todo = previous.clone();
+        todo.assignee = None;
+        todo.assignment_state = None;
+        index_assignee(Some(&previous), Some(&todo));
+        assert!(_get_assigned_todos(&assignee, AssignmentState::Accepted).is_empty());
+    }
+
+    #[test]
+    fn indexed_pages_add_up_to_the_principals_todos() {
+        let owner = Principal::management_canister();
+        let collaborator = Principal::anonymous();
+        let total = 2 * MAX_PAGE_LIMIT + 5;
+        for id in 0..total {
+            let mut todo = stored_todo(id, owner);
+            if id % 2 == 0 {
+                todo.collaborators.push((collaborator, Role::Viewer));
+            }
+            STORAGE.with(|service| service.borrow_mut().insert(todo.id, todo.clone()));
+            index(&todo);
+        }
+
+        let mut paged = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = page_indexed_todos(&COLLABORATOR_INDEX, &collaborator, cursor, |_| true);
+            assert!(page.todos.len() <= MAX_PAGE_LIMIT as usize);
+            paged.extend(ids(&page.todos));
+            if page.complete {
+                break;
+            }
+            cursor = page.cursor;
+        }
+        assert_eq!(
+            paged,
+            ids(&_get_indexed_todos(&COLLABORATOR_INDEX, &collaborator))
+        );
+        assert_eq!(paged.len() as u64, total / 2 + 1);
+    }
+
+    #[test]
+    fn stale_indexes_are_rebuilt_from_the_todos() {
+        let owner = Principal::anonymous();
+        let owner_key = principal_key(&owner);
+        let todos: Vec<Todo> = (0..5).map(|id| stored_todo(id, owner)).collect();
+        for todo in &todos {
+            STORAGE.with(|service| service.borrow_mut().insert(todo.id, todo.clone()));
+            index_owner(None, Some(todo));
+            // Entries built with an older fold_text
+            let old_title = (title_key(&todo.owner, "Old title"), todo.id);
+            TITLE_INDEX.with(|index| index.borrow_mut().insert(old_title, ()));
+            let old_token = ((owner_key, token_key("TODO")), todo.id);
+            TITLE_TOKEN_INDEX.with(|index| index.borrow_mut().insert(old_token, ()));
+        }
+        ID_COUNTER
+            .with(|counter| counter.borrow_mut().set(5))
+            .expect("counter is set");
+
+        assert!(indexes_stale());
+        rebuild_indexes();
+        assert!(!indexes_stale());
+        let rebuilt = all_index_entries();
+
+        clear_storage_and_indexes();
+        for todo in &todos {
+            index(todo);
+        }
+        assert_eq!(all_index_entries(), rebuilt);
+    }
+
+    #[test]
+    fn scan_pages_match_an_unbounded_scan() {
+        let owners = [Principal::anonymous(), Principal::management_canister()];
+        let budget = 1_000;
+        let total = 4_500;
+        STORAGE.with(|service| {
+            let mut service = service.borrow_mut();
+            for id in 0..total {
+                service.insert(id, stored_todo(id, owners[(id % 7 == 0) as usize]));
+            }
+        });
+        let keep = |todo: &Todo| todo.owner == owners[1].to_string();
+
+        let unbounded: Vec<u64> = STORAGE.with(|service| {
+            service
+                .borrow()
+                .iter()
+                .filter(|(_, todo)| keep(todo))
+                .map(|(id, _)| id)
+                .collect()
+        });
+
+        let mut paged = Vec::new();
+        let mut cursor = None;
+        let mut calls = 0;
+        loop {
+            let page = scan_todos_within(cursor, budget, keep);
+            calls += 1;
+            paged.extend(page.todos.iter().map(|todo| todo.id));
+            if page.complete {
+                break;
+            }
+            cursor = page.cursor;
+        }
+
+        assert_eq!(calls, 5);
+        assert_eq!(paged, unbounded);
+    }
+
+    #[test]
+    fn fold_text_spells_out_sharp_s_and_ligatures() {
+        assert_eq!(fold_text("Straße"), "strasse");
+        assert_eq!(fold_text("STRASSE"), "strasse");
+        assert_eq!(fold_text("ẞ"), "ss");
+        assert_eq!(fold_text("ﬁle ﬂow"), "file flow");
+        assert_eq!(fold_text("Æsir Œuvre Ĳssel"), "aesir oeuvre ijssel");
+    }
+
+    #[test]
+    fn fold_text_keeps_dotless_i_apart() {
+        // Turkish ı is its own letter, while İ case folds to i plus a dot that is dropped
+        assert_eq!(fold_text("ı"), "ı");
+        assert_eq!(fold_text("İstanbul"), "istanbul");
+        assert_eq!(fold_text("I"), "i");
+        assert_ne!(fold_text("kırmızı"), fold_text("kirmizi"));
+    }
+
+    #[test]
+    fn fold_text_strips_precomposed_and_combining_accents() {
+        assert_eq!(fold_text("Café"), "cafe");
+        assert_eq!(fold_text("Cafe\u{0301}"), "cafe");
+        assert_eq!(fold_text("a\u{1dc4}\u{20d7}"), "a");
+        assert_eq!(fold_text("Pǎnjīn"), "panjin");
+        assert_eq!(fold_text("Tiếng Việt ạ"), "tieng viet a");
+        assert_eq!(fold_text("Şişli Ștefan"), "sisli stefan");
+    }
+
+    #[test]
+    fn fold_text_normalizes_other_scripts_and_compatibility_forms() {
+        assert_eq!(fold_text("ΆΛΦΑ άλφας"), "αλφα αλφασ");
+        assert_eq!(fold_text("Ёлка"), "елка");
+        assert_eq!(fold_text("ＴＯＤＯ ①"), "todo 1");
+        assert_eq!(fold_text("m² ½"), "m2 1\u{2044}2");
+        assert_eq!(fold_text("ｶﾞ"), "カ");
+        assert_eq!(fold_text("한"), "\u{1112}\u{1161}\u{11ab}");
+        assert_eq!(fold_text("ﾊﾝ"), fold_text("ハン"));
+    }
+
+    #[test]
+    fn title_keys_fold_like_search() {
+        let owner = Principal::anonymous().to_string();
+        assert!(title_key(&owner, "Café  Menu") == title_key(&owner, " CAFE menu"));
+        assert!(title_key(&owner, "Straße") == title_key(&owner, "STRASSE"));
+        assert!(title_key(&owner, "kırmızı") != title_key(&owner, "kirmizi"));
+    }
+
+    #[test]
+    fn title_tokens_are_folded_and_deduplicated() {
+        assert_eq!(
+            title_tokens("Café, CAFE and café-ﬁx"),
+            vec!["and", "cafe", "fix"]
+        );
+    }
+
+    fn ids(todos: &[Todo]) -> Vec<u64> {
+        todos.iter().map(|todo| todo.id).collect()
+    }
+
+    // Todos with a spread of statuses, priorities, due dates, labels and titles
+    fn filter_candidates() -> Vec<Todo> {
+        let statuses = [
+            TaskStatus::Pending,
+            TaskStatus::InProgress,
+            TaskStatus::Completed,
+        ];
+        let priorities = [Priority::Low, Priority::Medium, Priority::High];
+        (0..12)
+            .map(|id| {
+                let mut todo = stored_todo(id, Principal::anonymous());
+                todo.status = statuses[id as usize % 3].clone();
+                todo.priority = priorities[id as usize % 4 % 3].clone();
+                todo.due_date = (id % 2 == 0).then_some(1_000 - id * 10);
+                todo.label_ids = (0..id % 3).collect();
+                if id % 5 == 0 {
+                    todo.title = format!("Café visit {}", id);
+                }
+                todo
+            })
+            .collect()
+    }
+
+    // Collects every page of a listing, checking that no todo is returned twice
+    fn all_pages(
+        candidates: &[Todo],
+        criteria: &FilterCriteria,
+        sort: Option<&SortSpec>,
+        limit: u64,
+    ) -> Vec<u64> {
+        let mut seen = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page =
+                page_filtered_todos(candidates.to_vec(), criteria, sort, cursor.as_ref(), limit)
+                    .unwrap_or_else(|_| panic!("cursor fits the sort"));
+            assert!(page.todos.len() <= limit as usize);
+            seen.extend(ids(&page.todos));
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        let mut unique = seen.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), seen.len());
+        seen
+    }
+
+    #[test]
+    fn filter_criteria_combine_with_and() {
+        let candidates = filter_candidates();
+        let page = |criteria: FilterCriteria| {
+            ids(
+                &page_filtered_todos(candidates.clone(), &criteria, None, None, 100)
+                    .unwrap_or_else(|_| panic!("no cursor"))
+                    .todos,
+            )
+        };
+
+        assert_eq!(
+            page(FilterCriteria {
+                statuses: vec![TaskStatus::Pending, TaskStatus::Completed],
+                has_due_date: Some(true),
+                ..Default::default()
+            }),
+            vec![0, 2, 6, 8]
+        );
+        assert_eq!(
+            page(FilterCriteria {
+                priorities: vec![Priority::Low],
+                label_ids: vec![0, 1],
+                label_match: Some(LabelMatch::All),
+                ..Default::default()
+            }),
+            vec![8, 11]
+        );
+        assert_eq!(
+            page(FilterCriteria {
+                label_ids: vec![1],
+                due_after: Some(920),
+                ..Default::default()
+            }),
+            vec![2, 8]
+        );
+        assert_eq!(
+            page(FilterCriteria {
+                text: Some(" CAFE ".to_string()),
+                has_due_date: Some(true),
+                ..Default::default()
+            }),
+            vec![0, 10]
+        );
+    }
+
+    #[test]
+    fn filtered_pages_add_up_to_the_sorted_listing() {
+        let candidates = filter_candidates();
+        let criteria = FilterCriteria::default();
+
+        assert_eq!(
+            all_pages(&candidates, &criteria, None, 5),
+            (0..12).collect::<Vec<_>>()
+        );
+        for by in [
+            SortBy::Manual,
+            SortBy::CreatedAt,
+            SortBy::UpdatedAt,
+            SortBy::DueDate,
+            SortBy::Priority,
+        ] {
+            for reverse in [false, true] {
+                let sort = SortSpec {
+                    by: by.clone(),
+                    reverse,
+                };
+                let mut expected = candidates.clone();
+                expected.sort_by(|a, b| match reverse {
+                    true => sort_order(b, a, &by),
+                    false => sort_order(a, b, &by),
+                });
+                for limit in [1, 4, 12] {
+                    assert_eq!(
+                        all_pages(&candidates, &criteria, Some(&sort), limit),
+                        ids(&expected)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn filter_cursor_outlives_its_todo() {
+        let mut candidates = filter_candidates();
+        let criteria = FilterCriteria::default();
+        let sort = SortSpec {
+            by: SortBy::DueDate,
+            reverse: false,
+        };
+
+        let first = page_filtered_todos(candidates.clone(), &criteria, Some(&sort), None, 3)
+            .unwrap_or_else(|_| panic!("no cursor"));
+        assert_eq!(ids(&first.todos), vec![10, 8, 6]);
+        let cursor = first.next_cursor.expect("more todos");
+        assert!(cursor.key == Some(SortKey::DueDate(Some(940))));
+
+        // The last todo of the page is deleted before the next page is requested
+        candidates.retain(|todo| todo.id != 6);
+        let second =
+            page_filtered_todos(candidates.clone(), &criteria, Some(&sort), Some(&cursor), 3)
+                .unwrap_or_else(|_| panic!("cursor fits the sort"));
+        assert_eq!(ids(&second.todos), vec![4, 2, 0]);
+
+        let other_sort = SortSpec {
+            by: SortBy::Priority,
+            reverse: false,
+        };
+        assert!(
+            page_filtered_todos(candidates, &criteria, Some(&other_sort), Some(&cursor), 3)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn failed_transfer_keeps_share_links_and_history() {
+        let from = Principal::management_canister();
+        let offer = TransferOffer {
+            todo_id: 1,
+            from: from.to_string(),
+            to: Principal::anonymous(),
+            offered_at: 0,
+        };
+        let token = [7; SHARE_TOKEN_BYTES];
+        let grant = ShareGrant {
+            todo_id: 1,
+            created_at: 0,
+            expires_at: None,
+        };
+        SHARE_TOKENS.with(|tokens| tokens.borrow_mut().insert(token, grant));
+        SHARE_TOKEN_INDEX.with(|index| index.borrow_mut().insert((1, token), ()));
+
+        // Too large to store, so the new owner's copy is rejected
+        let mut todo = stored_todo(1, from);
+        todo.description = "x".repeat(Todo::MAX_SIZE as usize);
+        assert!(transfer_todo(todo, &offer, 10).is_err());
+
+        assert!(SHARE_TOKENS.with(|tokens| tokens.borrow().contains_key(&token)));
+        assert_eq!(_get_todo_share_tokens(1), vec![token]);
+        assert!(_get_ownership_history(1).is_empty());
+    }
+
+    #[test]
+    fn only_title_owner_or_reopening_changes_are_checked_for_duplicates() {
+        let stored = stored_todo(1, Principal::anonymous());
+        assert!(can_duplicate_title(None, &stored));
+
+        let mut starred = stored.clone();
+        starred.starred = true;
+        starred.priority = Priority::Urgent;
+        assert!(!can_duplicate_title(Some(&stored), &starred));
+
+        let mut renamed = stored.clone();
+        renamed.title = "Renamed".to_string();
+        assert!(can_duplicate_title(Some(&stored), &renamed));
+
+        let mut transferred = stored.clone();
+        transferred.owner = Principal::management_canister().to_string();
+        assert!(can_duplicate_title(Some(&stored), &transferred));
+
+        let mut completed = stored.clone();
+        completed.status = TaskStatus::Completed;
+        assert!(can_duplicate_title(Some(&completed), &stored));
+    }
+
+    #[test]
+    fn cloning_someone_elses_todo_is_unauthorized() {
+        let owner = Principal::management_canister();
+        let todo = stored_todo(1, owner);
+        STORAGE.with(|storage| storage.borrow_mut().insert(todo.id, todo));
+
+        assert!(_get_clone_source(1, &owner).is_ok());
+        assert!(matches!(
+            _get_clone_source(1, &Principal::anonymous()),
+            Err(Error::Unauthorized { .. })
+        ));
+        assert!(matches!(
+            _get_clone_source(2, &owner),
+            Err(Error::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn removing_a_todo_removes_only_its_checkpoints() {
+        for (id, todo_id) in [(1, 1), (2, 2), (3, 1)] {
+            let checkpoint = Checkpoint {
+                id,
+                todo_id,
+                ..Default::default()
+            };
+            CHECKPOINTS.with(|checkpoints| checkpoints.borrow_mut().insert(id, checkpoint));
+            CHECKPOINT_INDEX.with(|index| index.borrow_mut().insert((todo_id, id), ()));
+        }
+        let ids = |todo_id| -> Vec<u64> {
+            _get_todo_checkpoints(todo_id)
+                .iter()
+                .map(|checkpoint| checkpoint.id)
+                .collect()
+        };
+        assert_eq!(ids(1), vec![1, 3]);
+
+        do_remove(1);
+
+        assert!(ids(1).is_empty());
+        assert_eq!(ids(2), vec![2]);
+        assert_eq!(
+            CHECKPOINTS.with(|checkpoints| checkpoints.borrow().len()),
+            1
+        );
+    }
+
+    #[test]
+    fn largest_valid_todo_fits_into_a_storage_slot() {
+        // Principals are at most 29 bytes long, their text form at most 63 characters
+        let principal = |byte: u8| Principal::from_slice(&[byte; 29]);
+        let restricted = FieldPermission {
+            title: false,
+            description: false,
+            priority: false,
+            due_date: false,
+            status: false,
+        };
+        let mut todo = stored_todo(u64::MAX, principal(0));
+        todo.title = "\u{1F600}".repeat(MAX_TITLE_LENGTH);
+        todo.description = "\u{1F600}".repeat(MAX_DESCRIPTION_LENGTH);
+        todo.due_date = Some(u64::MAX);
+        todo.updated_at = Some(u64::MAX);
+        todo.color = Some("#FFFFFF".to_string());
+        todo.assignee = Some(principal(1));
+        todo.assignment_state = Some(AssignmentState::Accepted);
+        todo.collaborators = (0..MAX_COLLABORATORS_PER_TODO as u8)
+            .map(|i| (principal(i + 2), Role::Editor))
+            .collect();
+        todo.field_permissions = todo
+            .collaborators
+            .iter()
+            .map(|(principal, _)| (*principal, restricted.clone()))
+            .collect();
+        todo.completed_at = Some(u64::MAX);
+        todo.completion_duration = Some(u64::MAX);
+        todo.label_ids = vec![u64::MAX; MAX_LABELS_PER_TODO];
+        todo.reminder_at = Some(u64::MAX);
+        todo.estimated_minutes = Some(MAX_ESTIMATED_MINUTES);
+        todo.version = u64::MAX;
+        todo.last_modified_by = Some(principal(1));
+        todo.frozen_by = Some(principal(1).to_string());
+
+        assert!(validate_title(&todo.title).is_ok());
+        assert!(validate_description(&todo.description).is_ok());
+        let size = Encode!(&todo).expect("todo encodes").len();
+        assert!(size <= Todo::MAX_SIZE as usize, "{} bytes", size);
+    }
+
+    #[test]
+    fn rejected_priority_change_is_not_recorded() {
+        let mut todo = stored_todo(1, Principal::anonymous());
+        todo.priority = Priority::Urgent;
+        // Too large to store
+        todo.description = "x".repeat(Todo::MAX_SIZE as usize);
+
+        assert!(do_insert_tracking_priority(&mut todo, &Priority::Low, 10).is_err());
+        assert!(_get_priority_history(1).is_empty());
+    }
+
+    #[test]
+    fn completion_webhooks_are_debounced_and_rate_limited() {
+        let owner = Principal::management_canister();
+        let other = Principal::anonymous();
+        let minute = 60 * NANOS_PER_SECOND;
+
+        // Toggling a todo's completion only posts it once per debounce period
+        assert!(throttle_webhook(&owner, 1, 0).is_ok());
+        assert!(throttle_webhook(&owner, 1, minute).is_err());
+        assert!(throttle_webhook(&owner, 1, WEBHOOK_TODO_DEBOUNCE).is_ok());
+
+        // Other todos count against the owner's window, other owners have their own
+        for id in 2..MAX_WEBHOOKS_PER_WINDOW as u64 {
+            assert!(throttle_webhook(&owner, id, minute).is_ok());
+        }
+        assert!(throttle_webhook(&owner, 100, minute).is_err());
+        assert!(throttle_webhook(&other, 100, minute).is_ok());
+        assert!(throttle_webhook(&owner, 101, WEBHOOK_OWNER_WINDOW).is_ok());
+    }
+
+    #[test]
+    fn imported_ids_of_deleted_todos_are_forgotten() {
+        let owner = Principal::management_canister();
+        let other = Principal::anonymous();
+        IMPORTED_IDS.with(|ids| {
+            let mut ids = ids.borrow_mut();
+            ids.insert((principal_key(&owner), 1), 10);
+            ids.insert((principal_key(&owner), 2), 20);
+            ids.insert((principal_key(&owner), 3), 30);
+            ids.insert((principal_key(&other), 1), 10);
+        });
+
+        remove_imported_ids(&owner, &[10, 30].into());
+
+        let remaining: Vec<((PrincipalKey, u64), u64)> =
+            IMPORTED_IDS.with(|ids| ids.borrow().iter().collect());
+        let mut expected = vec![
+            ((principal_key(&owner), 2), 20),
+            ((principal_key(&other), 1), 10),
+        ];
+        expected.sort();
+        assert!(remaining == expected);
+    }
+
+    #[test]
+    fn inspect_message_lists_every_exported_method() {
+        let (mut updates, mut queries) = exported_methods();
+        updates.sort();
+        queries.sort();
+        assert_eq!(sorted(UPDATE_METHODS), updates);
+        assert_eq!(sorted(QUERY_METHODS), queries);
+    }
+
+    #[test]
+    fn inspect_message_rejects_anonymous_updates_and_unknown_methods() {
+        let user = Principal::management_canister();
+        let anonymous = Principal::anonymous();
+
+        assert!(accepts_message("add_todo", &user, 64));
+        assert!(!accepts_message("add_todo", &anonymous, 64));
+        assert!(!accepts_message(
+            "add_todo",
+            &user,
+            MAX_INGRESS_ARG_BYTES + 1
+        ));
+        assert!(accepts_message(
+            "import_todos_merge",
+            &user,
+            MAX_INGRESS_ARG_BYTES + 1
+        ));
+
+        // Queries stay open to anonymous callers
+        assert!(accepts_message("get_todo", &anonymous, 64));
+
+        assert!(!accepts_message("no_such_method", &user, 64));
+        assert!(!accepts_message("no_such_method", &anonymous, 64));
+    }
+}